@@ -24,6 +24,10 @@ use pw_log::info;
 use regs::*;
 
 use crate::regs::msr::ControlVal;
+#[cfg(feature = "user_space")]
+use crate::regs::msr::Spsel;
+#[cfg(feature = "user_space")]
+use crate::regs::scb::CfsrVal;
 
 /// Combined Exception Return Program Status Register Value
 #[repr(transparent)]
@@ -252,6 +256,107 @@ impl KernelExceptionFrame {
     }
 }
 
+/// Exits the current thread after it faulted in user space.
+///
+/// Entered in privileged thread mode through the frame built by
+/// [`redirect_to_exit()`].
+#[cfg(feature = "user_space")]
+extern "C" fn exit_faulted_thread() {
+    kernel::scheduler::exit_thread(crate::Arch);
+}
+
+/// Rewrites the exception frame of a thread which faulted in user space so
+/// that the exception returns into [`exit_faulted_thread()`] instead.
+///
+/// This lets the thread's teardown, which takes mutexes, run in thread mode
+/// rather than in the fault handler.  Returns the frame for the exception
+/// wrapper to restore.
+#[cfg(feature = "user_space")]
+fn redirect_to_exit(frame: *mut KernelExceptionFrame) -> *mut KernelExceptionFrame {
+    // As the fault was taken from user space, `frame` sits at the 8 byte
+    // aligned top of the thread's kernel stack.  The new frames overlap it:
+    // the hardware frame starts 8 bytes into `frame` and the kernel frame
+    // sits directly below that.  The kernel frame's callee saved registers
+    // are left as they are as `exit_faulted_thread()` does not depend on
+    // them, so only `frame` and the word of alignment padding the exception
+    // wrapper leaves below it are written.
+    let user_frame: *mut ExceptionFrame = unsafe { frame.byte_add(8) }.cast();
+    let kernel_frame = unsafe { user_frame.cast::<KernelExceptionFrame>().sub(1) };
+    pw_assert::assert!(user_frame.addr().is_multiple_of(8));
+
+    unsafe {
+        (*kernel_frame).psp = 0;
+        (*kernel_frame).control = ControlVal::default()
+            .with_npriv(false)
+            .with_spsel(Spsel::Main);
+        (*kernel_frame).return_address = ExcReturn::new(
+            ExcReturnStack::MainSecure,
+            ExcReturnRegisterStacking::Default,
+            ExcReturnFrameType::Standard,
+            ExcReturnMode::ThreadSecure,
+        )
+        .bits()
+        .cast_into();
+        user_frame.write(ExceptionFrame {
+            r0: 0,
+            r1: 0,
+            r2: 0,
+            r3: 0,
+            r12: 0,
+            lr: 0,
+            pc: (exit_faulted_thread as *const () as usize).cast_into(),
+            psr: RetPsrVal(0).with_t(true),
+        });
+    }
+
+    kernel_frame
+}
+
+/// Terminates the current thread's process if `frame` was taken from user
+/// space.
+///
+/// Returns the frame to restore if the fault was handled.  The exception then
+/// returns into a trampoline which exits the faulting thread.
+#[cfg(feature = "user_space")]
+fn handle_user_fault(frame_ptr: *mut KernelExceptionFrame) -> Option<*mut KernelExceptionFrame> {
+    let frame = unsafe { &*frame_ptr };
+    if !frame.control.npriv() {
+        return None;
+    }
+
+    let mut scb = crate::regs::Regs::get().scb;
+    let cfsr = scb.cfsr.read();
+
+    // The program counter is only known if the hardware managed to push the
+    // exception frame to the user stack.
+    let pc = if cfsr.stacking_error() {
+        None
+    } else {
+        let user_frame = with_exposed_provenance::<ExceptionFrame>(frame.psp.cast_into());
+        Some(usize::cast_from(unsafe { (*user_frame).pc }))
+    };
+    let address = if cfsr.mmarvalid() {
+        Some(usize::cast_from(scb.mmfar.read().0))
+    } else if cfsr.bfarvalid() {
+        Some(usize::cast_from(scb.bfar.read().0))
+    } else {
+        None
+    };
+
+    // Clear the reported fault status so that it does not carry over to
+    // later faults.
+    scb.cfsr.write(CfsrVal(cfsr.0));
+
+    kernel::scheduler::process_manager::fault_current_process(
+        crate::Arch,
+        pc,
+        usize::cast_from(cfsr.0),
+        address,
+    );
+
+    Some(redirect_to_exit(frame_ptr))
+}
+
 #[exception(exception = "HardFault")]
 #[unsafe(no_mangle)]
 extern "C" fn pw_kernel_hard_fault(frame: *mut KernelExceptionFrame) -> *mut KernelExceptionFrame {
@@ -291,6 +396,11 @@ extern "C" fn pw_kernel_non_maskable_int(
 extern "C" fn pw_kernel_memory_management(
     frame: *mut KernelExceptionFrame,
 ) -> *mut KernelExceptionFrame {
+    #[cfg(feature = "user_space")]
+    if let Some(frame) = handle_user_fault(frame) {
+        return frame;
+    }
+
     let mmfar = with_exposed_provenance::<u32>(0xe000ed34);
     info!(
         "MemoryManagement exception triggered: address={:#010x}",
@@ -305,6 +415,11 @@ extern "C" fn pw_kernel_memory_management(
 #[exception(exception = "BusFault")]
 #[unsafe(no_mangle)]
 extern "C" fn pw_kernel_bus_fault(frame: *mut KernelExceptionFrame) -> *mut KernelExceptionFrame {
+    #[cfg(feature = "user_space")]
+    if let Some(frame) = handle_user_fault(frame) {
+        return frame;
+    }
+
     let bfar = with_exposed_provenance::<u32>(0xe000ed38);
     info!(
         "BusFault exception triggered: address={:#010x}",
@@ -318,6 +433,11 @@ extern "C" fn pw_kernel_bus_fault(frame: *mut KernelExceptionFrame) -> *mut Kern
 #[exception(exception = "UsageFault")]
 #[unsafe(no_mangle)]
 extern "C" fn pw_kernel_usage_fault(frame: *mut KernelExceptionFrame) -> *mut KernelExceptionFrame {
+    #[cfg(feature = "user_space")]
+    if let Some(frame) = handle_user_fault(frame) {
+        return frame;
+    }
+
     info!("UsageFault exception triggered");
     unsafe { &*frame }.dump();
     #[expect(clippy::empty_loop)]
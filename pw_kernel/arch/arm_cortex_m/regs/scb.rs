@@ -25,6 +25,12 @@ pub struct Scb {
     pub icsr: Icsr,
     /// System Handler Control and State Register
     pub shcsr: Shcsr,
+    /// Configurable Fault Status Register
+    pub cfsr: Cfsr,
+    /// MemManage Fault Address Register
+    pub mmfar: Mmfar,
+    /// BusFault Address Register
+    pub bfar: Bfar,
 }
 
 impl Scb {
@@ -33,6 +39,9 @@ impl Scb {
             cpu_id: CpuId,
             icsr: Icsr,
             shcsr: Shcsr,
+            cfsr: Cfsr,
+            mmfar: Mmfar,
+            bfar: Bfar,
         }
     }
 }
@@ -53,6 +62,7 @@ ro_reg!(CpuId, CpuIdVal, u32, 0xe000_ed00, "CPUID Base Register");
 pub struct IcsrVal(u32);
 impl IcsrVal {
     ro_int_field!(u32, vecactive, 0, 8, u32, "Vector active");
+    ro_bool_field!(u32, pendstset, 26, "SysTick exception pending");
 }
 rw_reg!(
     Icsr,
@@ -118,3 +128,67 @@ rw_reg!(
     0xe000_ed24,
     "SCB System Handler Control and State Register"
 );
+
+#[repr(transparent)]
+pub struct CfsrVal(pub u32);
+impl CfsrVal {
+    ro_bool_field!(u32, iaccviol, 0, "MemManage instruction access violation");
+    ro_bool_field!(u32, daccviol, 1, "MemManage data access violation");
+    ro_bool_field!(u32, munstkerr, 3, "MemManage fault on unstacking");
+    ro_bool_field!(u32, mstkerr, 4, "MemManage fault on stacking");
+    ro_bool_field!(
+        u32,
+        mlsperr,
+        5,
+        "MemManage fault on lazy FP state preservation"
+    );
+    ro_bool_field!(u32, mmarvalid, 7, "MMFAR holds a valid address");
+    ro_bool_field!(u32, ibuserr, 8, "BusFault on instruction fetch");
+    ro_bool_field!(u32, preciserr, 9, "Precise data BusFault");
+    ro_bool_field!(u32, unstkerr, 11, "BusFault on unstacking");
+    ro_bool_field!(u32, stkerr, 12, "BusFault on stacking");
+    ro_bool_field!(u32, lsperr, 13, "BusFault on lazy FP state preservation");
+    ro_bool_field!(u32, bfarvalid, 15, "BFAR holds a valid address");
+    ro_bool_field!(u32, undefinstr, 16, "UsageFault undefined instruction");
+    ro_bool_field!(u32, invstate, 17, "UsageFault invalid state");
+    ro_bool_field!(u32, invpc, 18, "UsageFault invalid PC load");
+    ro_bool_field!(u32, nocp, 19, "UsageFault no coprocessor");
+    ro_bool_field!(u32, stkof, 20, "UsageFault stack overflow");
+    ro_bool_field!(u32, unaligned, 24, "UsageFault unaligned access");
+    ro_bool_field!(u32, divbyzero, 25, "UsageFault divide by zero");
+
+    /// Returns `true` if the exception frame could not be stacked or
+    /// unstacked.
+    pub const fn stacking_error(&self) -> bool {
+        self.munstkerr() || self.mstkerr() || self.unstkerr() || self.stkerr()
+    }
+}
+
+// Fault status bits are cleared by writing one to them.
+rw_reg!(
+    Cfsr,
+    CfsrVal,
+    u32,
+    0xe000_ed28,
+    "SCB Configurable Fault Status Register"
+);
+
+#[repr(transparent)]
+pub struct MmfarVal(pub u32);
+ro_reg!(
+    Mmfar,
+    MmfarVal,
+    u32,
+    0xe000_ed34,
+    "SCB MemManage Fault Address Register"
+);
+
+#[repr(transparent)]
+pub struct BfarVal(pub u32);
+ro_reg!(
+    Bfar,
+    BfarVal,
+    u32,
+    0xe000_ed38,
+    "SCB BusFault Address Register"
+);
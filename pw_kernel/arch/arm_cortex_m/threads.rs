@@ -177,8 +177,12 @@ impl Arch for crate::Arch {
         super::timer::Clock::now()
     }
 
-    fn idle(self) {
+    fn idle(self, wakeup: Option<time::Instant<super::timer::Clock>>) {
+        let suppressed = wakeup.is_some_and(super::timer::suppress_tick);
         cortex_m::asm::wfi();
+        if suppressed {
+            super::timer::resume_tick();
+        }
     }
 
     fn early_init(self) {
@@ -241,6 +245,12 @@ impl Arch for crate::Arch {
             // External IRQ priorities are set by the interrupt controller.
 
             scb.enable(scb::Exception::MemoryManagement);
+
+            // Enable the remaining configurable faults so that faults taken
+            // in user space can be attributed to the faulting process rather
+            // than escalating to a HardFault.
+            scb.enable(scb::Exception::BusFault);
+            scb.enable(scb::Exception::UsageFault);
             // TODO: configure BASEPRI, FAULTMASK
         } // unsafe
 
@@ -270,6 +280,7 @@ impl Arch for crate::Arch {
     }
 
     fn panic() -> ! {
+        kernel::panic_flush();
         unsafe {
             asm!("bkpt");
         }
@@ -287,6 +298,13 @@ impl kernel::scheduler::thread::ThreadState for ArchThreadState {
         local: ThreadLocalState::new(),
     };
 
+    const SAVED_FRAME_OFFSET: Option<usize> = Some(mem::offset_of!(Self, frame));
+    // A switched out thread's kernel frame is immediately followed by the
+    // hardware exception frame holding the pc it resumes at.  The kernel
+    // frame's `return_address` is the `EXC_RETURN` value, not a pc.
+    const SAVED_PC_OFFSET: usize =
+        size_of::<KernelExceptionFrame>() + mem::offset_of!(ExceptionFrame, pc);
+
     unsafe fn initialize_kernel_frame(
         &mut self,
         kernel_stack: Stack,
@@ -373,6 +391,13 @@ impl kernel::scheduler::thread::ThreadState for ArchThreadState {
 
         Ok(())
     }
+
+    #[cfg(feature = "user_space")]
+    fn resumes_in_user_space(&self) -> bool {
+        // Threads preempted in user space are resumed by an exception return
+        // straight into their user space code.
+        !self.frame.is_null() && unsafe { (*self.frame).control.npriv() }
+    }
 }
 
 extern "C" fn trampoline(
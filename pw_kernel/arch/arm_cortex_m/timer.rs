@@ -23,6 +23,8 @@ use crate::regs::Regs;
 static TICKS: SpinLock<crate::Arch, u64> = SpinLock::new(0);
 const LOG_SYSTICK: bool = false;
 const SYSTICK_RELOAD_VALUE: u32 = KernelConfig::SYS_TICK_HZ / KernelConfig::SCHEDULER_TICK_HZ;
+// SysTick is a 24 bit counter.
+const SYSTICK_MAX_PERIOD: u32 = 1 << 24;
 
 pub struct Clock {}
 
@@ -96,6 +98,73 @@ pub fn systick_init() {
     }
 }
 
+/// Stretches the current SysTick period to end at `wakeup`, bounded by the
+/// range of the counter.
+///
+/// Returns `false` and leaves the periodic tick in place if `wakeup` is before
+/// the next scheduler tick.  Must be called with interrupts disabled and paired
+/// with a call to [`resume_tick`] before they are re-enabled.
+pub fn suppress_tick(wakeup: time::Instant<Clock>) -> bool {
+    let mut ticks = TICKS.lock(crate::Arch);
+    let systick_regs = Regs::get().systick;
+    let mut csr = systick_regs.csr;
+    let mut rvr = systick_regs.rvr;
+    let mut cvr = systick_regs.cvr;
+
+    // Stop the counter so that the elapsed time can be folded into `TICKS`
+    // without racing the reload.
+    csr.write(csr.read().with_enable(false));
+
+    let reload = rvr.read().reload();
+    let current = cvr.read().current();
+    let remaining = u64::from(current);
+    let now = *ticks + u64::from(reload - current);
+    if Regs::get().scb.icsr.read().pendstset() || wakeup.ticks() <= now.saturating_add(remaining) {
+        csr.write(csr.read().with_enable(true));
+        return false;
+    }
+
+    let period = (wakeup.ticks() - now).min(u64::from(SYSTICK_MAX_PERIOD));
+    *ticks = now;
+    // `period` is bounded by `SYSTICK_MAX_PERIOD` above.
+    #[expect(clippy::cast_possible_truncation)]
+    rvr.write(rvr.read().with_reload(period as u32 - 1));
+    cvr.write(cvr.read().with_current(0));
+    csr.write(csr.read().with_enable(true));
+    log_if::info_if!(
+        LOG_SYSTICK,
+        "SysTick suppressed for {} ticks",
+        period as u64
+    );
+    true
+}
+
+/// Restores the periodic tick after [`suppress_tick`].
+///
+/// Must be called with interrupts disabled.
+pub fn resume_tick() {
+    let mut ticks = TICKS.lock(crate::Arch);
+    let systick_regs = Regs::get().systick;
+    let mut csr = systick_regs.csr;
+    let mut rvr = systick_regs.rvr;
+    let mut cvr = systick_regs.cvr;
+
+    csr.write(csr.read().with_enable(false));
+
+    let reload = rvr.read().reload();
+    let mut elapsed = u64::from(reload - cvr.read().current());
+    if Regs::get().scb.icsr.read().pendstset() {
+        // The stretched period expired.  The pending SysTick handler accounts
+        // for one regular period, so only the remainder is added here.
+        elapsed += u64::from(reload) + 1 - u64::from(SYSTICK_RELOAD_VALUE);
+    }
+    *ticks += elapsed;
+
+    rvr.write(rvr.read().with_reload(SYSTICK_RELOAD_VALUE - 1));
+    cvr.write(cvr.read().with_current(0));
+    csr.write(csr.read().with_enable(true));
+}
+
 #[unsafe(no_mangle)]
 #[allow(non_snake_case)]
 pub unsafe extern "C" fn SysTick() {
@@ -0,0 +1,186 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+//! Simulated interrupt controller.
+//!
+//! The host has no hardware interrupts.  Instead, a global flag tracks
+//! whether "interrupts" are enabled and pending timer ticks are delivered on
+//! the running kernel thread whenever they are re-enabled.
+//!
+//! IRQs raised with `trigger_interrupt()` are delivered the same way, through
+//! the interrupt table registered with [`set_interrupt_table()`].
+
+extern crate std;
+
+use core::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::OnceLock;
+
+use kernel::Kernel;
+use kernel::interrupt_controller::{self, InterruptController, InterruptTableEntry};
+use kernel::scheduler::PreemptDisableGuard;
+
+static INTERRUPTS_ENABLED: AtomicBool = AtomicBool::new(false);
+
+// Bitmask of the IRQs enabled with `enable_interrupt()`.
+static ENABLED_IRQS: AtomicU64 = AtomicU64::new(0);
+
+// Bitmask of the IRQs triggered but not yet delivered.
+static PENDING_IRQS: AtomicU64 = AtomicU64::new(0);
+
+static INTERRUPT_TABLE: OnceLock<&'static [InterruptTableEntry]> = OnceLock::new();
+
+const MAX_IRQS: u32 = u64::BITS;
+
+pub struct HostInterruptController {}
+
+impl HostInterruptController {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for HostInterruptController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Disables interrupts, returning whether they were previously enabled.
+pub(crate) fn save_and_disable() -> bool {
+    INTERRUPTS_ENABLED.swap(false, Ordering::SeqCst)
+}
+
+/// Re-enables interrupts if `enabled` is set, delivering any pending tick.
+pub(crate) fn restore(enabled: bool) {
+    if enabled {
+        HostInterruptController::enable_interrupts();
+    }
+}
+
+/// Enables interrupts without delivering a pending tick.
+///
+/// Used when returning from a simulated interrupt handler.
+pub(crate) fn enable_without_delivery() {
+    INTERRUPTS_ENABLED.store(true, Ordering::SeqCst);
+}
+
+/// Registers the table used to dispatch triggered IRQs.
+///
+/// Targets with interrupt objects pass their generated
+/// `PW_KERNEL_INTERRUPT_TABLE` before starting the system.  May only be
+/// called once.
+pub fn set_interrupt_table(table: &'static [InterruptTableEntry]) {
+    if INTERRUPT_TABLE.set(table).is_err() {
+        pw_assert::panic!("Interrupt table already set");
+    }
+}
+
+// Runs the handlers of any pending, enabled IRQs.
+//
+// Called with interrupts enabled.  Handlers run with interrupts disabled, as
+// they would on hardware, and may switch to another thread before returning.
+fn deliver_pending_interrupts() {
+    loop {
+        let pending = PENDING_IRQS.load(Ordering::SeqCst) & ENABLED_IRQS.load(Ordering::SeqCst);
+        if pending == 0 {
+            return;
+        }
+        let irq = pending.trailing_zeros();
+        PENDING_IRQS.fetch_and(!irq_bit(irq), Ordering::SeqCst);
+
+        let Some(handler) = INTERRUPT_TABLE
+            .get()
+            .and_then(|table| table.get(irq as usize).copied().flatten())
+        else {
+            pw_assert::panic!("Unhandled interrupt: irq={}", irq as u32);
+        };
+
+        HostInterruptController::disable_interrupts();
+        // SAFETY: Handlers in the interrupt table expect to be called from
+        // interrupt context, which is simulated by disabling interrupts.
+        unsafe { handler() };
+        enable_without_delivery();
+    }
+}
+
+fn irq_bit(irq: u32) -> u64 {
+    pw_assert::assert!(irq < MAX_IRQS, "IRQ {} out of range", irq as u32);
+    1 << irq
+}
+
+impl InterruptController for HostInterruptController {
+    fn early_init(&self) {
+        ENABLED_IRQS.store(0, Ordering::SeqCst);
+    }
+
+    fn enable_interrupt(irq: u32) {
+        ENABLED_IRQS.fetch_or(irq_bit(irq), Ordering::SeqCst);
+    }
+
+    fn disable_interrupt(irq: u32) {
+        ENABLED_IRQS.fetch_and(!irq_bit(irq), Ordering::SeqCst);
+    }
+
+    fn userspace_interrupt_ack(_irq: u32) {}
+
+    fn userspace_interrupt_handler_enter<K: Kernel>(
+        kernel: K,
+        _irq: u32,
+    ) -> PreemptDisableGuard<K> {
+        PreemptDisableGuard::new(kernel)
+    }
+
+    fn userspace_interrupt_handler_exit<K: Kernel>(
+        kernel: K,
+        _irq: u32,
+        preempt_guard: PreemptDisableGuard<K>,
+    ) {
+        interrupt_controller::handler_done(kernel, preempt_guard);
+    }
+
+    fn kernel_interrupt_handler_enter<K: Kernel>(kernel: K, _irq: u32) -> PreemptDisableGuard<K> {
+        PreemptDisableGuard::new(kernel)
+    }
+
+    fn kernel_interrupt_handler_exit<K: Kernel>(
+        kernel: K,
+        _irq: u32,
+        preempt_guard: PreemptDisableGuard<K>,
+    ) {
+        interrupt_controller::handler_done(kernel, preempt_guard);
+    }
+
+    fn enable_interrupts() {
+        INTERRUPTS_ENABLED.store(true, Ordering::SeqCst);
+        crate::timer::deliver_pending_tick();
+        deliver_pending_interrupts();
+    }
+
+    fn disable_interrupts() {
+        INTERRUPTS_ENABLED.store(false, Ordering::SeqCst);
+    }
+
+    fn interrupts_enabled() -> bool {
+        INTERRUPTS_ENABLED.load(Ordering::SeqCst)
+    }
+
+    fn trigger_interrupt(irq: u32) {
+        PENDING_IRQS.fetch_or(irq_bit(irq), Ordering::SeqCst);
+        // Otherwise delivered when interrupts are next enabled.
+        if Self::interrupts_enabled() {
+            deliver_pending_interrupts();
+        }
+    }
+}
@@ -14,44 +14,63 @@
 
 #![no_std]
 
-use kernel::interrupt_controller::InterruptController;
-use kernel::scheduler::thread::{Stack, ThreadState};
-use kernel::scheduler::{PreemptDisableGuard, SchedulerState};
+//! Host architecture.
+//!
+//! Runs the kernel as a native process, backing each kernel thread with an OS
+//! thread and simulating a single CPU with a scheduler tick.  This allows
+//! kernel logic to be run and debugged without an emulator.
+//!
+//! User space apps are linked into the same executable.  Their threads also
+//! run on OS threads and make system calls by calling directly into the
+//! kernel.  Memory protection is not enforced.
+
+extern crate std;
+
+use kernel::scheduler::SchedulerState;
 use kernel::sync::spinlock::SpinLockGuard;
-use kernel::{Arch, Kernel, KernelState};
-use memory_config::MemoryRegionType;
+use kernel::{Kernel, KernelState};
+use memory_config::{MemoryRegion, MemoryRegionType};
 use pw_log::info;
-use pw_status::{Error, Result};
 
+mod interrupt_controller;
 mod spinlock;
+mod syscall;
+mod threads;
+mod timer;
+
+pub use interrupt_controller::{HostInterruptController, set_interrupt_table};
+pub use syscall::HostSyscallArgs;
+pub use threads::ArchThreadState;
+pub use timer::Clock;
 
 #[derive(Copy, Clone, Default)]
 pub struct HostArch;
 
-kernel::impl_thread_arg_for_default_zst!(HostArch);
+/// Name under which generated system code refers to the architecture.
+pub type Arch = HostArch;
 
-pub struct ArchThreadState;
+kernel::impl_thread_arg_for_default_zst!(HostArch);
 
-impl Arch for HostArch {
+impl kernel::Arch for HostArch {
     type ThreadState = ArchThreadState;
     type BareSpinLock = spinlock::BareSpinLock;
     type Clock = Clock;
     type AtomicBool = core::sync::atomic::AtomicBool;
     type AtomicUsize = core::sync::atomic::AtomicUsize;
-    type SyscallArgs<'a> = HostSyscallArgs;
+    type SyscallArgs<'a> = HostSyscallArgs<'a>;
     type InterruptController = HostInterruptController;
 
     unsafe fn context_switch(
         self,
-        _sched_state: SpinLockGuard<'_, Self, SchedulerState<Self>>,
-        _old_thread_state: *mut ArchThreadState,
-        _new_thread_state: *mut ArchThreadState,
+        sched_state: SpinLockGuard<'_, Self, SchedulerState<Self>>,
+        old_thread_state: *mut ArchThreadState,
+        new_thread_state: *mut ArchThreadState,
     ) -> SpinLockGuard<'_, Self, SchedulerState<Self>> {
-        pw_assert::panic!("Unimplemented: context_switch");
+        unsafe { threads::context_switch(sched_state, old_thread_state, new_thread_state) }
     }
 
     fn thread_local_state(self) -> &'static kernel::scheduler::ThreadLocalState<Self> {
-        pw_assert::panic!("Unimplemented: thread_local_state");
+        threads::thread_local_state()
     }
 
     fn now(self) -> time::Instant<Clock> {
@@ -59,15 +78,29 @@ impl Arch for HostArch {
         Clock::now()
     }
 
+    fn idle(self, wakeup: Option<time::Instant<Clock>>) {
+        timer::idle(wakeup);
+    }
+
     fn early_init(self) {
         info!("Host architecture early initialization");
+        timer::early_init();
     }
+
     fn init(self) {
         info!("Host architecture initialization");
+        timer::init();
+    }
+
+    fn panic() -> ! {
+        kernel::panic_flush();
+        std::process::abort()
     }
 }
 
 impl Kernel for HostArch {
+    type SchedulerAlgorithm = kernel::scheduler::algorithm::TargetSchedulerAlgorithm<Self>;
+
     fn get_state(self) -> &'static KernelState<HostArch> {
         static STATE: KernelState<HostArch> =
             KernelState::new(kernel::ArchState::new(HostInterruptController::new()));
@@ -75,47 +108,27 @@ impl Kernel for HostArch {
     }
 }
 
-impl ThreadState for ArchThreadState {
-    const NEW: Self = Self;
-    type MemoryConfig = MemoryConfig;
-
-    unsafe fn initialize_kernel_frame(
-        &mut self,
-        _kernel_stack: Stack,
-        _memory_config: *const MemoryConfig,
-        _initial_function: extern "C" fn(usize, usize, usize),
-        _args: (usize, usize, usize),
-    ) {
-        pw_assert::panic!("Unimplemented: initialize_kernel_frame");
-    }
-
-    #[cfg(feature = "user_space")]
-    unsafe fn initialize_user_frame(
-        &mut self,
-        _kernel_stack: Stack,
-        _memory_config: *const MemoryConfig,
-        _initial_sp: usize,
-        _initial_pc: usize,
-        _args: (usize, usize, usize),
-    ) -> Result<()> {
-        pw_assert::panic!("Unimplemented: initialize_user_frame");
-    }
+/// Memory configuration of a thread.
+///
+/// User space shares the kernel's address space on the host and keeps its
+/// buffers on its OS thread's stack, so user space threads may access any
+/// memory.
+pub struct MemoryConfig {
+    user_space: bool,
 }
 
-pub struct Clock;
-
-impl time::Clock for Clock {
-    const TICKS_PER_SEC: u64 = 1_000_000;
-
-    fn now() -> time::Instant<Self> {
-        time::Instant::from_ticks(0)
+impl MemoryConfig {
+    /// Creates a user space memory configuration.
+    ///
+    /// `regions` are those from the system config and are not enforced.
+    #[must_use]
+    pub const fn const_new(_regions: &'static [MemoryRegion]) -> Self {
+        Self { user_space: true }
     }
 }
 
-pub struct MemoryConfig;
-
 impl memory_config::MemoryConfig for MemoryConfig {
-    const KERNEL_THREAD_MEMORY_CONFIG: Self = Self;
+    const KERNEL_THREAD_MEMORY_CONFIG: Self = Self { user_space: false };
 
     fn range_has_access(
         &self,
@@ -123,87 +136,6 @@ impl memory_config::MemoryConfig for MemoryConfig {
         _start_addr: usize,
         _end_addr: usize,
     ) -> bool {
-        false
-    }
-}
-
-pub struct HostSyscallArgs;
-impl<'a> kernel::syscall::SyscallArgs<'a> for HostSyscallArgs {
-    fn next_usize(&mut self) -> Result<usize> {
-        Err(Error::Unimplemented)
-    }
-
-    fn next_u64(&mut self) -> Result<u64> {
-        Err(Error::Unimplemented)
-    }
-}
-
-pub struct HostInterruptController {}
-
-impl HostInterruptController {
-    #[must_use]
-    pub const fn new() -> Self {
-        Self {}
-    }
-}
-
-impl InterruptController for HostInterruptController {
-    fn early_init(&self) {
-        pw_assert::panic!("Unimplemented: early_init");
-    }
-
-    fn enable_interrupt(_irq: u32) {
-        pw_assert::panic!("Unimplemented: enable_interrupt");
-    }
-
-    fn disable_interrupt(_irq: u32) {
-        pw_assert::panic!("Unimplemented: disable_interrupt");
-    }
-
-    fn userspace_interrupt_ack(_irq: u32) {
-        pw_assert::panic!("Unimplemented: userspace_interrupt_ack");
-    }
-
-    fn userspace_interrupt_handler_enter<K: Kernel>(
-        _kernel: K,
-        _irq: u32,
-    ) -> PreemptDisableGuard<K> {
-        pw_assert::panic!("Unimplemented: userspace_interrupt_handler_enter");
-    }
-
-    fn userspace_interrupt_handler_exit<K: Kernel>(
-        _kernel: K,
-        _irq: u32,
-        _preempt_guard: PreemptDisableGuard<K>,
-    ) {
-        pw_assert::panic!("Unimplemented: userspace_interrupt_handler_exit");
-    }
-
-    fn kernel_interrupt_handler_enter<K: Kernel>(_kernel: K, _irq: u32) -> PreemptDisableGuard<K> {
-        pw_assert::panic!("Unimplemented: kernel_interrupt_handler_enter");
-    }
-
-    fn kernel_interrupt_handler_exit<K: Kernel>(
-        _kernel: K,
-        _irq: u32,
-        _preempt_guard: PreemptDisableGuard<K>,
-    ) {
-        pw_assert::panic!("Unimplemented: kernel_interrupt_handler_exit");
-    }
-
-    fn enable_interrupts() {
-        pw_assert::panic!("Unimplemented: enable_interrupts");
-    }
-
-    fn disable_interrupts() {
-        pw_assert::panic!("Unimplemented: disable_interrupts");
-    }
-
-    fn interrupts_enabled() -> bool {
-        pw_assert::panic!("Unimplemented: interrupts_enabled");
-    }
-
-    fn trigger_interrupt(_irq: u32) {
-        pw_assert::panic!("Unimplemented: trigger_interrupt");
+        self.user_space
     }
 }
@@ -11,19 +11,56 @@
 // WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
 // License for the specific language governing permissions and limitations under
 // the License.
+
 use core::sync::atomic::{AtomicBool, Ordering};
 
-pub struct AtomicSpinLockGuard<'a> {
+use crate::interrupt_controller;
+
+/// Disables simulated interrupts for its lifetime.
+pub struct InterruptGuard {
+    saved_interrupt_enable: bool,
+}
+
+impl InterruptGuard {
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            saved_interrupt_enable: interrupt_controller::save_and_disable(),
+        }
+    }
+}
+
+impl Default for InterruptGuard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for InterruptGuard {
+    #[inline]
+    fn drop(&mut self) {
+        interrupt_controller::restore(self.saved_interrupt_enable);
+    }
+}
+
+pub struct HostSpinLockGuard<'a> {
     lock: &'a BareSpinLock,
+    _guard: InterruptGuard,
 }
 
-impl Drop for AtomicSpinLockGuard<'_> {
+impl Drop for HostSpinLockGuard<'_> {
     fn drop(&mut self) {
         self.lock.unlock();
     }
 }
 
+/// Non-SMP bare spinlock
+///
+/// Only one kernel thread runs at a time, so, as on a uniprocessor, disabling
+/// interrupts is sufficient for exclusive access.
 pub struct BareSpinLock {
+    // Lock state is needed to support `try_lock()` semantics.
     locked: AtomicBool,
 }
 
@@ -34,7 +71,7 @@ impl BareSpinLock {
         }
     }
 
-    // Only to be called by AtomicSpinlockSentinel::drop().
+    // Only to be called by HostSpinLockGuard::drop().
     fn unlock(&self) {
         self.locked.store(false, Ordering::Release);
     }
@@ -47,15 +84,31 @@ impl Default for BareSpinLock {
 }
 
 impl kernel::sync::spinlock::BareSpinLock for BareSpinLock {
-    type Guard<'a> = AtomicSpinLockGuard<'a>;
+    type Guard<'a> = HostSpinLockGuard<'a>;
 
     #[allow(clippy::declare_interior_mutable_const)]
     const NEW: BareSpinLock = Self::new();
 
     fn try_lock(&self) -> Option<Self::Guard<'_>> {
-        self.locked
-            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
-            .map(|_| AtomicSpinLockGuard { lock: self })
-            .ok()
+        let guard = InterruptGuard::new();
+        if self.locked.swap(true, Ordering::Acquire) {
+            return None;
+        }
+        Some(HostSpinLockGuard {
+            lock: self,
+            _guard: guard,
+        })
+    }
+
+    fn lock(&self) -> Self::Guard<'_> {
+        let guard = InterruptGuard::new();
+        // For the uniprocessor version of the spinlock, there is no need to
+        // spin.  The lock may still be marked as held by a thread which
+        // switched away with it held.
+        self.locked.store(true, Ordering::Relaxed);
+        HostSpinLockGuard {
+            lock: self,
+            _guard: guard,
+        }
     }
 }
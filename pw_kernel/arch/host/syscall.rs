@@ -0,0 +1,61 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+//! System call entry.
+//!
+//! User space threads share the kernel's address space on the host, so
+//! system calls do not trap.  The `syscall_user` veneers call
+//! [`pw_kernel_host_syscall()`] directly, passing the arguments as a slice of
+//! `u64` slots.
+
+use kernel::syscall::{SyscallArgs, raw_handle_syscall};
+use pw_status::{Error, Result};
+
+use crate::HostArch;
+
+pub struct HostSyscallArgs<'a> {
+    args: &'a [u64],
+    cur_index: usize,
+}
+
+impl<'a> HostSyscallArgs<'a> {
+    fn new(args: &'a [u64]) -> Self {
+        Self { args, cur_index: 0 }
+    }
+}
+
+impl<'a> SyscallArgs<'a> for HostSyscallArgs<'a> {
+    fn next_usize(&mut self) -> Result<usize> {
+        let value = self.next_u64()?;
+        usize::try_from(value).map_err(|_| Error::InvalidArgument)
+    }
+
+    fn next_u64(&mut self) -> Result<u64> {
+        let value = *self
+            .args
+            .get(self.cur_index)
+            .ok_or(Error::InvalidArgument)?;
+        self.cur_index += 1;
+        Ok(value)
+    }
+}
+
+/// Handles system call `id` made by the current user space thread.
+///
+/// Returns the packed [`syscall_defs::SysCallReturnValue`].  Does not return
+/// if the calling thread is terminated during the system call.
+#[unsafe(no_mangle)]
+pub fn pw_kernel_host_syscall(id: u16, args: &[u64]) -> i64 {
+    raw_handle_syscall(HostArch, id, HostSyscallArgs::new(args))
+}
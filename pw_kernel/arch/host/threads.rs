@@ -0,0 +1,219 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+//! Kernel threads backed by host OS threads.
+//!
+//! Each kernel thread runs on its own OS thread, but only one of them runs at
+//! a time.  A context switch resumes the new thread's OS thread and then
+//! blocks the old one until it is switched back to, handing off the
+//! (simulated) single CPU.
+
+extern crate std;
+
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+
+use kernel::interrupt_controller::InterruptController as _;
+use kernel::scheduler::thread::{Stack, ThreadState};
+use kernel::scheduler::{self, SchedulerState, ThreadLocalState};
+use kernel::sync::spinlock::SpinLockGuard;
+#[cfg(feature = "user_space")]
+use pw_status::Result;
+
+use crate::interrupt_controller::HostInterruptController;
+use crate::{HostArch, MemoryConfig};
+
+static BOOT_THREAD_LOCAL_STATE: ThreadLocalState<HostArch> = ThreadLocalState::new();
+static THREAD_LOCAL_STATE: AtomicPtr<ThreadLocalState<HostArch>> =
+    AtomicPtr::new(ptr::from_ref(&BOOT_THREAD_LOCAL_STATE).cast_mut());
+
+struct RunState {
+    // Incremented each time the thread is (re)initialized.  OS threads from a
+    // previous incarnation of the thread never run again.
+    generation: usize,
+
+    // Set when the thread is switched to, cleared once it is running.
+    resumed: bool,
+}
+
+pub struct ArchThreadState {
+    local: ThreadLocalState<HostArch>,
+    run_state: Mutex<RunState>,
+    resume_condvar: Condvar,
+}
+
+impl ArchThreadState {
+    fn lock_run_state(&self) -> MutexGuard<'_, RunState> {
+        self.run_state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn resume(&self) {
+        self.lock_run_state().resumed = true;
+        self.resume_condvar.notify_all();
+    }
+
+    // Blocks the calling OS thread until the thread is switched to.  Never
+    // returns if the thread is re-initialized in the meantime.
+    fn wait_for_resume(&self, generation: usize) {
+        let mut run_state = self.lock_run_state();
+        loop {
+            if run_state.generation != generation {
+                drop(run_state);
+                loop {
+                    std::thread::park();
+                }
+            }
+            if run_state.resumed {
+                run_state.resumed = false;
+                return;
+            }
+            run_state = self
+                .resume_condvar
+                .wait(run_state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+}
+
+pub(crate) unsafe fn context_switch<'a>(
+    sched_state: SpinLockGuard<'a, HostArch, SchedulerState<HostArch>>,
+    old_thread_state: *mut ArchThreadState,
+    new_thread_state: *mut ArchThreadState,
+) -> SpinLockGuard<'a, HostArch, SchedulerState<HostArch>> {
+    // SAFETY: The scheduler guarantees both thread states are valid for the
+    // duration of the switch.
+    let (old_thread_state, new_thread_state) = unsafe { (&*old_thread_state, &*new_thread_state) };
+
+    THREAD_LOCAL_STATE.store(
+        ptr::from_ref(&new_thread_state.local).cast_mut(),
+        Ordering::SeqCst,
+    );
+
+    // The generation is read before resuming the new thread, which may
+    // re-initialize the old thread if it is exiting.
+    let generation = old_thread_state.lock_run_state().generation;
+    new_thread_state.resume();
+    old_thread_state.wait_for_resume(generation);
+
+    sched_state
+}
+
+pub(crate) fn thread_local_state() -> &'static ThreadLocalState<HostArch> {
+    // SAFETY: The pointer always refers to either the boot thread local state
+    // or that of the current thread, which outlives its time as the current
+    // thread.
+    unsafe { &*THREAD_LOCAL_STATE.load(Ordering::SeqCst) }
+}
+
+// Wraps a thread state pointer so it can be moved to the OS thread running it.
+struct ThreadStatePtr(*const ArchThreadState);
+
+// SAFETY: The thread state is only accessed through its `Sync` members.
+unsafe impl Send for ThreadStatePtr {}
+
+fn trampoline(initial_function: extern "C" fn(usize, usize, usize), args: (usize, usize, usize)) {
+    // Threads are switched to with the scheduler lock held and, therefore,
+    // interrupts disabled.
+    HostInterruptController::enable_interrupts();
+
+    initial_function(args.0, args.1, args.2);
+
+    // Does not return.
+    scheduler::exit_thread(HostArch);
+}
+
+#[cfg(feature = "user_space")]
+fn user_trampoline(
+    initial_function: extern "C" fn(usize, usize, usize) -> !,
+    args: (usize, usize, usize),
+) {
+    HostInterruptController::enable_interrupts();
+
+    // User space threads exit through a system call.
+    initial_function(args.0, args.1, args.2);
+}
+
+impl ArchThreadState {
+    // Starts a new OS thread which runs `f` once the thread is first switched
+    // to.
+    fn spawn<F: FnOnce() + Send + 'static>(&mut self, f: F) {
+        // The OS thread runs on its own stack rather than the kernel stack.
+        let generation = {
+            let mut run_state = self.lock_run_state();
+            run_state.generation += 1;
+            run_state.resumed = false;
+            run_state.generation
+        };
+        // Retire any OS thread left over from a previous incarnation.
+        self.resume_condvar.notify_all();
+
+        let thread_state = ThreadStatePtr(ptr::from_ref(self));
+        let spawn_result = std::thread::Builder::new().spawn(move || {
+            let thread_state = thread_state;
+            // SAFETY: Thread states live in statically allocated threads.
+            unsafe { &*thread_state.0 }.wait_for_resume(generation);
+            f();
+        });
+        if spawn_result.is_err() {
+            pw_assert::panic!("Failed to spawn host thread");
+        }
+    }
+}
+
+impl ThreadState for ArchThreadState {
+    const NEW: Self = Self {
+        local: ThreadLocalState::new(),
+        run_state: Mutex::new(RunState {
+            generation: 0,
+            resumed: false,
+        }),
+        resume_condvar: Condvar::new(),
+    };
+    type MemoryConfig = MemoryConfig;
+
+    unsafe fn initialize_kernel_frame(
+        &mut self,
+        _kernel_stack: Stack,
+        _memory_config: *const MemoryConfig,
+        initial_function: extern "C" fn(usize, usize, usize),
+        args: (usize, usize, usize),
+    ) {
+        self.spawn(move || trampoline(initial_function, args));
+    }
+
+    #[cfg(feature = "user_space")]
+    unsafe fn initialize_user_frame(
+        &mut self,
+        _kernel_stack: Stack,
+        _memory_config: *const MemoryConfig,
+        _initial_sp: usize,
+        initial_pc: usize,
+        args: (usize, usize, usize),
+    ) -> Result<()> {
+        // User space code is linked into the kernel's executable, so
+        // `initial_pc` is the address of an `extern "C"` function.  Entry
+        // points taking fewer than three arguments ignore the extras.  The
+        // user stack is unused as the OS thread runs on its own stack.
+        //
+        // SAFETY: The caller guarantees `initial_pc` is a valid entry point.
+        let initial_function = unsafe {
+            core::mem::transmute::<usize, extern "C" fn(usize, usize, usize) -> !>(initial_pc)
+        };
+        self.spawn(move || user_trampoline(initial_function, args));
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+//! Host monotonic clock and simulated scheduler tick.
+//!
+//! The scheduler tick is not driven by a real interrupt.  Instead it is
+//! delivered on the running kernel thread the next time interrupts are
+//! enabled after the tick period has elapsed.  A thread spinning without
+//! ever entering the kernel is therefore never preempted.
+
+extern crate std;
+
+use core::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+
+use kernel::interrupt_controller::InterruptController as _;
+use kernel::scheduler;
+use time::{Duration, Instant};
+
+use crate::interrupt_controller::HostInterruptController;
+
+const TICK_PERIOD: Duration<Clock> = Duration::from_millis(1);
+
+// Time of the next scheduler tick.  Ticks are not delivered until `init()`.
+static NEXT_TICK: AtomicU64 = AtomicU64::new(u64::MAX);
+
+static EPOCH: OnceLock<std::time::Instant> = OnceLock::new();
+
+pub struct Clock;
+
+impl time::Clock for Clock {
+    const TICKS_PER_SEC: u64 = 1_000_000;
+
+    fn now() -> Instant<Self> {
+        let elapsed = EPOCH.get_or_init(std::time::Instant::now).elapsed();
+        Instant::from_ticks(u64::try_from(elapsed.as_micros()).unwrap_or(u64::MAX))
+    }
+}
+
+fn now() -> Instant<Clock> {
+    use time::Clock as _;
+    Clock::now()
+}
+
+pub fn early_init() {
+    let _ = now();
+}
+
+pub fn init() {
+    NEXT_TICK.store((now() + TICK_PERIOD).ticks(), Ordering::SeqCst);
+}
+
+/// Runs the scheduler tick if it is due.
+///
+/// Called with interrupts enabled.  The tick runs with interrupts disabled,
+/// as it would in a timer interrupt handler, and may switch to another thread
+/// before returning.
+pub fn deliver_pending_tick() {
+    let now = now();
+    if now.ticks() < NEXT_TICK.load(Ordering::SeqCst) {
+        return;
+    }
+    // Ticks missed while interrupts were disabled are coalesced into one.
+    NEXT_TICK.store((now + TICK_PERIOD).ticks(), Ordering::SeqCst);
+
+    HostInterruptController::disable_interrupts();
+    scheduler::tick(crate::HostArch, now);
+    // Enable interrupts directly rather than through the interrupt controller
+    // to avoid recursing into another tick.
+    crate::interrupt_controller::enable_without_delivery();
+}
+
+/// Sleeps until the next scheduler tick or, if later, `wakeup`.
+///
+/// Called with interrupts disabled from the idle thread.  The tick is
+/// delivered when the idle thread re-enables interrupts.
+pub fn idle(wakeup: Option<Instant<Clock>>) {
+    let next_tick = NEXT_TICK.load(Ordering::SeqCst);
+    let deadline = match wakeup {
+        Some(wakeup) => wakeup.ticks().max(next_tick),
+        None => next_tick,
+    };
+    let now = now().ticks();
+    if deadline > now {
+        std::thread::sleep(core::time::Duration::from_micros(deadline - now));
+    }
+}
@@ -32,6 +32,8 @@ use crate::MemoryConfig;
 use crate::regs::{
     Cause, Exception, Interrupt, MCause, MCauseVal, MStatus, MtVal, MtVec, MtVecMode,
 };
+#[cfg(feature = "user_space")]
+use crate::regs::{MStatusVal, PrivilegeLevel};
 use crate::{plic, timer};
 
 const LOG_EXCEPTIONS: bool = false;
@@ -161,6 +163,17 @@ fn exception_handler(exception: Exception, mepc: usize, frame: &mut TrapFrame) {
         Exception::EnvironmentCallFromUMode | Exception::EnvironmentCallFromMMode => {
             handle_ecall(frame);
         }
+        // Faults taken in user space terminate the offending process.  The
+        // faulting thread exits before returning from the trap.
+        #[cfg(feature = "user_space")]
+        _ if matches!(MStatusVal(frame.status).mpp(), PrivilegeLevel::User) => {
+            kernel::scheduler::process_manager::fault_current_process(
+                super::Arch,
+                Some(mepc),
+                exception as usize,
+                Some(MtVal::read().0),
+            );
+        }
         Exception::Breakpoint => {
             dump_exception_frame(frame);
             #[allow(clippy::empty_loop)]
@@ -217,6 +230,13 @@ unsafe extern "C" fn trap_handler(mcause: MCauseVal, mepc: usize, frame: &mut Tr
         Cause::Exception(exception) => exception_handler(exception, mepc, frame),
     }
 
+    // A thread which was terminated while preempted in user space exits
+    // instead of returning to it.
+    #[cfg(feature = "user_space")]
+    if matches!(MStatusVal(frame.status).mpp(), PrivilegeLevel::User) {
+        kernel::scheduler::exit_if_terminating(crate::Arch);
+    }
+
     #[cfg(feature = "exceptions_reload_pmp")]
     {
         // When we exit the trap, get the current thread and load its memory
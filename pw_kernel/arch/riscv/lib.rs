@@ -37,9 +37,12 @@ pub struct Arch;
 kernel::impl_thread_arg_for_default_zst!(Arch);
 
 impl kernel::Kernel for Arch {
+    type SchedulerAlgorithm = kernel::scheduler::algorithm::TargetSchedulerAlgorithm<Self>;
+
     fn get_state(self) -> &'static KernelState<Arch> {
         static STATE: KernelState<Arch> =
             KernelState::new(kernel::ArchState::new(plic::Plic::new()));
+        kernel::annotate_threads!(Arch, &raw const STATE);
         &STATE
     }
 }
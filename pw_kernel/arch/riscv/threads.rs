@@ -178,8 +178,12 @@ impl Arch for super::Arch {
         super::timer::Clock::now()
     }
 
-    fn idle(self) {
+    fn idle(self, wakeup: Option<time::Instant<super::timer::Clock>>) {
+        let suppressed = wakeup.filter(|wakeup| super::timer::suppress_tick(*wakeup));
         riscv::asm::wfi();
+        if let Some(wakeup) = suppressed {
+            super::timer::resume_tick(wakeup);
+        }
     }
 
     fn early_init(self) {
@@ -196,6 +200,7 @@ impl Arch for super::Arch {
     }
 
     fn panic() -> ! {
+        kernel::panic_flush();
         unsafe {
             asm!("ebreak");
         }
@@ -213,6 +218,9 @@ impl kernel::scheduler::thread::ThreadState for ArchThreadState {
         local: ThreadLocalState::new(),
     };
 
+    const SAVED_FRAME_OFFSET: Option<usize> = Some(mem::offset_of!(Self, frame));
+    const SAVED_PC_OFFSET: usize = mem::offset_of!(ContextSwitchFrame, ra);
+
     #[inline(never)]
     unsafe fn initialize_kernel_frame(
         &mut self,
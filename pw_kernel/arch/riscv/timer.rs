@@ -13,8 +13,8 @@
 // the License.
 
 use kernel::scheduler;
-use kernel_config::{KernelConfig, RiscVKernelConfigInterface};
-use time::Clock as _;
+use kernel_config::{KernelConfig, KernelConfigInterface, RiscVKernelConfigInterface};
+use time::{Clock as _, Duration, Instant};
 
 #[cfg(feature = "timer_mtime")]
 mod mtime;
@@ -33,6 +33,8 @@ trait TimerInterface {
     fn disable();
     fn get_current_monotonic_tick() -> u64;
     fn set_next_monotonic_tick();
+    /// Programs the timer to fire at `deadline` instead of the next tick.
+    fn set_deadline(deadline: u64);
 }
 
 pub struct Clock;
@@ -58,3 +60,32 @@ pub fn mtimer_tick() {
 
     scheduler::tick(crate::Arch, Clock::now());
 }
+
+/// Programs the timer to fire at `wakeup` instead of the next scheduler tick.
+///
+/// Returns `false` and leaves the periodic tick in place if `wakeup` is before
+/// the next scheduler tick.  Must be called with interrupts disabled and paired
+/// with a call to [`resume_tick`] before they are re-enabled.
+pub fn suppress_tick(wakeup: Instant<Clock>) -> bool {
+    let tick_period: Duration<Clock> =
+        Duration::from_millis((1000 / KernelConfig::SCHEDULER_TICK_HZ).into());
+    if Clock::now()
+        .checked_add_duration(tick_period)
+        .is_none_or(|next_tick| wakeup <= next_tick)
+    {
+        return false;
+    }
+
+    Timer::set_deadline(wakeup.ticks());
+    true
+}
+
+/// Restores the periodic tick after [`suppress_tick`].
+pub fn resume_tick(wakeup: Instant<Clock>) {
+    // Once `wakeup` has been reached the pending timer interrupt reprograms
+    // the periodic tick itself.  Reprogramming it here would acknowledge the
+    // interrupt and delay processing of the expired timers by a tick.
+    if Clock::now() < wakeup {
+        Timer::set_next_monotonic_tick();
+    }
+}
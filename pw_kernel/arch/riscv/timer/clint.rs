@@ -71,6 +71,10 @@ impl TimerInterface for Timer {
             pw_assert::debug_panic!("Next monotonic tick overflow");
         }
     }
+
+    fn set_deadline(deadline: u64) {
+        write_mtimecmp(deadline);
+    }
 }
 
 fn write_mtimecmp(value: u64) {
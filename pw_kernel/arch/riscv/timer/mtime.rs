@@ -96,6 +96,12 @@ impl TimerInterface for Timer {
             pw_assert::debug_panic!("Next monotonic tick overflow");
         }
     }
+
+    fn set_deadline(deadline: u64) {
+        let _guard = InterruptGuard::new();
+        ack_timer();
+        write_mtimecmp(deadline);
+    }
 }
 
 fn write_mtimecmp(value: u64) {
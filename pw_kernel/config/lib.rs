@@ -19,9 +19,20 @@ pub trait KernelConfigInterface {
     /// Rate of the scheduler monotonic tick.
     const SCHEDULER_TICK_HZ: u32 = 100;
 
+    /// Suppress the scheduler tick while the system is idle.
+    ///
+    /// When enabled and no thread is ready to run, the idle thread programs
+    /// the timer to fire at the earliest timer deadline instead of at the
+    /// next scheduler tick.
+    const TICKLESS_IDLE: bool = false;
+
     /// The number of bytes allocated for each kernel stack.
     const KERNEL_STACK_SIZE_BYTES: usize = 2048;
 
+    /// Percentage of a thread's kernel stack which, once used, causes the
+    /// kernel's periodic stack check to log a warning for the thread.
+    const STACK_WARNING_THRESHOLD_PERCENT: usize = 80;
+
     /// The native rate at which the system clock advances.
     const SYSTEM_CLOCK_HZ: u64;
 }
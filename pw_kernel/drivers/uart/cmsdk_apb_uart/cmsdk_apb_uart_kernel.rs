@@ -15,17 +15,31 @@
 
 use circular_buffer::CircularBuffer;
 use kernel::Kernel;
+use kernel::char_device::{CharDevice, CharDeviceListener};
 use kernel::interrupt_controller::InterruptController;
+use kernel::sync::event::{Event, EventConfig};
 use kernel::sync::spinlock::SpinLock;
 use pw_status::Result;
 
 const LOG_UART: bool = true;
 
 const BUFFER_SIZE: usize = 16;
+
+/// A CMSDK APB UART with interrupt driven, buffered reads and writes.
+///
+/// Bytes written to the UART are queued in a write buffer which is drained
+/// into the transmitter from the TX interrupt.  Writers only block when the
+/// write buffer is full.
 pub struct Uart<K: Kernel> {
+    kernel: K,
     base_address: usize,
     irq: u32,
     read_buffer: SpinLock<K, CircularBuffer<u8, BUFFER_SIZE>>,
+    write_buffer: SpinLock<K, CircularBuffer<u8, BUFFER_SIZE>>,
+    // Signaled by the interrupt handler whenever space is freed in
+    // `write_buffer`.
+    write_space: Event<K>,
+    listener: SpinLock<K, Option<&'static dyn CharDeviceListener<K>>>,
 }
 
 impl<K: Kernel> regs::BaseAddress for Uart<K> {
@@ -38,11 +52,15 @@ impl<K: Kernel> cmsdk_apb_uart_regs::CmsdkApbUartBaseAddress for Uart<K> {}
 
 impl<K: Kernel> Uart<K> {
     #[must_use]
-    pub const fn new(base_address: usize, irq: u32) -> Uart<K> {
+    pub const fn new(kernel: K, base_address: usize, irq: u32) -> Uart<K> {
         Self {
+            kernel,
             base_address,
             irq,
             read_buffer: SpinLock::new(CircularBuffer::new()),
+            write_buffer: SpinLock::new(CircularBuffer::new()),
+            write_space: Event::new(kernel, EventConfig::AutoReset),
+            listener: SpinLock::new(None),
         }
     }
 
@@ -51,17 +69,88 @@ impl<K: Kernel> Uart<K> {
         Ok(self.read_buffer.lock(kernel).pop_front())
     }
 
+    /// Queues `value` for transmission.
+    ///
+    /// Blocks if the write buffer is full.  See [`Uart::write_all`].
     pub fn write(&self, value: u8) -> Result<()> {
         log_if::debug_if!(LOG_UART, "uart write: {}", value as u8);
+        self.write_all(&[value])
+    }
+
+    /// Queues all of `data` for transmission.
+    ///
+    /// Blocks until every byte has been queued in the write buffer, waiting
+    /// for the interrupt handler to make room whenever the buffer is full.
+    /// Returns once the last byte is queued, not once it has been transmitted;
+    /// use [`Uart::flush`] to hand the queued bytes to the transmitter.
+    ///
+    /// # Interrupt context
+    ///
+    /// This method is *not* safe to call in an interrupt context.
+    pub fn write_all(&self, data: &[u8]) -> Result<()> {
+        let mut remaining = data;
+        while !remaining.is_empty() {
+            remaining = &remaining[self.queue(remaining)..];
+            if !remaining.is_empty() {
+                log_if::debug_if!(LOG_UART, "write buffer full, waiting");
+                self.write_space.wait()?;
+            }
+        }
+        Ok(())
+    }
 
+    /// Hands all buffered bytes to the transmitter by polling it.
+    ///
+    /// Returns once the write buffer is empty and the transmitter has room
+    /// for another byte.  The CMSDK UART does not report when its shift
+    /// register is empty, so the last byte may still be on the wire.
+    ///
+    /// Does not rely on interrupts or the scheduler, making it suitable for
+    /// panic paths.  If the write buffer is locked, as can happen when the
+    /// panic occurred while it was held, the buffered bytes are dropped rather
+    /// than risking a deadlock.
+    pub fn flush(&self) {
         let state_reg = cmsdk_apb_uart_regs::State;
+        if let Some(mut write_buffer) = self.write_buffer.try_lock(self.kernel) {
+            while let Some(byte) = write_buffer.pop_front() {
+                while state_reg.read(self).tx_buffer_full() {}
+                let mut data_reg = cmsdk_apb_uart_regs::Data;
+                data_reg.write(self, cmsdk_apb_uart_regs::DataValue(byte));
+            }
+        }
         while state_reg.read(self).tx_buffer_full() {}
+    }
 
-        let mut data_reg = cmsdk_apb_uart_regs::Data;
-        data_reg.write(self, cmsdk_apb_uart_regs::DataValue(value));
+    // Queues as many bytes of `data` as fit in the write buffer, returning
+    // the number queued.
+    fn queue(&self, data: &[u8]) -> usize {
+        let mut write_buffer = self.write_buffer.lock(self.kernel);
+        let mut queued = 0;
+        for &byte in data {
+            if write_buffer.push_back(byte).is_err() {
+                break;
+            }
+            queued += 1;
+        }
+        self.start_tx(&mut write_buffer);
+        queued
+    }
 
-        log_if::debug_if!(LOG_UART, "done");
-        Ok(())
+    fn listener(&self, kernel: K) -> Option<&'static dyn CharDeviceListener<K>> {
+        *self.listener.lock(kernel)
+    }
+
+    // Moves bytes from the write buffer into the transmitter while it has
+    // room.  Must be called with the write buffer lock held.
+    fn start_tx(&self, write_buffer: &mut CircularBuffer<u8, BUFFER_SIZE>) {
+        let state_reg = cmsdk_apb_uart_regs::State;
+        while !state_reg.read(self).tx_buffer_full() {
+            let Some(byte) = write_buffer.pop_front() else {
+                break;
+            };
+            let mut data_reg = cmsdk_apb_uart_regs::Data;
+            data_reg.write(self, cmsdk_apb_uart_regs::DataValue(byte));
+        }
     }
 
     pub fn interrupt_handler(&self, kernel: K) {
@@ -78,11 +167,16 @@ impl<K: Kernel> Uart<K> {
             log_if::debug_if!(LOG_UART, "RX interrupt");
 
             let state_reg = cmsdk_apb_uart_regs::State;
+            let mut received = false;
             while state_reg.read(self).rx_buffer_full() {
                 let data_reg = cmsdk_apb_uart_regs::Data;
                 let value = data_reg.read(self);
                 log_if::debug_if!(LOG_UART, "data ready: {}", value.data() as u8);
                 let _ = self.read_buffer.lock(kernel).push_back(value.data());
+                received = true;
+            }
+            if received && let Some(listener) = self.listener(kernel) {
+                listener.readable(kernel);
             }
             let mut int_clear_reg = cmsdk_apb_uart_regs::IntClear;
             let int_clear = cmsdk_apb_uart_regs::IntClearValue(0).with_rx_interrupt(true);
@@ -95,7 +189,43 @@ impl<K: Kernel> Uart<K> {
             let mut int_clear_reg = cmsdk_apb_uart_regs::IntClear;
             let int_clear = cmsdk_apb_uart_regs::IntClearValue(0).with_tx_interrupt(true);
             int_clear_reg.write(self, int_clear);
+
+            let mut write_buffer = self.write_buffer.lock(kernel);
+            let buffered = write_buffer.len();
+            self.start_tx(&mut write_buffer);
+            let freed_space = write_buffer.len() < buffered;
+            drop(write_buffer);
+
+            if freed_space {
+                self.write_space.get_signaler().signal();
+                if let Some(listener) = self.listener(kernel) {
+                    listener.writable(kernel);
+                }
+            }
+        }
+    }
+}
+
+impl<K: Kernel> CharDevice<K> for Uart<K> {
+    fn read(&self, kernel: K, buffer: &mut [u8]) -> Result<usize> {
+        let mut read_buffer = self.read_buffer.lock(kernel);
+        let mut len = 0;
+        for byte in buffer.iter_mut() {
+            let Some(value) = read_buffer.pop_front() else {
+                break;
+            };
+            *byte = value;
+            len += 1;
         }
+        Ok(len)
+    }
+
+    fn write(&self, _kernel: K, buffer: &[u8]) -> Result<usize> {
+        Ok(self.queue(buffer))
+    }
+
+    fn set_listener(&self, kernel: K, listener: &'static dyn CharDeviceListener<K>) {
+        *self.listener.lock(kernel) = Some(listener);
     }
 }
 
@@ -26,17 +26,22 @@ pub mod __private {
     pub use paste::paste;
 }
 
+/// Declares statically allocated kernel UARTs and their interrupt handlers.
+///
+/// Also declares `flush_<uarts_array>()`, which flushes every UART and can be
+/// registered with `kernel::set_panic_flush_handler()` so that output
+/// buffered by the UARTs is not lost on a panic.
 #[macro_export]
 macro_rules! declare_uarts {
     (
-        $arch:ty,
+        $arch:ident,
         $uarts_array:ident,
         $uart_type:ty,
         [ $( $uart_name:ident: $config:ident),* $(,)? ]
     ) => {
         $crate::__private::paste! {
             $(
-                static $uart_name: $uart_type<$arch> = $uart_type::new($config::BASE_ADDRESS, $config::IRQ);
+                static $uart_name: $uart_type<$arch> = $uart_type::new($arch, $config::BASE_ADDRESS, $config::IRQ);
                 fn [<interrupt_handler_$uart_name:lower>](kernel: $arch) {
                     $uart_name.interrupt_handler(kernel);
                 }
@@ -44,6 +49,9 @@ macro_rules! declare_uarts {
             pub static $uarts_array: [&$uart_type<$arch>; [$(stringify!($uart_name)),*].len()] = [
                 $( &$uart_name ),*
             ];
+            pub fn [<flush_ $uarts_array:lower>]() {
+                $( $uart_name.flush(); )*
+            }
         }
     };
 }
@@ -15,17 +15,31 @@
 
 use circular_buffer::CircularBuffer;
 use kernel::Kernel;
+use kernel::char_device::{CharDevice, CharDeviceListener};
 use kernel::interrupt_controller::InterruptController;
+use kernel::sync::event::{Event, EventConfig};
 use kernel::sync::spinlock::SpinLock;
 use pw_status::Result;
 
 const LOG_UART: bool = false;
 
 const BUFFER_SIZE: usize = 128;
+
+/// A 16550 UART with interrupt driven, buffered reads and writes.
+///
+/// Bytes written to the UART are queued in a write buffer which is drained
+/// into the transmitter from the Transmitter Holding Register Empty interrupt.
+/// Writers only block when the write buffer is full.
 pub struct Uart<K: Kernel> {
+    kernel: K,
     base_address: usize,
     irq: u32,
     read_buffer: SpinLock<K, CircularBuffer<u8, BUFFER_SIZE>>,
+    write_buffer: SpinLock<K, CircularBuffer<u8, BUFFER_SIZE>>,
+    // Signaled by the interrupt handler whenever space is freed in
+    // `write_buffer`.
+    write_space: Event<K>,
+    listener: SpinLock<K, Option<&'static dyn CharDeviceListener<K>>>,
 }
 
 impl<K: Kernel> regs::BaseAddress for Uart<K> {
@@ -38,11 +52,15 @@ impl<K: Kernel> uart_16550_regs::Uart16550BaseAddress for Uart<K> {}
 
 impl<K: Kernel> Uart<K> {
     #[must_use]
-    pub const fn new(base_address: usize, irq: u32) -> Uart<K> {
+    pub const fn new(kernel: K, base_address: usize, irq: u32) -> Uart<K> {
         Self {
+            kernel,
             base_address,
             irq,
             read_buffer: SpinLock::new(CircularBuffer::new()),
+            write_buffer: SpinLock::new(CircularBuffer::new()),
+            write_space: Event::new(kernel, EventConfig::AutoReset),
+            listener: SpinLock::new(None),
         }
     }
 
@@ -57,16 +75,96 @@ impl<K: Kernel> Uart<K> {
         Ok(self.read_buffer.lock(kernel).pop_front())
     }
 
+    /// Queues `value` for transmission.
+    ///
+    /// Blocks if the write buffer is full.  See [`Uart::write_all`].
     pub fn write(&self, value: u8) -> Result<()> {
         log_if::debug_if!(LOG_UART, "uart write: {}", value as u8);
-        let lsr = uart_16550_regs::Lsr;
-        while !lsr.read(self).thre() {}
+        self.write_all(&[value])
+    }
 
-        uart_16550_regs::Thr.write(self, uart_16550_regs::ThrValue(value));
-        log_if::debug_if!(LOG_UART, "done");
+    /// Queues all of `data` for transmission.
+    ///
+    /// Blocks until every byte has been queued in the write buffer, waiting
+    /// for the interrupt handler to make room whenever the buffer is full.
+    /// Returns once the last byte is queued, not once it has been transmitted;
+    /// use [`Uart::flush`] to wait for the transmitter to drain.
+    ///
+    /// # Interrupt context
+    ///
+    /// This method is *not* safe to call in an interrupt context.
+    pub fn write_all(&self, data: &[u8]) -> Result<()> {
+        let mut remaining = data;
+        while !remaining.is_empty() {
+            remaining = &remaining[self.queue(remaining)..];
+            if !remaining.is_empty() {
+                log_if::debug_if!(LOG_UART, "write buffer full, waiting");
+                self.write_space.wait()?;
+            }
+        }
         Ok(())
     }
 
+    /// Transmits all buffered bytes by polling the transmitter and waits
+    /// until the transmitter is empty.
+    ///
+    /// Does not rely on interrupts or the scheduler, making it suitable for
+    /// panic paths.  If the write buffer is locked, as can happen when the
+    /// panic occurred while it was held, the buffered bytes are dropped rather
+    /// than risking a deadlock.
+    pub fn flush(&self) {
+        let lsr = uart_16550_regs::Lsr;
+        if let Some(mut write_buffer) = self.write_buffer.try_lock(self.kernel) {
+            while let Some(byte) = write_buffer.pop_front() {
+                while !lsr.read(self).thre() {}
+                uart_16550_regs::Thr.write(self, uart_16550_regs::ThrValue(byte));
+            }
+            let mut ier = uart_16550_regs::Ier;
+            ier.write(self, ier.read(self).with_etbei(false));
+        }
+        while !lsr.read(self).temt() {}
+    }
+
+    // Queues as many bytes of `data` as fit in the write buffer, returning
+    // the number queued.
+    fn queue(&self, data: &[u8]) -> usize {
+        let mut write_buffer = self.write_buffer.lock(self.kernel);
+        let mut queued = 0;
+        for &byte in data {
+            if write_buffer.push_back(byte).is_err() {
+                break;
+            }
+            queued += 1;
+        }
+        self.start_tx(&mut write_buffer);
+        queued
+    }
+
+    fn listener(&self, kernel: K) -> Option<&'static dyn CharDeviceListener<K>> {
+        *self.listener.lock(kernel)
+    }
+
+    // Moves bytes from the write buffer into the transmitter while it has
+    // room and enables the Transmitter Holding Register Empty interrupt only
+    // while bytes remain buffered.  Must be called with the write buffer lock
+    // held.
+    fn start_tx(&self, write_buffer: &mut CircularBuffer<u8, BUFFER_SIZE>) {
+        let lsr = uart_16550_regs::Lsr;
+        while lsr.read(self).thre() {
+            let Some(byte) = write_buffer.pop_front() else {
+                break;
+            };
+            uart_16550_regs::Thr.write(self, uart_16550_regs::ThrValue(byte));
+        }
+
+        let mut ier = uart_16550_regs::Ier;
+        let ier_val = ier.read(self);
+        let tx_pending = !write_buffer.is_empty();
+        if ier_val.etbei() != tx_pending {
+            ier.write(self, ier_val.with_etbei(tx_pending));
+        }
+    }
+
     pub fn interrupt_handler(&self, kernel: K) {
         log_if::debug_if!(
             LOG_UART,
@@ -75,16 +173,69 @@ impl<K: Kernel> Uart<K> {
         );
 
         let lsr = uart_16550_regs::Lsr;
+        let mut received = false;
         while lsr.read(self).dr() {
             let value = uart_16550_regs::Rbr.read(self);
             log_if::debug_if!(LOG_UART, "data ready: {}", value.data() as u8);
             let _ = self.read_buffer.lock(kernel).push_back(value.data());
+            received = true;
+        }
+        if received && let Some(listener) = self.listener(kernel) {
+            listener.readable(kernel);
+        }
+
+        let mut write_buffer = self.write_buffer.lock(kernel);
+        let buffered = write_buffer.len();
+        self.start_tx(&mut write_buffer);
+        let freed_space = write_buffer.len() < buffered;
+        drop(write_buffer);
+
+        if freed_space {
+            log_if::debug_if!(LOG_UART, "TX space available");
+            self.write_space.get_signaler().signal();
+            if let Some(listener) = self.listener(kernel) {
+                listener.writable(kernel);
+            }
         }
     }
 }
 
+impl<K: Kernel> CharDevice<K> for Uart<K> {
+    fn read(&self, kernel: K, buffer: &mut [u8]) -> Result<usize> {
+        let mut read_buffer = self.read_buffer.lock(kernel);
+        let mut len = 0;
+        for byte in buffer.iter_mut() {
+            let Some(value) = read_buffer.pop_front() else {
+                break;
+            };
+            *byte = value;
+            len += 1;
+        }
+        Ok(len)
+    }
+
+    fn write(&self, _kernel: K, buffer: &[u8]) -> Result<usize> {
+        Ok(self.queue(buffer))
+    }
+
+    fn set_listener(&self, kernel: K, listener: &'static dyn CharDeviceListener<K>) {
+        *self.listener.lock(kernel) = Some(listener);
+    }
+}
+
 pub fn init<K: Kernel>(uarts: &[&Uart<K>]) {
     for uart in uarts {
+        // Without the FIFOs a burst of bytes written while the interrupt
+        // handler can not run overruns the receiver in loopback mode.
+        let mut fcr = uart_16550_regs::Fcr;
+        fcr.write(
+            *uart,
+            uart_16550_regs::FcrVal(0)
+                .with_fifoe(true)
+                .with_rfifor(true)
+                .with_xfifor(true),
+        );
+
         let mut ier = uart_16550_regs::Ier;
         ier.write(*uart, ier.read(*uart).with_erbfi(true));
 
@@ -61,6 +61,24 @@ impl IerVal {
     rw_bool_field!(u8, edssi, 3, "Modem Status Interrupt");
 }
 
+// Write only.  Reads at this offset return the Interrupt Identification
+// Register.
+rw_block_reg!(
+    Fcr,
+    FcrVal,
+    u8,
+    Uart16550BaseAddress,
+    2,
+    "FIFO Control Register"
+);
+#[repr(transparent)]
+pub struct FcrVal(pub u8);
+impl FcrVal {
+    rw_bool_field!(u8, fifoe, 0, "FIFO Enable");
+    rw_bool_field!(u8, rfifor, 1, "Receiver FIFO Reset");
+    rw_bool_field!(u8, xfifor, 2, "Transmitter FIFO Reset");
+}
+
 rw_block_reg!(
     Mcr,
     McrVal,
@@ -0,0 +1,119 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+//! RP2350 hardware watchdog driver.
+
+#![no_std]
+
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use kernel::Kernel;
+use pw_status::{Error, Result};
+use rp2350_watchdog_regs::{
+    Ctrl, Load, LoadValue, Reason, TickCtrl, TickCycles, TickCyclesValue, Wdsel, WdselValue,
+};
+use time::{Clock as _, Duration};
+use watchdog::Watchdog;
+
+/// Largest value of the 24 bit countdown, in microseconds.
+const MAX_LOAD_US: u32 = 0x00ff_ffff;
+
+pub struct Rp2350Watchdog {
+    ref_clock_mhz: u32,
+    /// Countdown reload value in microseconds, or `0` while disabled.
+    load_us: AtomicU32,
+}
+
+impl Rp2350Watchdog {
+    /// Creates a watchdog whose tick generator is driven by a `ref_clock_mhz`
+    /// MHz reference clock.
+    #[must_use]
+    pub const fn new(ref_clock_mhz: u32) -> Self {
+        Self {
+            ref_clock_mhz,
+            load_us: AtomicU32::new(0),
+        }
+    }
+
+    /// Returns `true` if the last reset was caused by the watchdog, either by
+    /// its countdown expiring or by [`Watchdog::force_reset()`].
+    #[must_use]
+    pub fn caused_last_reset(&self) -> bool {
+        let reason = Reason.read();
+        reason.timer() || reason.force()
+    }
+}
+
+impl<K: Kernel> Watchdog<K> for Rp2350Watchdog {
+    fn max_timeout(&self) -> Duration<K::Clock> {
+        Duration::from_micros(i64::from(MAX_LOAD_US))
+    }
+
+    fn enable(&'static self, _kernel: K, timeout: Duration<K::Clock>) -> Result<()> {
+        if timeout.ticks() <= 0 || timeout > Watchdog::<K>::max_timeout(self) {
+            return Err(Error::InvalidArgument);
+        }
+        // Bounded by `MAX_LOAD_US` above.
+        #[expect(clippy::cast_possible_truncation)]
+        let load_us =
+            (timeout.ticks().cast_unsigned() * 1_000_000 / K::Clock::TICKS_PER_SEC).max(1) as u32;
+        if self
+            .load_us
+            .compare_exchange(0, load_us, Ordering::Relaxed, Ordering::Relaxed)
+            .is_err()
+        {
+            return Err(Error::FailedPrecondition);
+        }
+
+        // Generate one watchdog tick per microsecond.
+        let mut tick_cycles = TickCycles;
+        tick_cycles.write(TickCyclesValue(0).with_cycles(self.ref_clock_mhz));
+        let mut tick_ctrl = TickCtrl;
+        tick_ctrl.write(tick_ctrl.read().with_enable(true));
+
+        let mut wdsel = Wdsel;
+        wdsel.write(WdselValue::ALL_EXCEPT_OSCILLATORS);
+
+        let mut load = Load;
+        load.write(LoadValue(0).with_load(load_us));
+
+        // Don't reset the system out from under a debugger.
+        let mut ctrl = Ctrl;
+        ctrl.write(
+            ctrl.read()
+                .with_pause_jtag(true)
+                .with_pause_dbg0(true)
+                .with_pause_dbg1(true)
+                .with_enable(true),
+        );
+
+        Ok(())
+    }
+
+    fn feed(&self, _kernel: K) {
+        let load_us = self.load_us.load(Ordering::Relaxed);
+        if load_us == 0 {
+            return;
+        }
+        let mut load = Load;
+        load.write(LoadValue(0).with_load(load_us));
+    }
+
+    fn force_reset(&self, _kernel: K) -> ! {
+        let mut ctrl = Ctrl;
+        ctrl.write(ctrl.read().with_trigger(true));
+        #[allow(clippy::empty_loop)]
+        loop {}
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+//! RP2350 watchdog, watchdog tick generator and power-on state machine
+//! registers.
+
+#![no_std]
+
+use regs::{ro_bool_field, ro_int_field, ro_reg, rw_bool_field, rw_int_field, rw_reg};
+
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct CtrlValue(pub u32);
+impl CtrlValue {
+    ro_int_field!(
+        u32,
+        time,
+        0,
+        23,
+        u32,
+        "Current countdown value in microseconds"
+    );
+    rw_bool_field!(
+        u32,
+        pause_jtag,
+        24,
+        "Pause the watchdog while JTAG is active"
+    );
+    rw_bool_field!(
+        u32,
+        pause_dbg0,
+        25,
+        "Pause the watchdog while core 0 is halted"
+    );
+    rw_bool_field!(
+        u32,
+        pause_dbg1,
+        26,
+        "Pause the watchdog while core 1 is halted"
+    );
+    rw_bool_field!(u32, enable, 30, "Enable the countdown");
+    rw_bool_field!(u32, trigger, 31, "Trigger a watchdog reset");
+}
+rw_reg!(
+    Ctrl,
+    CtrlValue,
+    u32,
+    0x400d_8000,
+    "Watchdog Control Register"
+);
+
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct LoadValue(pub u32);
+impl LoadValue {
+    rw_int_field!(
+        u32,
+        load,
+        0,
+        23,
+        u32,
+        "Countdown reload value in microseconds"
+    );
+}
+rw_reg!(Load, LoadValue, u32, 0x400d_8004, "Watchdog Load Register");
+
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct ReasonValue(pub u32);
+impl ReasonValue {
+    ro_bool_field!(
+        u32,
+        timer,
+        0,
+        "Last reset was caused by the countdown expiring"
+    );
+    ro_bool_field!(
+        u32,
+        force,
+        1,
+        "Last reset was triggered through CTRL.TRIGGER"
+    );
+}
+ro_reg!(
+    Reason,
+    ReasonValue,
+    u32,
+    0x400d_8008,
+    "Watchdog Reset Reason Register"
+);
+
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct TickCtrlValue(pub u32);
+impl TickCtrlValue {
+    rw_bool_field!(u32, enable, 0, "Start the tick generator");
+    ro_bool_field!(u32, running, 1, "Tick generator is running");
+}
+rw_reg!(
+    TickCtrl,
+    TickCtrlValue,
+    u32,
+    0x4010_8030,
+    "Watchdog Tick Generator Control Register"
+);
+
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct TickCyclesValue(pub u32);
+impl TickCyclesValue {
+    rw_int_field!(u32, cycles, 0, 8, u32, "Reference clock cycles per tick");
+}
+rw_reg!(
+    TickCycles,
+    TickCyclesValue,
+    u32,
+    0x4010_8034,
+    "Watchdog Tick Generator Cycles Register"
+);
+
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct WdselValue(pub u32);
+impl WdselValue {
+    /// Every block except the ring and crystal oscillators.
+    ///
+    /// Leaving the oscillators running lets the reference clock, and with it
+    /// the watchdog tick, survive the reset.
+    pub const ALL_EXCEPT_OSCILLATORS: Self = Self(0x01ff_ffff & !((1 << 2) | (1 << 3)));
+
+    rw_int_field!(u32, select, 0, 24, u32, "Blocks reset by a watchdog reset");
+}
+rw_reg!(
+    Wdsel,
+    WdselValue,
+    u32,
+    0x4001_8008,
+    "Power-on State Machine Watchdog Select Register"
+);
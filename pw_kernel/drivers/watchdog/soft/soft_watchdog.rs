@@ -0,0 +1,144 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+//! Software watchdog for targets without a usable hardware watchdog.
+//!
+//! Emulators such as QEMU either don't model the watchdog of the boards they
+//! emulate or don't wire it to a system reset.  [`SoftWatchdog`] stands in for
+//! a hardware watchdog by checking its deadline from a kernel timer and calling
+//! a target provided reset function once it expires.
+//!
+//! Unlike a hardware watchdog it depends on the kernel's timer interrupt, so it
+//! will not catch a system which hangs with interrupts disabled.
+
+#![no_std]
+
+use core::cell::UnsafeCell;
+
+use foreign_box::ForeignBox;
+use kernel::Kernel;
+use kernel::scheduler::timer::{self, Timer, TimerCallback};
+use kernel::sync::spinlock::SpinLock;
+use pw_log::error;
+use pw_status::{Error, Result};
+use time::{Duration, Instant};
+use watchdog::Watchdog;
+
+struct SoftWatchdogState<K: Kernel> {
+    /// Countdown length, or `None` while disabled.
+    timeout: Option<Duration<K::Clock>>,
+    deadline: Instant<K::Clock>,
+}
+
+struct ExpiryCheck<K: Kernel> {
+    watchdog: Option<&'static SoftWatchdog<K>>,
+}
+
+impl<K: Kernel> TimerCallback<K> for ExpiryCheck<K> {
+    fn callback(
+        &mut self,
+        kernel: K,
+        mut timer: ForeignBox<Timer<K>>,
+        now: Instant<K::Clock>,
+    ) -> Option<ForeignBox<Timer<K>>> {
+        let Some(watchdog) = self.watchdog else {
+            let _ = timer.consume();
+            return None;
+        };
+
+        let deadline = watchdog.state.lock(kernel).deadline;
+        if now >= deadline {
+            error!("Soft watchdog expired");
+            (watchdog.reset)();
+        }
+
+        // Fed since the timer was armed, check again at the new deadline.
+        timer.set(deadline);
+        Some(timer)
+    }
+}
+
+pub struct SoftWatchdog<K: Kernel> {
+    reset: fn() -> !,
+    state: SpinLock<K, SoftWatchdogState<K>>,
+    timer: UnsafeCell<Option<Timer<K>>>,
+    expiry_check: UnsafeCell<ExpiryCheck<K>>,
+}
+
+// Safety: `timer` and `expiry_check` are only accessed by `enable()`, which the
+// `timeout` check under the state lock limits to a single call.  Afterwards
+// they are owned by the kernel's timer queue.
+unsafe impl<K: Kernel> Sync for SoftWatchdog<K> {}
+
+impl<K: Kernel> SoftWatchdog<K> {
+    /// Creates a watchdog which calls `reset` when it expires.
+    #[must_use]
+    pub const fn new(reset: fn() -> !) -> Self {
+        Self {
+            reset,
+            state: SpinLock::new(SoftWatchdogState {
+                timeout: None,
+                deadline: Instant::MAX,
+            }),
+            timer: UnsafeCell::new(None),
+            expiry_check: UnsafeCell::new(ExpiryCheck { watchdog: None }),
+        }
+    }
+}
+
+impl<K: Kernel> Watchdog<K> for SoftWatchdog<K> {
+    fn max_timeout(&self) -> Duration<K::Clock> {
+        Duration::MAX
+    }
+
+    fn enable(&'static self, kernel: K, timeout: Duration<K::Clock>) -> Result<()> {
+        if timeout.ticks() <= 0 {
+            return Err(Error::InvalidArgument);
+        }
+
+        let deadline = {
+            let mut state = self.state.lock(kernel);
+            if state.timeout.is_some() {
+                return Err(Error::FailedPrecondition);
+            }
+            state.timeout = Some(timeout);
+            state.deadline = kernel.now() + timeout;
+            state.deadline
+        };
+
+        // Safety: See the `Sync` implementation above.  This is the only
+        // access to `timer` and `expiry_check`, both of which are `'static`.
+        unsafe {
+            let expiry_check = &mut *self.expiry_check.get();
+            expiry_check.watchdog = Some(self);
+            let callback: *mut dyn TimerCallback<K> = expiry_check;
+            let timer = (*self.timer.get())
+                .insert(Timer::new(deadline, ForeignBox::new_from_ptr(callback)));
+            timer::schedule_timer(kernel, ForeignBox::new_from_ptr(timer));
+        }
+
+        Ok(())
+    }
+
+    fn feed(&self, kernel: K) {
+        let mut state = self.state.lock(kernel);
+        if let Some(timeout) = state.timeout {
+            state.deadline = kernel.now() + timeout;
+        }
+    }
+
+    fn force_reset(&self, _kernel: K) -> ! {
+        (self.reset)()
+    }
+}
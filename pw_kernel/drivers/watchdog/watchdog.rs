@@ -0,0 +1,109 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+//! Hardware watchdog support.
+//!
+//! [`Watchdog`] is implemented by each hardware watchdog driver.  [`run()`]
+//! ties a driver to the kernel's per-thread check-ins (see
+//! [`kernel::scheduler::watchdog`]): the hardware watchdog is only fed while
+//! every watched thread has checked in within its interval.  If a thread falls
+//! behind, a fault report is logged and the system is reset.
+
+#![no_std]
+
+use kernel::Kernel;
+use kernel::scheduler::watchdog::{OverdueThread, find_overdue_thread};
+use pw_log::{error, info};
+use pw_status::{Error, Result};
+use time::Duration;
+
+/// Interface to a hardware watchdog timer.
+pub trait Watchdog<K: Kernel>: Sync {
+    /// Returns the longest timeout supported by the watchdog.
+    fn max_timeout(&self) -> Duration<K::Clock>;
+
+    /// Starts the watchdog.
+    ///
+    /// Once enabled the watchdog can not be disabled.  It must be fed at least
+    /// once every `timeout` or the system is reset.
+    ///
+    /// # Returns
+    /// - `Error::InvalidArgument`: `timeout` is not positive or is longer than
+    ///   [`Watchdog::max_timeout()`].
+    /// - `Error::FailedPrecondition`: The watchdog is already enabled.
+    fn enable(&'static self, kernel: K, timeout: Duration<K::Clock>) -> Result<()>;
+
+    /// Restarts the watchdog countdown.
+    fn feed(&self, kernel: K);
+
+    /// Resets the system immediately.
+    fn force_reset(&self, kernel: K) -> !;
+}
+
+fn report_overdue_thread<K: Kernel>(kernel: K, overdue: &OverdueThread<K>) {
+    let now = kernel.now();
+    error!(
+        "Watchdog: thread '{}' ({:#010x}) of process '{}' missed its check-in",
+        overdue.name as &str, overdue.id as usize, overdue.process_name as &str
+    );
+    error!(
+        "Watchdog: interval {} ms, last check-in {} ms ago",
+        overdue.interval.as_millis().cast_unsigned() as u64,
+        (now - overdue.last_check_in).as_millis().cast_unsigned() as u64
+    );
+    kernel::scheduler::stats::dump_cpu_usage(kernel);
+}
+
+/// Runs the watchdog service.
+///
+/// Enables `watchdog` with `timeout` and then, every `feed_interval`, feeds it
+/// if no watched thread is overdue.  Otherwise a fault report naming the
+/// overdue thread is logged and the system is reset through
+/// [`Watchdog::force_reset()`].
+///
+/// Meant to be the body of a dedicated kernel thread.  The thread should run at
+/// a priority high enough that it is not starved by the threads it watches.
+///
+/// # Returns
+/// Only returns if the watchdog could not be enabled:
+/// - `Error::InvalidArgument`: `feed_interval` is not positive or is not
+///   shorter than `timeout`.
+/// - Any error returned by [`Watchdog::enable()`].
+pub fn run<K: Kernel, W: Watchdog<K>>(
+    kernel: K,
+    watchdog: &'static W,
+    timeout: Duration<K::Clock>,
+    feed_interval: Duration<K::Clock>,
+) -> Result<()> {
+    if feed_interval.ticks() <= 0 || feed_interval >= timeout {
+        return Err(Error::InvalidArgument);
+    }
+    watchdog.enable(kernel, timeout)?;
+    info!(
+        "Watchdog: enabled with a {} ms timeout",
+        timeout.as_millis().cast_unsigned() as u64
+    );
+
+    let mut deadline = kernel.now();
+    loop {
+        if let Some(overdue) = find_overdue_thread(kernel, kernel.now()) {
+            report_overdue_thread(kernel, &overdue);
+            watchdog.force_reset(kernel);
+        }
+        watchdog.feed(kernel);
+
+        deadline = deadline + feed_interval;
+        let _ = kernel::sleep_until(kernel, deadline);
+    }
+}
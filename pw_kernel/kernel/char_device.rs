@@ -0,0 +1,54 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+//! Interface between byte stream device drivers and the rest of the kernel.
+
+use pw_status::Result;
+
+use crate::Kernel;
+
+/// Notified by a [`CharDevice`] when it may be read from or written to.
+///
+/// Listeners are called from the device's interrupt handler and must not
+/// block.
+pub trait CharDeviceListener<K: Kernel>: Sync {
+    /// Called when received data becomes available.
+    fn readable(&self, kernel: K);
+
+    /// Called when space becomes available in the device's write buffer.
+    fn writable(&self, kernel: K);
+}
+
+/// A buffered byte stream device such as a UART.
+///
+/// All operations are non-blocking.  Users wait for readiness through the
+/// device's [`CharDeviceListener`].
+pub trait CharDevice<K: Kernel>: Sync {
+    /// Reads up to `buffer.len()` received bytes into `buffer`.
+    ///
+    /// Returns the number of bytes read, which is `0` if no data was
+    /// available.
+    fn read(&self, kernel: K, buffer: &mut [u8]) -> Result<usize>;
+
+    /// Queues as many bytes of `buffer` for transmission as fit in the
+    /// device's write buffer.
+    ///
+    /// Returns the number of bytes queued, which is `0` if the write buffer
+    /// was full.
+    fn write(&self, kernel: K, buffer: &[u8]) -> Result<usize>;
+
+    /// Registers the listener to notify of changes in readiness, replacing
+    /// any previously registered listener.
+    fn set_listener(&self, kernel: K, listener: &'static dyn CharDeviceListener<K>);
+}
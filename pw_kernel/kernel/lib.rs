@@ -17,10 +17,12 @@ use pw_atomic::{AtomicBool, AtomicUsize};
 use pw_log::info;
 pub use time::{Duration, Instant};
 
+pub mod char_device;
 pub mod interrupt_controller;
 pub mod object;
 #[cfg(not(feature = "std_panic_handler"))]
 mod panic;
+mod panic_flush;
 pub mod scheduler;
 pub mod sync;
 pub mod syscall;
@@ -29,6 +31,7 @@ mod target;
 use interrupt_controller::InterruptController;
 use kernel_config::{KernelConfig, KernelConfigInterface};
 pub use object::NullObjectTable;
+pub use panic_flush::{panic_flush, set_panic_flush_handler};
 #[doc(hidden)]
 pub use scheduler::thread::{Process, Stack, StackStorage, StackStorageExt, Thread, ThreadState};
 use scheduler::timer::TimerQueue;
@@ -75,13 +78,26 @@ pub trait Arch: 'static + Copy + thread::ThreadArg {
         &self.get_state().arch_state.interrupt_controller
     }
 
+    /// Waits for an interrupt.
+    ///
+    /// Called from the idle thread with interrupts disabled.  A pending
+    /// interrupt must still wake the CPU and is serviced once the idle thread
+    /// re-enables interrupts.
+    ///
+    /// When `wakeup` is set no thread needs to run before that time and the
+    /// scheduler tick may be suppressed until then.  Any changes made to the
+    /// timer must be undone before returning.
     #[allow(dead_code)]
-    fn idle(self) {}
+    fn idle(self, _wakeup: Option<Instant<Self::Clock>>) {}
 
     fn early_init(self) {}
     fn init(self) {}
 
+    /// Halts the system.
+    ///
+    /// Implementations call [`panic_flush`] before halting.
     fn panic() -> ! {
+        panic_flush();
         #[allow(clippy::empty_loop)]
         loop {}
     }
@@ -101,6 +117,12 @@ impl<K: Kernel> ArchState<K> {
 }
 
 pub trait Kernel: Arch + Sync {
+    /// The algorithm used to select the next thread to run.
+    ///
+    /// Most targets use [`scheduler::algorithm::TargetSchedulerAlgorithm`]
+    /// which is selected by the target's `scheduler` constraint.
+    type SchedulerAlgorithm: scheduler::algorithm::SchedulerAlgorithm<Self>;
+
     fn get_state(self) -> &'static KernelState<Self>;
 
     fn get_scheduler(self) -> &'static SpinLock<Self, SchedulerState<Self>> {
@@ -253,7 +275,7 @@ fn bootstrap_thread_entry<K: Kernel>(
 
     kernel.init();
 
-    kernel.get_scheduler().lock(kernel).dump_all_threads();
+    scheduler::stats::dump_all_threads(kernel);
 
     let idle_thread = thread::init_thread_in(
         kernel,
@@ -265,7 +287,7 @@ fn bootstrap_thread_entry<K: Kernel>(
         0,
     );
 
-    kernel.get_scheduler().lock(kernel).dump_all_threads();
+    scheduler::stats::dump_all_threads(kernel);
 
     scheduler::start_thread(kernel, idle_thread);
 
@@ -276,7 +298,7 @@ fn idle_thread_entry<K: Kernel>(kernel: K, _arg: usize) {
     // Fake idle thread to keep the runqueue from being empty if all threads are blocked.
     pw_assert::assert!(K::InterruptController::interrupts_enabled());
     loop {
-        kernel.idle();
+        scheduler::idle(kernel);
     }
 }
 
@@ -19,25 +19,43 @@ use core::ptr::NonNull;
 use foreign_box::{ForeignBox, ForeignRc};
 use list::{self, Link, RandomAccessForeignList};
 use pw_status::{Error, Result};
-use syscall_defs::Signals;
-use time::Instant;
+use syscall_defs::{Signals, WaitReturn};
+use time::{Duration, Instant};
 
 use crate::Kernel;
+use crate::scheduler::process_manager::FaultReport;
 use crate::sync::event::{Event, EventConfig, EventSignaler};
-use crate::sync::spinlock::SpinLock;
+use crate::sync::spinlock::{SpinLock, SpinLockGuard};
 
 mod buffer;
 mod channel;
 mod interrupt;
+mod process;
+mod timer;
+mod uart;
+mod wait_group;
 
 pub use buffer::SyscallBuffer;
 pub use channel::{ChannelHandlerObject, ChannelInitiatorObject};
 pub use interrupt::InterruptObject;
+pub use process::ProcessObject;
+pub use timer::TimerObject;
+pub use uart::UartObject;
+use wait_group::WaitGroupMembership;
+pub use wait_group::WaitGroupObject;
 
 /// Trait that all kernel objects implement.
 ///
 /// The methods on this trait map directly to the kernel's system calls.
 pub trait KernelObject<K: Kernel>: Any + Send + Sync {
+    /// Returns the object's [`ObjectBase`].
+    ///
+    /// Objects which do not have an `ObjectBase` can not be added to a wait
+    /// group.
+    fn object_base(&self) -> Option<&ObjectBase<K>> {
+        None
+    }
+
     /// Wait on a set of signals to be active.
     ///
     /// Blocks until any of the signals in `signal_mask` are active on the object
@@ -48,7 +66,13 @@ pub trait KernelObject<K: Kernel>: Any + Send + Sync {
         kernel: K,
         signal_mask: Signals,
         deadline: Instant<K::Clock>,
-    ) -> Result<Signals> {
+    ) -> Result<WaitReturn> {
+        Err(Error::Unimplemented)
+    }
+
+    /// Raise `Signals::USER` on the object's peer.
+    #[allow(unused_variables)]
+    fn object_raise_peer_user_signal(&self, kernel: K) -> Result<()> {
         Err(Error::Unimplemented)
     }
 
@@ -63,6 +87,21 @@ pub trait KernelObject<K: Kernel>: Any + Send + Sync {
         Err(Error::Unimplemented)
     }
 
+    #[allow(unused_variables)]
+    fn channel_async_transact(
+        &self,
+        kernel: K,
+        send_buffer: SyscallBuffer,
+        recv_buffer: SyscallBuffer,
+    ) -> Result<()> {
+        Err(Error::Unimplemented)
+    }
+
+    #[allow(unused_variables)]
+    fn channel_async_cancel(&self, kernel: K) -> Result<()> {
+        Err(Error::Unimplemented)
+    }
+
     #[allow(unused_variables)]
     fn channel_read(&self, kernel: K, offset: usize, read_buffer: SyscallBuffer) -> Result<usize> {
         Err(Error::Unimplemented)
@@ -77,6 +116,99 @@ pub trait KernelObject<K: Kernel>: Any + Send + Sync {
     fn interrupt_ack(&self, kernel: K, signal_mask: Signals) -> Result<()> {
         Err(Error::Unimplemented)
     }
+
+    /// Start the timer, expiring at `deadline` and then every `period` if one
+    /// is given.
+    #[allow(unused_variables)]
+    fn timer_set(
+        &self,
+        kernel: K,
+        deadline: Instant<K::Clock>,
+        period: Option<Duration<K::Clock>>,
+    ) -> Result<()> {
+        Err(Error::Unimplemented)
+    }
+
+    /// Stop the timer.
+    #[allow(unused_variables)]
+    fn timer_cancel(&self, kernel: K) -> Result<()> {
+        Err(Error::Unimplemented)
+    }
+
+    /// Read received bytes into `read_buffer` without blocking.
+    #[allow(unused_variables)]
+    fn uart_read(&self, kernel: K, read_buffer: SyscallBuffer) -> Result<usize> {
+        Err(Error::Unimplemented)
+    }
+
+    /// Queue the bytes of `write_buffer` for transmission without blocking.
+    #[allow(unused_variables)]
+    fn uart_write(&self, kernel: K, write_buffer: SyscallBuffer) -> Result<usize> {
+        Err(Error::Unimplemented)
+    }
+
+    /// Add `object` to this wait group.
+    ///
+    /// The wait group will become readable when any of the signals in
+    /// `signal_mask` are active on `object`.
+    #[allow(unused_variables)]
+    fn wait_group_add(
+        &self,
+        kernel: K,
+        object: ForeignRc<K::AtomicUsize, dyn KernelObject<K>>,
+        signal_mask: Signals,
+        user_data: usize,
+    ) -> Result<()> {
+        Err(Error::Unimplemented)
+    }
+
+    /// Remove `object` from this wait group.
+    #[allow(unused_variables)]
+    fn wait_group_remove(
+        &self,
+        kernel: K,
+        object: ForeignRc<K::AtomicUsize, dyn KernelObject<K>>,
+    ) -> Result<()> {
+        Err(Error::Unimplemented)
+    }
+
+    /// Called when the process holding a handle to the object has exited.
+    ///
+    /// Objects with a peer use this to notify the peer that it will no longer
+    /// be serviced.
+    #[allow(unused_variables)]
+    fn object_close(&self, kernel: K) {}
+
+    /// Called when the process holding a handle to the object is restarted.
+    ///
+    /// Reverses the effects of [`KernelObject::object_close()`].
+    #[allow(unused_variables)]
+    fn object_reopen(&self, kernel: K) {}
+
+    /// Request the supervised process to terminate.
+    #[allow(unused_variables)]
+    fn process_terminate(&self, kernel: K) -> Result<()> {
+        Err(Error::Unimplemented)
+    }
+
+    /// Restart the supervised process once it has exited.
+    #[allow(unused_variables)]
+    fn process_restart(&self, kernel: K) -> Result<()> {
+        Err(Error::Unimplemented)
+    }
+
+    /// Returns the status the supervised process exited with.
+    #[allow(unused_variables)]
+    fn process_exit_status(&self, kernel: K) -> Result<Result<()>> {
+        Err(Error::Unimplemented)
+    }
+
+    /// Returns the report of the fault which terminated the supervised
+    /// process.
+    #[allow(unused_variables)]
+    fn process_fault(&self, kernel: K) -> Result<FaultReport> {
+        Err(Error::Unimplemented)
+    }
 }
 
 list::define_adapter!(pub ObjectWaiterListAdapter<K: Kernel> => ObjectWaiter<K>::link);
@@ -115,6 +247,7 @@ struct ObjectWaiter<K: Kernel> {
 pub struct ObjectBaseState<K: Kernel> {
     active_signals: Signals,
     waiters: RandomAccessForeignList<ObjectWaiter<K>, ObjectWaiterListAdapter<K>>,
+    wait_group_membership: Option<WaitGroupMembership<K>>,
 }
 
 impl<K: Kernel> ObjectBaseState<K> {
@@ -123,6 +256,7 @@ impl<K: Kernel> ObjectBaseState<K> {
         Self {
             active_signals: Signals::new(),
             waiters: RandomAccessForeignList::new(),
+            wait_group_membership: None,
         }
     }
 }
@@ -137,6 +271,13 @@ pub trait ObjectTable<K: Kernel> {
         kernel: K,
         handle: u32,
     ) -> Option<ForeignRc<K::AtomicUsize, dyn KernelObject<K>>>;
+
+    /// Calls `f` with every object in the table.
+    fn for_each_object(
+        &self,
+        kernel: K,
+        f: &mut dyn FnMut(&ForeignRc<K::AtomicUsize, dyn KernelObject<K>>),
+    );
 }
 
 /// An object table with no entries.
@@ -159,6 +300,13 @@ impl<K: Kernel> ObjectTable<K> for NullObjectTable {
     ) -> Option<ForeignRc<K::AtomicUsize, dyn KernelObject<K>>> {
         None
     }
+
+    fn for_each_object(
+        &self,
+        _kernel: K,
+        _f: &mut dyn FnMut(&ForeignRc<K::AtomicUsize, dyn KernelObject<K>>),
+    ) {
+    }
 }
 
 impl<const N: usize, K: Kernel> ObjectTable<K>
@@ -171,18 +319,43 @@ impl<const N: usize, K: Kernel> ObjectTable<K>
     ) -> Option<ForeignRc<<K>::AtomicUsize, dyn KernelObject<K>>> {
         self.get(handle as usize).cloned()
     }
+
+    fn for_each_object(
+        &self,
+        _kernel: K,
+        f: &mut dyn FnMut(&ForeignRc<<K>::AtomicUsize, dyn KernelObject<K>>),
+    ) {
+        self.iter().for_each(f);
+    }
 }
 
 /// Common functionality used by many kernel objects
 pub struct ObjectBase<K: Kernel> {
     state: SpinLock<K, ObjectBaseState<K>>,
+
+    // Link in the member list of the wait group this object is in.  Protected
+    // by the wait group's member list lock.
+    wait_group_link: Link,
+
+    // Signals which are cleared once they have been reported to a waiter.
+    clear_on_wait: Signals,
 }
 
 impl<K: Kernel> ObjectBase<K> {
     #[must_use]
     pub const fn new() -> Self {
+        Self::new_with_clear_on_wait(Signals::new())
+    }
+
+    /// Creates an `ObjectBase` where `clear_on_wait` signals are cleared
+    /// when they are returned from a wait, either directly or through a wait
+    /// group.
+    #[must_use]
+    pub const fn new_with_clear_on_wait(clear_on_wait: Signals) -> Self {
         Self {
             state: SpinLock::new(ObjectBaseState::new()),
+            wait_group_link: Link::new(),
+            clear_on_wait,
         }
     }
 }
@@ -197,8 +370,11 @@ impl<K: Kernel> ObjectBase<K> {
         let mut state = self.state.lock(kernel);
 
         // Skip waiting if signals are already pending.
-        if state.active_signals.contains(signal_mask) {
-            return Ok(state.active_signals);
+        if state.active_signals.intersects(signal_mask) {
+            let pending_signals = state.active_signals;
+            // Only consume the signals the caller asked for.
+            state.active_signals -= pending_signals & signal_mask & self.clear_on_wait;
+            return Ok(pending_signals);
         }
 
         let event = Event::new(kernel, EventConfig::ManualReset);
@@ -236,15 +412,36 @@ impl<K: Kernel> ObjectBase<K> {
         // `waiter_box` is no longer referenced and is safe to consume.
         waiter_box.consume();
 
+        if let Ok(pending_signals) = result {
+            state.active_signals -= pending_signals & signal_mask & self.clear_on_wait;
+        }
+
         result
     }
 
+    /// Sets the object's active signals to `active_signals`, waking any
+    /// waiters which are waiting on one of them.
     pub fn signal(&self, kernel: K, active_signals: Signals) {
-        let mut state = self.state.lock(kernel);
+        let state = self.state.lock(kernel);
+        Self::set_active_signals(kernel, state, active_signals);
+    }
+
+    /// Raises `signals` in addition to the object's already active signals.
+    pub fn raise(&self, kernel: K, signals: Signals) {
+        let state = self.state.lock(kernel);
+        let active_signals = state.active_signals | signals;
+        Self::set_active_signals(kernel, state, active_signals);
+    }
+
+    fn set_active_signals(
+        kernel: K,
+        mut state: SpinLockGuard<'_, K, ObjectBaseState<K>>,
+        active_signals: Signals,
+    ) {
         state.active_signals = active_signals;
 
         let _ = state.waiters.for_each(|waiter| -> Result<()> {
-            if waiter.signal_mask.contains(active_signals) {
+            if waiter.signal_mask.intersects(active_signals) {
                 // Safety: While a waiter is in an object's `waiters` list, that
                 // object has exclusive access to the waiter.  The below
                 // operation is done with the object's spinlock held.
@@ -253,5 +450,18 @@ impl<K: Kernel> ObjectBase<K> {
             }
             Ok(())
         });
+
+        let wait_group = state
+            .wait_group_membership
+            .as_ref()
+            .filter(|membership| membership.signal_mask.intersects(active_signals))
+            .map(|membership| membership.wait_group.clone());
+
+        // The wait group is notified without holding this object's lock to
+        // keep the lock ordering of wait group before member.
+        drop(state);
+        if let Some(wait_group) = wait_group {
+            wait_group.member_signaled(kernel);
+        }
     }
 }
@@ -120,4 +120,16 @@ impl SyscallBuffer {
         // Safety: Address and size are checked and validated in `new()`.
         unsafe { core::slice::from_raw_parts(self.addr.as_ptr(), self.size) }
     }
+
+    /// Returns the buffer as a mutable slice.
+    ///
+    /// # Returns
+    /// - Error::PermissionDenied: The buffer is not writeable.
+    pub fn as_mut_slice(&mut self) -> Result<&mut [u8]> {
+        if !self.access_type.is_writeable() {
+            return Err(Error::PermissionDenied);
+        }
+        // Safety: Address and size are checked and validated in `new()`.
+        Ok(unsafe { core::slice::from_raw_parts_mut(self.addr.as_ptr(), self.size) })
+    }
 }
@@ -12,13 +12,19 @@
 // License for the specific language governing permissions and limitations under
 // the License.
 
+use core::ptr;
+use core::sync::atomic::Ordering;
+
 use foreign_box::{ForeignRc, ForeignRcState};
+use pw_atomic::{AtomicFalse, AtomicLoad, AtomicStore};
 use pw_status::{Error, Result};
+use syscall_defs::WaitReturn;
 use time::Instant;
 
 use crate::Kernel;
 use crate::object::{KernelObject, ObjectBase, Signals, SyscallBuffer};
 use crate::sync::mutex::Mutex;
+use crate::sync::spinlock::SpinLock;
 
 struct Transaction<K: Kernel> {
     send_buffer: SyscallBuffer,
@@ -29,25 +35,60 @@ struct Transaction<K: Kernel> {
 pub struct ChannelHandlerObject<K: Kernel> {
     base: ObjectBase<K>,
     active_transaction: Mutex<K, Option<Transaction<K>>>,
+
+    // The initiator peer of this channel.  Set once the initiator is created.
+    initiator: SpinLock<K, Option<ForeignRc<K::AtomicUsize, ChannelInitiatorObject<K>>>>,
+
+    // Set, with `active_transaction` locked, once the initiator's process has
+    // exited.
+    peer_closed: K::AtomicBool,
 }
 
 impl<K: Kernel> ChannelHandlerObject<K> {
     pub fn new(kernel: K) -> Self {
         Self {
-            base: ObjectBase::new(),
+            base: ObjectBase::new_with_clear_on_wait(Signals::USER),
             active_transaction: Mutex::new(kernel, None),
+            initiator: SpinLock::new(None),
+            peer_closed: K::AtomicBool::FALSE,
         }
     }
+
+    /// Connects the handler to its initiator peer.
+    pub fn set_initiator(
+        &self,
+        kernel: K,
+        initiator: ForeignRc<K::AtomicUsize, ChannelInitiatorObject<K>>,
+    ) {
+        *self.initiator.lock(kernel) = Some(initiator);
+    }
 }
 
 impl<K: Kernel> KernelObject<K> for ChannelHandlerObject<K> {
+    fn object_base(&self) -> Option<&ObjectBase<K>> {
+        Some(&self.base)
+    }
+
     fn object_wait(
         &self,
         kernel: K,
         signal_mask: Signals,
         deadline: Instant<<K>::Clock>,
-    ) -> Result<Signals> {
-        self.base.wait_until(kernel, signal_mask, deadline)
+    ) -> Result<WaitReturn> {
+        self.base
+            .wait_until(kernel, signal_mask, deadline)
+            .map(WaitReturn::new)
+    }
+
+    fn object_raise_peer_user_signal(&self, kernel: K) -> Result<()> {
+        if self.peer_closed.load(Ordering::SeqCst) {
+            return Err(Error::Aborted);
+        }
+        let Some(initiator) = self.initiator.lock(kernel).clone() else {
+            return Err(Error::FailedPrecondition);
+        };
+        initiator.base.raise(kernel, Signals::USER);
+        Ok(())
     }
 
     fn channel_read(
@@ -57,6 +98,9 @@ impl<K: Kernel> KernelObject<K> for ChannelHandlerObject<K> {
         mut read_buffer: SyscallBuffer,
     ) -> Result<usize> {
         let active_transaction = self.active_transaction.lock();
+        if self.peer_closed.load(Ordering::SeqCst) {
+            return Err(Error::Aborted);
+        }
         let Some(ref transaction) = *active_transaction else {
             return Err(Error::FailedPrecondition);
         };
@@ -66,6 +110,9 @@ impl<K: Kernel> KernelObject<K> for ChannelHandlerObject<K> {
 
     fn channel_respond(&self, kernel: K, response_buffer: SyscallBuffer) -> Result<()> {
         let mut active_transaction = self.active_transaction.lock();
+        if self.peer_closed.load(Ordering::SeqCst) {
+            return Err(Error::Aborted);
+        }
         let Some(ref mut transaction) = *active_transaction else {
             return Err(Error::FailedPrecondition);
         };
@@ -74,45 +121,79 @@ impl<K: Kernel> KernelObject<K> for ChannelHandlerObject<K> {
         }
         response_buffer.copy_into(0, &mut transaction.recv_buffer)?;
 
-        transaction.recv_buffer.truncate(response_buffer.size());
+        let initiator = transaction.initiator.clone();
+        *active_transaction = None;
         self.base.state.lock(kernel).active_signals -= Signals::READABLE | Signals::WRITEABLE;
-        transaction.initiator.base.signal(kernel, Signals::READABLE);
+
+        // The initiator is completed with `active_transaction` locked so that
+        // it can not start a new transaction before this one is completed.
+        initiator.complete_transaction(kernel, response_buffer.size());
         Ok(())
     }
+
+    fn object_close(&self, kernel: K) {
+        let Some(initiator) = self.initiator.lock(kernel).clone() else {
+            return;
+        };
+
+        // Drop any transaction in flight and mark the initiator's peer as
+        // closed with `active_transaction` locked so that no new transaction
+        // can be started in between.
+        let mut active_transaction = self.active_transaction.lock();
+        let transaction = active_transaction.take();
+        self.base.state.lock(kernel).active_signals -= Signals::READABLE | Signals::WRITEABLE;
+        initiator.peer_closed.store(true, Ordering::SeqCst);
+        drop(active_transaction);
+        drop(transaction);
+
+        initiator.base.raise(kernel, Signals::ERROR);
+    }
+
+    fn object_reopen(&self, kernel: K) {
+        // Drop any user signal raised before the process exited.
+        self.base.state.lock(kernel).active_signals -= Signals::USER;
+
+        let Some(initiator) = self.initiator.lock(kernel).clone() else {
+            return;
+        };
+
+        let active_transaction = self.active_transaction.lock();
+        initiator.peer_closed.store(false, Ordering::SeqCst);
+        drop(active_transaction);
+
+        initiator.base.state.lock(kernel).active_signals -= Signals::ERROR;
+    }
 }
 
 pub struct ChannelInitiatorObject<K: Kernel> {
     base: ObjectBase<K>,
     handler: ForeignRc<K::AtomicUsize, ChannelHandlerObject<K>>,
+
+    // Number of bytes the handler responded with to the last transaction.
+    response_len: SpinLock<K, Option<usize>>,
+
+    // Set, with the handler's `active_transaction` locked, once the handler's
+    // process has exited.
+    peer_closed: K::AtomicBool,
 }
 
 impl<K: Kernel> ChannelInitiatorObject<K> {
     #[must_use]
     pub fn new(handler: ForeignRc<K::AtomicUsize, ChannelHandlerObject<K>>) -> Self {
         Self {
-            base: ObjectBase::new(),
+            base: ObjectBase::new_with_clear_on_wait(Signals::USER),
             handler,
+            response_len: SpinLock::new(None),
+            peer_closed: K::AtomicBool::FALSE,
         }
     }
-}
-
-impl<K: Kernel> KernelObject<K> for ChannelInitiatorObject<K> {
-    fn object_wait(
-        &self,
-        kernel: K,
-        signal_mask: Signals,
-        deadline: Instant<<K>::Clock>,
-    ) -> Result<Signals> {
-        self.base.wait_until(kernel, signal_mask, deadline)
-    }
 
-    fn channel_transact(
+    fn start_transaction(
         &self,
         kernel: K,
         send_buffer: SyscallBuffer,
         recv_buffer: SyscallBuffer,
-        deadline: Instant<K::Clock>,
-    ) -> Result<usize> {
+    ) -> Result<()> {
         // TODO: konkers - When the kernel has dynamic memory mapping APIs either:
         // * these checks will have to be differed til the time of memcpy.
         // * a region locking mechanism will need to be built
@@ -122,11 +203,20 @@ impl<K: Kernel> KernelObject<K> for ChannelInitiatorObject<K> {
 
         let mut active_transaction = self.handler.active_transaction.lock();
 
+        if self.peer_closed.load(Ordering::SeqCst) {
+            return Err(Error::Aborted);
+        }
+
         // Check to see if a transaction is already active on the channel.
         if active_transaction.is_some() {
-            return Err(Error::Unavailable);
+            return Err(Error::ResourceExhausted);
         }
 
+        // Clear Readable and Writable signals on our side before the
+        // transaction becomes visible to the handler.
+        *self.response_len.lock(kernel) = None;
+        self.base.state.lock(kernel).active_signals -= Signals::READABLE | Signals::WRITEABLE;
+
         *active_transaction = Some(Transaction {
             send_buffer,
             recv_buffer,
@@ -135,29 +225,143 @@ impl<K: Kernel> KernelObject<K> for ChannelInitiatorObject<K> {
 
         drop(active_transaction);
 
-        // Clear Readable, Writable, and Error signals  on our side before
-        // signaling the handler.
-        self.base.state.lock(kernel).active_signals -=
-            Signals::READABLE | Signals::WRITEABLE | Signals::ERROR;
+        self.handler.base.raise(kernel, Signals::READABLE);
+
+        Ok(())
+    }
+
+    fn cancel_transaction(&self, kernel: K) -> Result<()> {
+        let mut active_transaction = self.handler.active_transaction.lock();
+        let is_pending = active_transaction
+            .as_ref()
+            .is_some_and(|transaction| ptr::eq(&*transaction.initiator, self));
+        if !is_pending {
+            return Err(Error::FailedPrecondition);
+        }
+
+        *active_transaction = None;
+        self.handler.base.state.lock(kernel).active_signals -=
+            Signals::READABLE | Signals::WRITEABLE;
+        drop(active_transaction);
+
+        self.base.raise(kernel, Signals::WRITEABLE);
+        Ok(())
+    }
+
+    fn complete_transaction(&self, kernel: K, response_len: usize) {
+        *self.response_len.lock(kernel) = Some(response_len);
+        self.base
+            .raise(kernel, Signals::READABLE | Signals::WRITEABLE);
+    }
+}
+
+impl<K: Kernel> KernelObject<K> for ChannelInitiatorObject<K> {
+    fn object_base(&self) -> Option<&ObjectBase<K>> {
+        Some(&self.base)
+    }
+
+    fn object_wait(
+        &self,
+        kernel: K,
+        signal_mask: Signals,
+        deadline: Instant<<K>::Clock>,
+    ) -> Result<WaitReturn> {
+        let pending_signals = self.base.wait_until(kernel, signal_mask, deadline)?;
+        let mut wait_return = WaitReturn::new(pending_signals);
 
-        self.handler.base.signal(kernel, Signals::READABLE);
+        // Report the size of the handler's response once the transaction has
+        // completed.
+        if pending_signals.contains(Signals::READABLE)
+            && let Some(response_len) = *self.response_len.lock(kernel)
+        {
+            wait_return.user_data = response_len;
+        }
 
-        self.object_wait(kernel, Signals::READABLE | Signals::ERROR, deadline)?;
+        Ok(wait_return)
+    }
 
-        // TODO: konkers - Rationalize signal behavior with syscall_defs.rs.
-        self.base.state.lock(kernel).active_signals |= Signals::WRITEABLE;
+    fn object_raise_peer_user_signal(&self, kernel: K) -> Result<()> {
+        if self.peer_closed.load(Ordering::SeqCst) {
+            return Err(Error::Aborted);
+        }
+        self.handler.base.raise(kernel, Signals::USER);
+        Ok(())
+    }
 
-        let mut active_transaction = self.handler.active_transaction.lock();
-        let recv_bytes = match &*active_transaction {
-            // The handler has stored the number of response bytes by updating.
-            // the recv_buffer length.
-            Some(transaction) => transaction.recv_buffer.size(),
+    fn channel_transact(
+        &self,
+        kernel: K,
+        send_buffer: SyscallBuffer,
+        recv_buffer: SyscallBuffer,
+        deadline: Instant<K::Clock>,
+    ) -> Result<usize> {
+        self.start_transaction(kernel, send_buffer, recv_buffer)?;
+
+        if let Err(e) = self
+            .base
+            .wait_until(kernel, Signals::READABLE | Signals::ERROR, deadline)
+        {
+            // Don't leave the transaction pending after returning.  If it can
+            // not be canceled, the handler completed it in the meantime.
+            if self.cancel_transaction(kernel).is_ok() {
+                return Err(e);
+            }
+        }
+
+        match *self.response_len.lock(kernel) {
+            Some(response_len) => Ok(response_len),
+            // The transaction was dropped because the handler's process
+            // exited.
+            None if self.peer_closed.load(Ordering::SeqCst) => Err(Error::Aborted),
+            // A transaction without a response was dropped.
+            None => Err(Error::Unavailable),
+        }
+    }
+
+    fn channel_async_transact(
+        &self,
+        kernel: K,
+        send_buffer: SyscallBuffer,
+        recv_buffer: SyscallBuffer,
+    ) -> Result<()> {
+        self.start_transaction(kernel, send_buffer, recv_buffer)
+    }
 
-            // Transaction was dropped.
-            None => return Err(Error::Unavailable),
+    fn channel_async_cancel(&self, kernel: K) -> Result<()> {
+        self.cancel_transaction(kernel)
+    }
+
+    fn object_close(&self, kernel: K) {
+        // Cancel our transaction, if it is in flight, and mark the handler's
+        // peer as closed with `active_transaction` locked so that the handler
+        // can not observe the transaction after it has been dropped.
+        let mut active_transaction = self.handler.active_transaction.lock();
+        let is_pending = active_transaction
+            .as_ref()
+            .is_some_and(|transaction| ptr::eq(&*transaction.initiator, self));
+        let transaction = if is_pending {
+            self.handler.base.state.lock(kernel).active_signals -=
+                Signals::READABLE | Signals::WRITEABLE;
+            active_transaction.take()
+        } else {
+            None
         };
-        *active_transaction = None;
+        self.handler.peer_closed.store(true, Ordering::SeqCst);
+        drop(active_transaction);
+        drop(transaction);
+
+        self.handler.base.raise(kernel, Signals::ERROR);
+    }
+
+    fn object_reopen(&self, kernel: K) {
+        // Drop any response or user signal from before the process exited.
+        *self.response_len.lock(kernel) = None;
+        self.base.state.lock(kernel).active_signals -= Signals::READABLE | Signals::USER;
+
+        let active_transaction = self.handler.active_transaction.lock();
+        self.handler.peer_closed.store(false, Ordering::SeqCst);
+        drop(active_transaction);
 
-        Ok(recv_bytes)
+        self.handler.base.state.lock(kernel).active_signals -= Signals::ERROR;
     }
 }
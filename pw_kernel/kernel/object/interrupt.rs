@@ -13,6 +13,7 @@
 // the License.
 
 use pw_status::Result;
+use syscall_defs::WaitReturn;
 use time::Instant;
 
 use crate::Kernel;
@@ -39,13 +40,19 @@ impl<K: Kernel> InterruptObject<K> {
 }
 
 impl<K: Kernel> KernelObject<K> for InterruptObject<K> {
+    fn object_base(&self) -> Option<&ObjectBase<K>> {
+        Some(&self.base)
+    }
+
     fn object_wait(
         &self,
         kernel: K,
         signal_mask: Signals,
         deadline: Instant<K::Clock>,
-    ) -> Result<Signals> {
-        self.base.wait_until(kernel, signal_mask, deadline)
+    ) -> Result<WaitReturn> {
+        self.base
+            .wait_until(kernel, signal_mask, deadline)
+            .map(WaitReturn::new)
     }
 
     fn interrupt_ack(&self, kernel: K, signal_mask: Signals) -> Result<()> {
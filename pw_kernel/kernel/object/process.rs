@@ -0,0 +1,121 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+use core::ptr::NonNull;
+
+use foreign_box::ForeignRcState;
+use pw_status::{Error, Result};
+use syscall_defs::WaitReturn;
+use time::Instant;
+
+use crate::Kernel;
+use crate::object::{KernelObject, ObjectBase, Signals};
+use crate::scheduler::process_manager::{self, FaultReport};
+use crate::scheduler::thread::Process;
+use crate::sync::spinlock::SpinLock;
+
+struct ProcessPtr<K: Kernel>(NonNull<Process<K>>);
+
+// Safety: Processes are statically allocated and their state is only accessed
+// with the scheduler lock held.
+unsafe impl<K: Kernel> Send for ProcessPtr<K> {}
+
+/// Object for supervising another process.
+///
+/// Allows the holder to terminate the process, restart it once it has exited
+/// and query its exit status and the fault which terminated it, if any.  `Signals::READABLE` is raised once the process
+/// has exited and cleared when it is restarted.
+pub struct ProcessObject<K: Kernel> {
+    base: ObjectBase<K>,
+    process: SpinLock<K, Option<ProcessPtr<K>>>,
+}
+
+impl<K: Kernel> ProcessObject<K> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            base: ObjectBase::new(),
+            process: SpinLock::new(None),
+        }
+    }
+
+    /// Binds the object to the process it supervises.
+    ///
+    /// # Returns
+    /// - `Error::FailedPrecondition`: Another object is already bound to
+    ///   `process`, or this object is already bound.
+    pub fn bind(&self, kernel: K, process: &mut Process<K>) -> Result<()> {
+        let mut bound_process = self.process.lock(kernel);
+        if bound_process.is_some() {
+            return Err(Error::FailedPrecondition);
+        }
+        let self_rc = unsafe { ForeignRcState::create_ref_from_inner(self) };
+        let process = NonNull::from(process);
+        process_manager::set_exit_observer(kernel, process, self_rc)?;
+        *bound_process = Some(ProcessPtr(process));
+        Ok(())
+    }
+
+    fn process(&self, kernel: K) -> Result<NonNull<Process<K>>> {
+        self.process
+            .lock(kernel)
+            .as_ref()
+            .map(|process| process.0)
+            .ok_or(Error::FailedPrecondition)
+    }
+
+    /// Called once the supervised process has exited.
+    pub(crate) fn process_exited(&self, kernel: K) {
+        self.base.raise(kernel, Signals::READABLE);
+    }
+
+    /// Called once the supervised process has been restarted.
+    pub(crate) fn process_restarted(&self, kernel: K) {
+        self.base.state.lock(kernel).active_signals -= Signals::READABLE;
+    }
+}
+
+impl<K: Kernel> KernelObject<K> for ProcessObject<K> {
+    fn object_base(&self) -> Option<&ObjectBase<K>> {
+        Some(&self.base)
+    }
+
+    fn object_wait(
+        &self,
+        kernel: K,
+        signal_mask: Signals,
+        deadline: Instant<K::Clock>,
+    ) -> Result<WaitReturn> {
+        self.base
+            .wait_until(kernel, signal_mask, deadline)
+            .map(WaitReturn::new)
+    }
+
+    fn process_terminate(&self, kernel: K) -> Result<()> {
+        process_manager::terminate_process(kernel, self.process(kernel)?)
+    }
+
+    #[cfg(feature = "user_space")]
+    fn process_restart(&self, kernel: K) -> Result<()> {
+        process_manager::restart_process(kernel, self.process(kernel)?)
+    }
+
+    fn process_exit_status(&self, kernel: K) -> Result<Result<()>> {
+        process_manager::exit_status(kernel, self.process(kernel)?)
+    }
+
+    fn process_fault(&self, kernel: K) -> Result<FaultReport> {
+        process_manager::fault_report(kernel, self.process(kernel)?).ok_or(Error::NotFound)
+    }
+}
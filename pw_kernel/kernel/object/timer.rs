@@ -0,0 +1,114 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+use pw_status::{Error, Result};
+use syscall_defs::WaitReturn;
+use time::{Duration, Instant};
+
+use crate::Kernel;
+use crate::object::{KernelObject, ObjectBase, Signals};
+use crate::scheduler::timer::{KernelTimer, TimerHandler};
+use crate::sync::spinlock::SpinLock;
+
+/// Object which raises `Signals::READABLE` when a one-shot or periodic timer
+/// expires.
+///
+/// `Signals::READABLE` is cleared once it has been returned from a wait so
+/// that each expiry of a periodic timer is reported once.
+pub struct TimerObject<K: Kernel> {
+    base: ObjectBase<K>,
+    timer: KernelTimer<K>,
+    // Set by `attach()`.  The timer queue keeps a reference to the object
+    // while the timer is running.
+    this: SpinLock<K, Option<&'static TimerObject<K>>>,
+}
+
+impl<K: Kernel> TimerObject<K> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            base: ObjectBase::new_with_clear_on_wait(Signals::READABLE),
+            timer: KernelTimer::new(),
+            this: SpinLock::new(None),
+        }
+    }
+
+    /// Allows the timer to be set.
+    ///
+    /// Must be called once the object has been allocated.  Until then
+    /// setting the timer fails with `Error::FailedPrecondition`.
+    ///
+    /// # Safety
+    /// The object must be statically allocated as the timer queue keeps a
+    /// `'static` reference to it.
+    pub unsafe fn attach(&self, kernel: K) {
+        // SAFETY: The caller guarantees that the object lives for `'static`.
+        let this: &'static Self = unsafe { &*core::ptr::from_ref(self) };
+        *self.this.lock(kernel) = Some(this);
+    }
+}
+
+impl<K: Kernel> TimerHandler<K> for TimerObject<K> {
+    fn expired(&self, kernel: K, _now: Instant<K::Clock>) {
+        self.base.raise(kernel, Signals::READABLE);
+    }
+}
+
+impl<K: Kernel> KernelObject<K> for TimerObject<K> {
+    fn object_base(&self) -> Option<&ObjectBase<K>> {
+        Some(&self.base)
+    }
+
+    fn object_wait(
+        &self,
+        kernel: K,
+        signal_mask: Signals,
+        deadline: Instant<K::Clock>,
+    ) -> Result<WaitReturn> {
+        self.base
+            .wait_until(kernel, signal_mask, deadline)
+            .map(WaitReturn::new)
+    }
+
+    fn timer_set(
+        &self,
+        kernel: K,
+        deadline: Instant<K::Clock>,
+        period: Option<Duration<K::Clock>>,
+    ) -> Result<()> {
+        if period.is_some_and(|period| period.ticks() <= 0) {
+            return Err(Error::InvalidArgument);
+        }
+        let Some(this) = *self.this.lock(kernel) else {
+            return Err(Error::FailedPrecondition);
+        };
+
+        // Drop any expiry of the previous configuration which has not been
+        // waited on yet.
+        self.base.state.lock(kernel).active_signals -= Signals::READABLE;
+
+        this.timer.start(kernel, this, deadline, period);
+        Ok(())
+    }
+
+    fn timer_cancel(&self, kernel: K) -> Result<()> {
+        self.timer.cancel(kernel);
+        Ok(())
+    }
+
+    fn object_close(&self, kernel: K) {
+        // Nobody is left to wait on the timer.
+        self.timer.cancel(kernel);
+    }
+}
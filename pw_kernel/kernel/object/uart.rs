@@ -0,0 +1,115 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+use pw_status::Result;
+use syscall_defs::WaitReturn;
+use time::Instant;
+
+use crate::Kernel;
+use crate::char_device::{CharDevice, CharDeviceListener};
+use crate::object::{KernelObject, ObjectBase, Signals, SyscallBuffer};
+
+/// Object exposing a kernel owned [`CharDevice`], typically a UART, to user
+/// space.
+///
+/// `Signals::READABLE` and `Signals::WRITEABLE` track the readiness of the
+/// device.  They are raised conservatively so a signal may be pending while
+/// the next read or write transfers zero bytes.
+pub struct UartObject<K: Kernel> {
+    base: ObjectBase<K>,
+    device: &'static dyn CharDevice<K>,
+}
+
+impl<K: Kernel> UartObject<K> {
+    #[must_use]
+    pub const fn new(device: &'static dyn CharDevice<K>) -> Self {
+        Self {
+            base: ObjectBase::new(),
+            device,
+        }
+    }
+
+    /// Registers the object as the device's listener.
+    ///
+    /// Must be called once the object has been allocated.  A device has a
+    /// single listener, so only one object may be attached to each device.
+    ///
+    /// # Safety
+    /// The object must be statically allocated as the device keeps a
+    /// `'static` reference to it.
+    pub unsafe fn attach(&self, kernel: K) {
+        // SAFETY: The caller guarantees that the object lives for `'static`.
+        let listener: &'static Self = unsafe { &*core::ptr::from_ref(self) };
+        self.device.set_listener(kernel, listener);
+        // Data may have been received before the object was attached and the
+        // write buffer is assumed to have space.
+        self.base
+            .raise(kernel, Signals::READABLE | Signals::WRITEABLE);
+    }
+}
+
+impl<K: Kernel> CharDeviceListener<K> for UartObject<K> {
+    fn readable(&self, kernel: K) {
+        self.base.raise(kernel, Signals::READABLE);
+    }
+
+    fn writable(&self, kernel: K) {
+        self.base.raise(kernel, Signals::WRITEABLE);
+    }
+}
+
+impl<K: Kernel> KernelObject<K> for UartObject<K> {
+    fn object_base(&self) -> Option<&ObjectBase<K>> {
+        Some(&self.base)
+    }
+
+    fn object_wait(
+        &self,
+        kernel: K,
+        signal_mask: Signals,
+        deadline: Instant<K::Clock>,
+    ) -> Result<WaitReturn> {
+        self.base
+            .wait_until(kernel, signal_mask, deadline)
+            .map(WaitReturn::new)
+    }
+
+    fn uart_read(&self, kernel: K, mut read_buffer: SyscallBuffer) -> Result<usize> {
+        let buffer = read_buffer.as_mut_slice()?;
+
+        // The signal is cleared before reading so that data received after
+        // the read raises it again.
+        self.base.state.lock(kernel).active_signals -= Signals::READABLE;
+        let len = self.device.read(kernel, buffer)?;
+        if len == buffer.len() {
+            // More data may be pending.
+            self.base.raise(kernel, Signals::READABLE);
+        }
+        Ok(len)
+    }
+
+    fn uart_write(&self, kernel: K, write_buffer: SyscallBuffer) -> Result<usize> {
+        let buffer = write_buffer.as_slice();
+
+        // The signal is cleared before writing so that space freed after the
+        // write raises it again.
+        self.base.state.lock(kernel).active_signals -= Signals::WRITEABLE;
+        let len = self.device.write(kernel, buffer)?;
+        if len == buffer.len() {
+            // More space may be available.
+            self.base.raise(kernel, Signals::WRITEABLE);
+        }
+        Ok(len)
+    }
+}
@@ -0,0 +1,254 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+use core::ptr::{self, NonNull};
+
+use foreign_box::{ForeignRc, ForeignRcState};
+use list::UnsafeList;
+use pw_status::{Error, Result};
+use syscall_defs::WaitReturn;
+use time::Instant;
+
+use crate::Kernel;
+use crate::object::{KernelObject, ObjectBase, Signals};
+use crate::sync::spinlock::SpinLock;
+
+list::define_adapter!(pub WaitGroupMemberListAdapter<K: Kernel> => ObjectBase<K>::wait_group_link);
+
+/// Per object record of its membership in a wait group.
+///
+/// Stored in the member's [`ObjectBaseState`](super::ObjectBaseState) and
+/// protected by the member's lock.
+pub(super) struct WaitGroupMembership<K: Kernel> {
+    pub(super) wait_group: ForeignRc<K::AtomicUsize, WaitGroupObject<K>>,
+    pub(super) signal_mask: Signals,
+    pub(super) user_data: usize,
+}
+
+struct WaitGroupMembers<K: Kernel> {
+    list: UnsafeList<ObjectBase<K>, WaitGroupMemberListAdapter<K>>,
+}
+
+// Safety: Members are only accessed with the wait group's member list lock
+// held.
+unsafe impl<K: Kernel> Send for WaitGroupMembers<K> {}
+
+/// Object for waiting on multiple objects at once.
+///
+/// An object may be a member of at most one wait group.  The list storage for
+/// membership lives in the member's [`ObjectBase`] allowing wait groups to be
+/// statically allocated without a bound on the number of members.
+pub struct WaitGroupObject<K: Kernel> {
+    base: ObjectBase<K>,
+    members: SpinLock<K, WaitGroupMembers<K>>,
+}
+
+impl<K: Kernel> WaitGroupObject<K> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            base: ObjectBase::new(),
+            members: SpinLock::new(WaitGroupMembers {
+                list: UnsafeList::new(),
+            }),
+        }
+    }
+
+    /// Called by a member when one of the signals it was added with is raised.
+    pub(super) fn member_signaled(&self, kernel: K) {
+        // Avoid redundant wakeups and, if wait groups have been added to each
+        // other, unbounded recursion.
+        if self
+            .base
+            .state
+            .lock(kernel)
+            .active_signals
+            .contains(Signals::READABLE)
+        {
+            return;
+        }
+        self.base.signal(kernel, Signals::READABLE);
+    }
+
+    /// Returns the first member which has pending signals.
+    ///
+    /// The returned member is moved to the back of the member list so that
+    /// members are serviced in a round robin fashion.
+    fn take_pending_member(&self, kernel: K) -> Option<WaitReturn> {
+        let mut members = self.members.lock(kernel);
+        let mut pending = None;
+
+        // Safety: The member list lock is held.
+        let _ = unsafe {
+            members
+                .list
+                .for_each(|member| -> core::result::Result<(), ()> {
+                    let mut state = member.state.lock(kernel);
+                    let Some((signal_mask, user_data)) = state
+                        .wait_group_membership
+                        .as_ref()
+                        .map(|membership| (membership.signal_mask, membership.user_data))
+                    else {
+                        return Ok(());
+                    };
+                    let pending_signals = state.active_signals & signal_mask;
+                    if pending_signals.is_empty() {
+                        return Ok(());
+                    }
+                    state.active_signals -= pending_signals & member.clear_on_wait;
+                    pending = Some((
+                        NonNull::from_ref(member),
+                        WaitReturn {
+                            user_data,
+                            pending_signals,
+                        },
+                    ));
+                    // Stop iteration.
+                    Err(())
+                })
+        };
+
+        let (member, wait_return) = pending?;
+
+        // Safety: The member list lock is held and `member` was found in the
+        // list above.
+        unsafe {
+            members.list.unlink_element_unchecked(member);
+            members.list.push_back_unchecked(member);
+        }
+
+        Some(wait_return)
+    }
+}
+
+impl<K: Kernel> KernelObject<K> for WaitGroupObject<K> {
+    fn object_base(&self) -> Option<&ObjectBase<K>> {
+        Some(&self.base)
+    }
+
+    fn object_wait(
+        &self,
+        kernel: K,
+        signal_mask: Signals,
+        deadline: Instant<K::Clock>,
+    ) -> Result<WaitReturn> {
+        // Wait groups only ever raise `Signals::READABLE`.
+        if !signal_mask.contains(Signals::READABLE) {
+            return Err(Error::InvalidArgument);
+        }
+
+        loop {
+            if let Some(wait_return) = self.take_pending_member(kernel) {
+                return Ok(wait_return);
+            }
+
+            // No members are pending.  Clear `Signals::READABLE` and check
+            // again to catch any member which was signaled in between.
+            self.base.state.lock(kernel).active_signals -= Signals::READABLE;
+            if let Some(wait_return) = self.take_pending_member(kernel) {
+                return Ok(wait_return);
+            }
+
+            self.base.wait_until(kernel, Signals::READABLE, deadline)?;
+        }
+    }
+
+    fn wait_group_add(
+        &self,
+        kernel: K,
+        object: ForeignRc<K::AtomicUsize, dyn KernelObject<K>>,
+        signal_mask: Signals,
+        user_data: usize,
+    ) -> Result<()> {
+        if user_data > WaitReturn::MAX_USER_DATA {
+            return Err(Error::InvalidArgument);
+        }
+
+        let Some(member) = object.object_base() else {
+            return Err(Error::InvalidArgument);
+        };
+
+        if ptr::eq(member, &self.base) {
+            return Err(Error::InvalidArgument);
+        }
+
+        // Safety: Wait group objects are only ever allocated inside a
+        // `ForeignRc`, so `self` points into a live `ForeignRcState`.
+        let self_rc = unsafe { ForeignRcState::create_ref_from_inner(self) };
+
+        let mut members = self.members.lock(kernel);
+        let mut member_state = member.state.lock(kernel);
+        if member_state.wait_group_membership.is_some() {
+            return Err(Error::ResourceExhausted);
+        }
+
+        member_state.wait_group_membership = Some(WaitGroupMembership {
+            wait_group: self_rc,
+            signal_mask,
+            user_data,
+        });
+        let already_pending = member_state.active_signals.intersects(signal_mask);
+        drop(member_state);
+
+        // Safety: The member list lock is held and the member was not in a wait
+        // group.  Objects are statically allocated and outlive their
+        // membership.
+        unsafe { members.list.push_back_unchecked(NonNull::from_ref(member)) };
+        drop(members);
+
+        if already_pending {
+            self.member_signaled(kernel);
+        }
+
+        Ok(())
+    }
+
+    fn wait_group_remove(
+        &self,
+        kernel: K,
+        object: ForeignRc<K::AtomicUsize, dyn KernelObject<K>>,
+    ) -> Result<()> {
+        let Some(member) = object.object_base() else {
+            return Err(Error::InvalidArgument);
+        };
+
+        let mut members = self.members.lock(kernel);
+        let mut member_state = member.state.lock(kernel);
+        let is_member = member_state
+            .wait_group_membership
+            .as_ref()
+            .is_some_and(|membership| ptr::eq(&*membership.wait_group, self));
+        if !is_member {
+            return Err(Error::NotFound);
+        }
+
+        let membership = member_state.wait_group_membership.take();
+        drop(member_state);
+
+        // Safety: The member list lock is held and the membership check above
+        // guarantees that `member` is in this wait group's list.
+        unsafe {
+            members
+                .list
+                .unlink_element_unchecked(NonNull::from_ref(member))
+        };
+        drop(members);
+
+        // Release the member's reference to the wait group outside of any
+        // locks.
+        drop(membership);
+
+        Ok(())
+    }
+}
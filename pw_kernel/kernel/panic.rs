@@ -14,6 +14,7 @@
 
 #[panic_handler]
 fn panic_handler(info: &core::panic::PanicInfo) -> ! {
+    crate::panic_flush();
     if let Some(location) = info.location() {
         panic_is_possible(
             location.file().as_ptr(),
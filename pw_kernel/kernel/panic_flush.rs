@@ -0,0 +1,44 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+// Only loaded and stored, which every supported architecture provides
+// atomically even when it lacks compare-and-swap.
+static FLUSH_HANDLER: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+/// Registers `handler` to push out buffered output before the system halts.
+///
+/// The handler is called on a panic or failed assertion so that the last
+/// messages written to a buffered device, such as an interrupt driven UART,
+/// are not lost.  It runs with the system in an unknown state, so it must
+/// neither block on locks nor rely on interrupts or the scheduler.
+///
+/// Replaces any previously registered handler.
+pub fn set_panic_flush_handler(handler: fn()) {
+    FLUSH_HANDLER.store(handler as *mut (), Ordering::Release);
+}
+
+/// Calls the handler registered with [`set_panic_flush_handler`], if any.
+pub fn panic_flush() {
+    let handler = FLUSH_HANDLER.load(Ordering::Acquire);
+    if handler.is_null() {
+        return;
+    }
+    // SAFETY: Non-null values are only ever stored from a `fn()` by
+    // `set_panic_flush_handler`.
+    let handler = unsafe { core::mem::transmute::<*mut (), fn()>(handler) };
+    handler();
+}
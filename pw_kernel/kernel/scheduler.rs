@@ -17,6 +17,7 @@ use core::ptr::NonNull;
 use core::sync::atomic::Ordering;
 
 use foreign_box::ForeignBox;
+use kernel_config::{KernelConfig, KernelConfigInterface};
 use list::*;
 use memory_config::MemoryConfig as _;
 use pw_atomic::{
@@ -26,22 +27,32 @@ use pw_log::info;
 use pw_status::{Error, Result};
 use time::Instant;
 
+use crate::interrupt_controller::InterruptController;
 use crate::object::NullObjectTable;
 use crate::scheduler::timer::Timer;
 use crate::sync::event::EventSignaler;
 use crate::sync::spinlock::SpinLockGuard;
 use crate::{Arch, Kernel};
 
-mod algorithm;
+pub mod algorithm;
+pub mod annotations;
+pub mod futex;
 mod locks;
 mod priority;
 pub mod priority_bitmask;
+mod priority_inheritance;
+pub mod process_manager;
+pub mod stack_check;
+pub mod stats;
 pub mod thread;
+pub mod thread_pool;
 pub mod timer;
+pub mod watchdog;
 
 use algorithm::{RescheduleReason, SchedulerAlgorithm};
-pub use locks::{SchedLockGuard, WaitQueueLock};
+pub use locks::{SchedLockGuard, WaitQueueLock, WaitQueueLockGuard};
 pub use priority::Priority;
+use priority_inheritance::update_inherited_priority;
 use thread::*;
 
 const LOG_SCHEDULER_EVENTS: bool = false;
@@ -78,17 +89,20 @@ pub fn start_thread<K: Kernel>(kernel: K, mut thread: ForeignBox<Thread<K>>) ->
     pw_assert::assert!(thread.state == State::Initial);
 
     thread.state = State::Ready;
+    thread.watchdog.last_check_in = kernel.now();
     let thread_ref = thread.get_ref(kernel);
 
     let mut sched_state = kernel.get_scheduler().lock(kernel);
 
     // If there is a current thread, insert it into the scheduler.
-    let id = if let Some(mut current_thread) = sched_state.current_thread.take() {
+    let id = if sched_state.current_thread.is_some() {
+        let mut current_thread = sched_state.take_current_thread(kernel);
         let id = current_thread.id();
         current_thread.state = State::Ready;
+        sched_state.preempted_thread = Some(NonNull::from_ref(&*current_thread));
         sched_state
             .algorithm
-            .schedule_thread(current_thread, RescheduleReason::Preempted);
+            .schedule_thread(kernel, current_thread, RescheduleReason::Preempted);
         id
     } else {
         Thread::<K>::null_id()
@@ -96,7 +110,7 @@ pub fn start_thread<K: Kernel>(kernel: K, mut thread: ForeignBox<Thread<K>>) ->
 
     sched_state
         .algorithm
-        .schedule_thread(thread, RescheduleReason::Started);
+        .schedule_thread(kernel, thread, RescheduleReason::Started);
 
     // Now that we've added the new thread, trigger a reschedule event.
     reschedule(kernel, sched_state, id);
@@ -129,7 +143,7 @@ pub fn bootstrap_scheduler<K: Kernel>(
 
     sched_state
         .algorithm
-        .schedule_thread(thread, RescheduleReason::Started);
+        .schedule_thread(kernel, thread, RescheduleReason::Started);
 
     info!("Context switching to first thread");
 
@@ -187,9 +201,16 @@ pub struct SchedulerState<K: Kernel> {
     process_list: UnsafeList<Process<K>, ProcessListAdapter<K>>,
 
     /// The algorithm used for choosing the next thread to run.
-    algorithm: SchedulerAlgorithm<K>,
+    algorithm: K::SchedulerAlgorithm,
 
     termination_queue: ForeignList<Thread<K>, ThreadListAdapter<K>>,
+
+    // Time at which the current thread was switched to.
+    running_since: Instant<K::Clock>,
+
+    // Thread which was switched away from while still ready to run, used to
+    // count preemptions when the next thread is chosen.
+    preempted_thread: Option<NonNull<Thread<K>>>,
 }
 
 unsafe impl<K: Kernel> Sync for SchedulerState<K> {}
@@ -211,8 +232,10 @@ impl<K: Kernel> SchedulerState<K> {
             current_thread: None,
             current_arch_thread_state: core::ptr::null_mut(),
             process_list: UnsafeList::new(),
-            algorithm: SchedulerAlgorithm::new(),
+            algorithm: <K::SchedulerAlgorithm as SchedulerAlgorithm<K>>::NEW,
             termination_queue: ForeignList::new(),
+            running_since: Instant::MIN,
+            preempted_thread: None,
         }
     }
 
@@ -231,11 +254,18 @@ impl<K: Kernel> SchedulerState<K> {
         self.current_arch_thread_state
     }
 
-    fn reschedule_current_thread(&mut self, reason: RescheduleReason) -> usize {
-        let mut current_thread = self.take_current_thread();
+    fn reschedule_current_thread(&mut self, kernel: K, reason: RescheduleReason) -> usize {
+        let mut current_thread = self.take_current_thread(kernel);
         let current_thread_id = current_thread.id();
         current_thread.state = State::Ready;
-        self.algorithm.schedule_thread(current_thread, reason);
+        if matches!(
+            reason,
+            RescheduleReason::Preempted | RescheduleReason::Ticked
+        ) {
+            self.preempted_thread = Some(NonNull::from_ref(&*current_thread));
+        }
+        self.algorithm
+            .schedule_thread(kernel, current_thread, reason);
         current_thread_id
     }
 
@@ -259,10 +289,15 @@ impl<K: Kernel> SchedulerState<K> {
         }
     }
 
-    pub fn take_current_thread(&mut self) -> ForeignBox<Thread<K>> {
-        let Some(thread) = self.current_thread.take() else {
+    /// Removes the current thread, charging it for the time it has run since
+    /// it was switched to.
+    pub fn take_current_thread(&mut self, kernel: K) -> ForeignBox<Thread<K>> {
+        let Some(mut thread) = self.current_thread.take() else {
             pw_assert::panic!("No current thread");
         };
+        let run_time = kernel.now() - self.running_since;
+        thread.stats.run_time = thread.stats.run_time + run_time;
+        stack_check::check_outgoing_stack(&mut thread);
         thread
     }
 
@@ -291,19 +326,6 @@ impl<K: Kernel> SchedulerState<K> {
     pub unsafe fn add_process_to_list(&mut self, process: NonNull<Process<K>>) {
         unsafe { self.process_list.push_front_unchecked(process) };
     }
-
-    #[allow(dead_code)]
-    pub fn dump_all_threads(&self) {
-        info!("List of all threads:");
-        unsafe {
-            let _ = self
-                .process_list
-                .for_each(|process| -> core::result::Result<(), ()> {
-                    process.dump();
-                    Ok(())
-                });
-        }
-    }
 }
 
 pub enum JoinResult<K: Kernel> {
@@ -328,7 +350,7 @@ impl<K: Kernel> SpinLockGuard<'_, K, SchedulerState<K>> {
             .load(Ordering::SeqCst)
             == 1
         {
-            let current_thread_id = self.reschedule_current_thread(reason);
+            let current_thread_id = self.reschedule_current_thread(kernel, reason);
             reschedule(kernel, self, current_thread_id)
         } else {
             kernel
@@ -347,6 +369,8 @@ impl<K: Kernel> SpinLockGuard<'_, K, SchedulerState<K>> {
         kernel_stack: Stack,
     ) {
         thread.stack = kernel_stack;
+        thread.stack_warned = false;
+        thread.stack_overflow = stack_check::StackOverflow::None;
         thread.process = process;
 
         thread.state = State::Initial;
@@ -436,7 +460,8 @@ impl<K: Kernel> SpinLockGuard<'_, K, SchedulerState<K>> {
         initial_pc: usize,
         args: (usize, usize, usize),
     ) -> Result<()> {
-        pw_assert::assert!(thread.state == State::New);
+        // Joined threads may be re-initialized to be started again.
+        pw_assert::assert!(matches!(thread.state, State::New | State::Joined));
 
         kernel_stack.initialize();
 
@@ -462,9 +487,9 @@ impl<K: Kernel> SpinLockGuard<'_, K, SchedulerState<K>> {
         unsafe { thread.thread.as_ref().state }
     }
 
-    fn thread_terminate(&mut self, thread_ref: &mut ThreadRef<K>) -> Result<()> {
+    fn thread_terminate(&mut self, kernel: K, mut thread_ptr: NonNull<Thread<K>>) -> Result<()> {
         // SAFETY: we have exclusive access to thread by virtue of the scheduler lock being held.
-        let thread = unsafe { thread_ref.thread.as_mut() };
+        let thread = unsafe { thread_ptr.as_mut() };
         match &mut thread.owner {
             ThreadOwner::None => Err(Error::InvalidArgument),
             ThreadOwner::Scheduler => {
@@ -476,6 +501,13 @@ impl<K: Kernel> SpinLockGuard<'_, K, SchedulerState<K>> {
                 // Mark thread as terminating so that it can clean itself up.
                 thread.terminating = true;
 
+                #[cfg(feature = "user_space")]
+                if thread.state == State::Ready
+                    && thread.arch_thread_state.get_mut().resumes_in_user_space()
+                {
+                    self.thread_redirect_to_exit(kernel, thread);
+                }
+
                 // Should we signal the scheduling algorithm to give it the
                 // chance to move the thread to the front of the queue?
 
@@ -484,13 +516,13 @@ impl<K: Kernel> SpinLockGuard<'_, K, SchedulerState<K>> {
             ThreadOwner::WaitQueue { queue, wait_type } => {
                 // First, mark the thread as being in the terminated state.
                 // SAFETY: Threads access is guarded by the scheduler lock.
-                unsafe { (*thread_ref.thread.as_ptr()).terminating = true }
+                unsafe { (*thread_ptr.as_ptr()).terminating = true }
 
                 // Second, wake the thread if it is in an interruptible wait.
                 if *wait_type == WaitType::Interruptible {
                     // SAFETY: All thread and wait queue accesses are guarded by
                     // the scheduler lock.
-                    let ret = unsafe { queue.as_mut().queue.remove_element(thread_ref.thread) };
+                    let ret = unsafe { queue.as_mut().queue.remove_element(thread_ptr) };
                     let Some(thread_box) = ret else {
                         // The a thread's owner is `ThreadOwner::WaitQueue` it will always
                         // be in the `WaitQueue`'s queue.  A failure to remove here would
@@ -499,13 +531,36 @@ impl<K: Kernel> SpinLockGuard<'_, K, SchedulerState<K>> {
                     };
                     thread.state = State::Ready;
                     self.algorithm
-                        .schedule_thread(thread_box, RescheduleReason::Woken);
+                        .schedule_thread(kernel, thread_box, RescheduleReason::Woken);
                 }
                 Ok(())
             }
         }
     }
 
+    /// Arranges for a terminating thread, which would otherwise resume
+    /// directly in user space, to exit when it is next switched to.
+    #[cfg(feature = "user_space")]
+    fn thread_redirect_to_exit(&mut self, kernel: K, thread: &mut Thread<K>) {
+        extern "C" fn exit_terminated_thread<K: Kernel>(kernel: usize, _: usize, _: usize) {
+            exit_thread(unsafe { K::from_usize(kernel) })
+        }
+
+        let process = self.kernel_process.get();
+
+        // SAFETY: The thread is not running so its kernel stack and
+        // architecture state are not in use.  The kernel process'
+        // `memory_config` is valid for the lifetime of the scheduler.
+        unsafe {
+            (*thread.arch_thread_state.get()).initialize_kernel_frame(
+                thread.stack,
+                &raw const (*process).memory_config,
+                exit_terminated_thread::<K>,
+                (kernel.into_usize(), 0, 0),
+            );
+        }
+    }
+
     fn thread_signal_join(&self, thread: &mut ThreadRef<K>) {
         if let Some(signaler) = unsafe { thread.thread.as_mut() }.join_event.take() {
             signaler.signal();
@@ -563,8 +618,8 @@ impl<K: Kernel> SpinLockGuard<'_, K, SchedulerState<K>> {
         thread.join_event = None;
     }
 
-    fn thread_exit(mut self, kernel: K) -> ! {
-        let mut current_thread = self.take_current_thread();
+    fn thread_exit(mut self, kernel: K) -> Self {
+        let mut current_thread = self.take_current_thread(kernel);
         let current_thread_id = current_thread.id();
 
         pw_assert::assert!(matches!(current_thread.owner, ThreadOwner::Scheduler));
@@ -589,9 +644,7 @@ impl<K: Kernel> SpinLockGuard<'_, K, SchedulerState<K>> {
 
         self.termination_queue.push_back(current_thread);
 
-        reschedule(kernel, self, current_thread_id);
-
-        pw_assert::panic!("thread_exit returned unexpectedly");
+        reschedule(kernel, self, current_thread_id)
     }
 
     fn thread_is_terminating(&self, thread: &ThreadRef<K>) -> bool {
@@ -634,12 +687,26 @@ fn reschedule<K: Kernel>(
         new_thread.id() as usize,
     );
     new_thread.state = State::Running;
+    sched_state.running_since = kernel.now();
+
+    let preempted_thread = sched_state.preempted_thread.take();
+    if current_thread_id != new_thread.id() {
+        new_thread.stats.context_switches += 1;
+        if let Some(mut preempted_thread) = preempted_thread {
+            // SAFETY: The preempted thread was put back into the run queue
+            // with the scheduler lock held, which has not been released since.
+            unsafe { preempted_thread.as_mut().stats.preemptions += 1 };
+        }
+    }
 
     if current_thread_id == new_thread.id() {
         sched_state.current_thread = Some(new_thread);
         return sched_state;
     }
 
+    // A thread which overflowed its stack exits rather than resume.
+    stack_check::exit_if_overflowed(kernel, &sched_state, &mut new_thread);
+
     let old_thread_state = sched_state.current_arch_thread_state;
     let new_thread_state = new_thread.arch_thread_state.get();
     sched_state.set_current_thread(new_thread);
@@ -671,7 +738,7 @@ pub fn yield_timeslice<K: Kernel>(kernel: K) {
         sched_state.current_thread_name() as &str,
         sched_state.current_thread_id() as usize
     );
-    sched_state.try_reschedule(kernel, RescheduleReason::Ticked);
+    sched_state.try_reschedule(kernel, RescheduleReason::Yielded);
 }
 
 #[allow(dead_code)]
@@ -684,7 +751,8 @@ fn preempt<K: Kernel>(kernel: K) {
         sched_state.current_thread_id() as usize
     );
 
-    let current_thread_id = sched_state.reschedule_current_thread(RescheduleReason::Preempted);
+    let current_thread_id =
+        sched_state.reschedule_current_thread(kernel, RescheduleReason::Preempted);
 
     reschedule(kernel, sched_state, current_thread_id);
 }
@@ -724,13 +792,93 @@ pub fn tick<K: Kernel>(kernel: K, now: Instant<K::Clock>) {
         .try_reschedule(kernel, RescheduleReason::Ticked);
 }
 
+/// Sleeps until an interrupt arrives.  Called in a loop by the idle thread.
+///
+/// With [`KernelConfigInterface::TICKLESS_IDLE`] enabled and no other thread
+/// ready to run, the architecture is allowed to suppress the scheduler tick
+/// until the earliest deadline in the timer queue.
+pub(crate) fn idle<K: Kernel>(kernel: K) {
+    // Interrupts stay disabled until the CPU has woken so that a timer
+    // scheduled or a thread woken by an interrupt handler can not be missed
+    // between computing the wakeup time and going to sleep.
+    K::InterruptController::disable_interrupts();
+    let wakeup = if KernelConfig::TICKLESS_IDLE {
+        next_wakeup(kernel)
+    } else {
+        None
+    };
+    kernel.idle(wakeup);
+    K::InterruptController::enable_interrupts();
+}
+
+/// Returns the time at which the scheduler next needs to run, or `None` if the
+/// scheduler tick is needed to end the idle thread's timeslice.
+fn next_wakeup<K: Kernel>(kernel: K) -> Option<Instant<K::Clock>> {
+    if kernel
+        .get_scheduler()
+        .lock(kernel)
+        .algorithm
+        .has_ready_threads()
+    {
+        return None;
+    }
+
+    Some(
+        kernel
+            .get_timer_queue()
+            .lock(kernel)
+            .next_deadline()
+            .unwrap_or(Instant::MAX),
+    )
+}
+
 /// Exit the currently running thread.
 ///
 /// The thread will enter `State::Terminated`, wait for all outstanding
 /// references to be dropped, then wait to be joined.
 #[allow(dead_code)]
 pub fn exit_thread<K: Kernel>(kernel: K) -> ! {
-    kernel.get_scheduler().lock(kernel).thread_exit(kernel)
+    let mut sched_state = kernel.get_scheduler().lock(kernel);
+    let process = sched_state.current_thread_mut().process;
+    // SAFETY: *process is only accessed with the scheduler lock held.
+    let process_exited = unsafe { (*process).thread_exited() };
+    drop(sched_state);
+
+    // Once the last thread of a process has exited, its objects are closed so
+    // that any peers are not left waiting on it.
+    if process_exited {
+        // SAFETY: A process outlives its threads and its object table is
+        // immutable.
+        unsafe { (*process).close_objects(kernel) };
+
+        let sched_state = kernel.get_scheduler().lock(kernel);
+        // SAFETY: *process is only accessed with the scheduler lock held.
+        let observer = unsafe { (*process).objects_closed() };
+        drop(sched_state);
+        if let Some(observer) = observer {
+            observer.process_exited(kernel);
+        }
+    }
+
+    let _sched_state = kernel.get_scheduler().lock(kernel).thread_exit(kernel);
+
+    pw_assert::panic!("thread_exit returned unexpectedly");
+}
+
+/// Exits the current thread if it has been requested to terminate.
+///
+/// Called by architecture implementations before returning to user space so
+/// that terminated user threads do not continue to run.
+#[cfg(feature = "user_space")]
+pub fn exit_if_terminating<K: Kernel>(kernel: K) {
+    let terminating = kernel
+        .get_scheduler()
+        .lock(kernel)
+        .current_thread()
+        .terminating;
+    if terminating {
+        exit_thread(kernel);
+    }
 }
 
 pub fn sleep_until<K: Kernel>(kernel: K, deadline: Instant<K::Clock>) -> Result<()> {
@@ -750,8 +898,17 @@ pub fn sleep_until<K: Kernel>(kernel: K, deadline: Instant<K::Clock>) -> Result<
 
 pub struct WaitQueue<K: Kernel> {
     queue: ForeignList<Thread<K>, ThreadListAdapter<K>>,
+
+    // Thread holding the resource the queue's waiters are waiting on, if any.
+    // See `priority_inheritance`.
+    owner: Option<NonNull<Thread<K>>>,
+
+    // Link in the owner's list of owned wait queues.
+    owned_link: Link,
 }
 
+list::define_adapter!(pub OwnedWaitQueueListAdapter<K: Kernel> => WaitQueue<K>::owned_link);
+
 unsafe impl<K: Kernel> Sync for WaitQueue<K> {}
 unsafe impl<K: Kernel> Send for WaitQueue<K> {}
 
@@ -761,6 +918,8 @@ impl<K: Kernel> WaitQueue<K> {
     pub const fn new() -> Self {
         Self {
             queue: ForeignList::new(),
+            owner: None,
+            owned_link: Link::new(),
         }
     }
 }
@@ -791,6 +950,10 @@ impl<K: Kernel> SchedLockGuard<'_, K, WaitQueue<K>> {
             wait_type,
         };
         self.queue.push_back(thread);
+        if let Some(owner) = self.owner {
+            // SAFETY: The scheduler lock is held.
+            unsafe { update_inherited_priority(&mut self.sched_mut().algorithm, owner) };
+        }
         wait_queue_debug!(
             "WaitQueue: thread '{}' ({:#010x}) rescheduling",
             current_thread_name as &str,
@@ -821,26 +984,67 @@ impl<K: Kernel> SchedLockGuard<'_, K, WaitQueue<K>> {
             thread.id() as usize
         );
         thread.state = State::Ready;
+        let kernel = self.kernel;
         self.sched_mut()
             .algorithm
-            .schedule_thread(thread, RescheduleReason::Woken);
+            .schedule_thread(kernel, thread, RescheduleReason::Woken);
+        if let Some(owner) = self.owner {
+            // SAFETY: The scheduler lock is held.
+            unsafe { update_inherited_priority(&mut self.sched_mut().algorithm, owner) };
+        }
         Some(Error::DeadlineExceeded)
     }
 
     #[allow(clippy::must_use_candidate)]
     pub fn wake_one(mut self) -> (Self, WakeResult) {
-        let Some(mut thread) = self.queue.pop_head() else {
+        let Some(thread) = self.queue.pop_head() else {
             return (self, WakeResult::QueueEmpty);
         };
+        self.wake_thread(thread)
+    }
+
+    /// Wakes the first thread in the queue for which `predicate` returns
+    /// `true`.
+    #[allow(clippy::must_use_candidate)]
+    pub fn wake_one_matching<F: FnMut(&Thread<K>) -> bool>(
+        mut self,
+        mut predicate: F,
+    ) -> (Self, WakeResult) {
+        let mut matching = None;
+        let _ = self
+            .queue
+            .for_each(|thread| -> core::result::Result<(), ()> {
+                if predicate(thread) {
+                    matching = Some(NonNull::from_ref(thread));
+                    // Stop iteration.
+                    return Err(());
+                }
+                Ok(())
+            });
+
+        let Some(matching) = matching else {
+            return (self, WakeResult::QueueEmpty);
+        };
+
+        // Safety: `matching` was found in the queue above and the queue can
+        // not have changed since the scheduler lock is still held.
+        let Some(thread) = (unsafe { self.queue.remove_element(matching) }) else {
+            pw_assert::panic!("Thread no longer in wait queue");
+        };
+        self.wake_thread(thread)
+    }
+
+    fn wake_thread(mut self, mut thread: ForeignBox<Thread<K>>) -> (Self, WakeResult) {
         wait_queue_debug!(
             "WaitQueue: waking thread '{}' ({:#010x})",
             thread.name as &str,
             thread.id() as usize
         );
         thread.state = State::Ready;
+        let kernel = self.kernel;
         self.sched_mut()
             .algorithm
-            .schedule_thread(thread, RescheduleReason::Woken);
+            .schedule_thread(kernel, thread, RescheduleReason::Woken);
 
         (
             self.try_reschedule(RescheduleReason::Preempted),
@@ -867,7 +1071,8 @@ impl<K: Kernel> SchedLockGuard<'_, K, WaitQueue<K>> {
             return (self, Err(Error::Cancelled));
         }
 
-        let thread = self.sched_mut().take_current_thread();
+        let kernel = self.kernel;
+        let thread = self.sched_mut().take_current_thread(kernel);
         wait_queue_debug!(
             "WaitQueue: thread '{}' ({:#010x}) waiting",
             thread.name as &str,
@@ -900,7 +1105,8 @@ impl<K: Kernel> SchedLockGuard<'_, K, WaitQueue<K>> {
             return (self, Err(Error::Cancelled));
         }
 
-        let mut thread = self.sched_mut().take_current_thread();
+        let kernel = self.kernel;
+        let mut thread = self.sched_mut().take_current_thread(kernel);
         wait_queue_debug!(
             "WaitQueue: thread '{}' ({:#010x}) wait_until",
             thread.name as &str,
@@ -12,19 +12,39 @@
 // License for the specific language governing permissions and limitations under
 // the License.
 
-use core::mem::MaybeUninit;
+use core::ptr::NonNull;
 
 use foreign_box::ForeignBox;
 use list::ForeignList;
+use time::{Duration, Instant};
 
 use crate::Kernel;
 use crate::scheduler::Priority;
-use crate::scheduler::priority_bitmask::PriorityBitmask;
-use crate::scheduler::thread::ThreadOwner;
 use crate::thread::{Thread, ThreadListAdapter};
 
+mod deadline;
+mod priority;
+
+pub use deadline::{
+    DeadlinePolicy, DeadlineScheduler, EarliestDeadlineFirst, EdfScheduler, RateMonotonic,
+    RateMonotonicScheduler,
+};
+pub use priority::PriorityScheduler;
+
 type RunQueue<K> = ForeignList<Thread<K>, ThreadListAdapter<K>>;
 
+/// The scheduler algorithm selected by the target's `scheduler` constraint.
+#[cfg(not(any(feature = "scheduler_edf", feature = "scheduler_rate_monotonic")))]
+pub type TargetSchedulerAlgorithm<K> = PriorityScheduler<K>;
+
+/// The scheduler algorithm selected by the target's `scheduler` constraint.
+#[cfg(feature = "scheduler_edf")]
+pub type TargetSchedulerAlgorithm<K> = EdfScheduler<K>;
+
+/// The scheduler algorithm selected by the target's `scheduler` constraint.
+#[cfg(feature = "scheduler_rate_monotonic")]
+pub type TargetSchedulerAlgorithm<K> = RateMonotonicScheduler<K>;
+
 /// The reason a thread is being rescheduled. This can be used as a hint to the
 /// scheduling algorithm to determine where the thread should end up in the
 /// queue of pending threads.
@@ -33,6 +53,8 @@ pub enum RescheduleReason {
     Preempted,
     /// A scheduler tick occurred causing the currently running thread to be readded to the scheduler.
     Ticked,
+    /// The currently running thread gave up the remainder of its timeslice.
+    Yielded,
     /// A currently waiting thread has been unblocked.
     Woken,
     /// A thread has been started and is being added to the scheduler for the first time.
@@ -40,92 +62,179 @@ pub enum RescheduleReason {
 }
 
 /// Per-thread state used by the scheduling algorithm.
-pub struct SchedulerAlgorithmThreadState {
-    /// The current priority of the thread.
-    current_priority: Priority,
+pub struct SchedulerAlgorithmThreadState<K: Kernel> {
+    /// The priority the thread was created with.
+    base_priority: Priority,
+
+    /// The priority the thread is scheduled at.
+    ///
+    /// Never lower than `base_priority`.  Raised above it while the thread
+    /// owns a wait queue, such as a held mutex, with higher priority waiters.
+    effective_priority: Priority,
+
+    /// The number of scheduler ticks the thread runs for before it is rotated
+    /// to the back of its run queue.
+    timeslice_ticks: u32,
+
+    /// The number of scheduler ticks left in the thread's current timeslice.
+    remaining_ticks: u32,
+
+    /// The time, relative to being started or woken, by which the thread
+    /// should have completed its work.
+    ///
+    /// Only used by deadline based algorithms.  Threads without a relative
+    /// deadline are scheduled by priority.  Rate monotonic scheduling treats
+    /// the relative deadline as the thread's period.
+    relative_deadline: Option<Duration<K::Clock>>,
+
+    /// The absolute deadline of the thread's current work.
+    deadline: Instant<K::Clock>,
+
+    /// The run time the thread may use each time it is started or woken
+    /// before it is considered to have overrun.
+    ///
+    /// Only used by deadline based algorithms.
+    budget: Option<Duration<K::Clock>>,
+
+    /// The thread's total run time when its current work began.
+    activation_run_time: Duration<K::Clock>,
+
+    /// Whether the thread has used up its budget for its current work.
+    ///
+    /// Deadline based algorithms schedule an overrunning thread by priority
+    /// until it is next woken.
+    overrun: bool,
+
+    /// The number of times the thread has overrun its budget.
+    overruns: u32,
 }
 
-impl SchedulerAlgorithmThreadState {
+impl<K: Kernel> SchedulerAlgorithmThreadState<K> {
+    /// Timeslice length of threads which do not configure their own.
+    pub const DEFAULT_TIMESLICE_TICKS: u32 = 1;
+
+    // Offset of the effective priority, for use in debug annotations.
+    pub(super) const EFFECTIVE_PRIORITY_OFFSET: usize =
+        core::mem::offset_of!(Self, effective_priority);
+
     #[allow(clippy::new_without_default)]
     #[must_use]
-    pub const fn new(current_priority: Priority) -> Self {
-        Self { current_priority }
+    pub const fn new(base_priority: Priority) -> Self {
+        Self {
+            base_priority,
+            effective_priority: base_priority,
+            timeslice_ticks: Self::DEFAULT_TIMESLICE_TICKS,
+            remaining_ticks: Self::DEFAULT_TIMESLICE_TICKS,
+            relative_deadline: None,
+            deadline: Instant::MAX,
+            budget: None,
+            activation_run_time: Duration::from_secs(0),
+            overrun: false,
+            overruns: 0,
+        }
     }
-}
 
-/// The algorithm used for determining which thread to run next.
-pub struct SchedulerAlgorithm<K: Kernel> {
-    // Array of run queues, one for each priority level.
-    // Index 0 is the lowest priority, NUM_PRIORITIES - 1 is the highest.
-    run_queues: [RunQueue<K>; Priority::NUM_PRIORITIES],
-    // Bitmask to track non-empty run queues.
-    ready_bitmask: PriorityBitmask,
-}
+    #[must_use]
+    pub const fn base_priority(&self) -> Priority {
+        self.base_priority
+    }
 
-unsafe impl<K: Kernel> Sync for SchedulerAlgorithm<K> {}
-unsafe impl<K: Kernel> Send for SchedulerAlgorithm<K> {}
+    #[must_use]
+    pub const fn effective_priority(&self) -> Priority {
+        self.effective_priority
+    }
 
-impl<K: Kernel> SchedulerAlgorithm<K> {
-    #[allow(clippy::new_without_default)]
     #[must_use]
-    pub const fn new() -> Self {
-        // Initialize the array of ForeignLists. There are a few limitations
-        // from running in a `const` context that make this more complicated
-        // than usual.
-        // - ForeignList does not implement `Copy`
-        // - `Default::default` and `core::array::from_fn` don't support `const`
-        // - `const` `for` loops are an unstable feature
-        //
-        // Instead a combination of `MaybeUninit`, `while`, and `transmute` are
-        // used.
-        let run_queues = {
-            let mut queues =
-                [const { MaybeUninit::<RunQueue<K>>::uninit() }; Priority::NUM_PRIORITIES];
-
-            let mut i = 0;
-            while i < Priority::NUM_PRIORITIES {
-                queues[i].write(ForeignList::new());
-                i += 1;
-            }
-
-            // SAFETY: All elements have been initiailzed in the loop above.
-            unsafe {
-                core::mem::transmute::<
-                    [MaybeUninit<RunQueue<K>>; Priority::NUM_PRIORITIES],
-                    [RunQueue<K>; Priority::NUM_PRIORITIES],
-                >(queues)
-            }
-        };
+    pub const fn timeslice_ticks(&self) -> u32 {
+        self.timeslice_ticks
+    }
 
-        Self {
-            run_queues,
-            ready_bitmask: PriorityBitmask::new(),
-        }
+    #[must_use]
+    pub const fn remaining_ticks(&self) -> u32 {
+        self.remaining_ticks
     }
 
-    pub fn schedule_thread(&mut self, mut thread: ForeignBox<Thread<K>>, reason: RescheduleReason) {
-        thread.owner = ThreadOwner::Scheduler;
-        let priority = thread.algorithm_state.current_priority;
-        self.ready_bitmask.set_priority(priority);
-        let run_queue = &mut self.run_queues[priority as usize];
-        match reason {
-            RescheduleReason::Preempted => {
-                run_queue.push_front(thread);
-            }
-            RescheduleReason::Started | RescheduleReason::Ticked | RescheduleReason::Woken => {
-                run_queue.push_back(thread);
-            }
-        }
+    /// Sets the timeslice length and starts a fresh timeslice.
+    pub fn set_timeslice_ticks(&mut self, ticks: u32) {
+        pw_assert::assert!(ticks > 0, "Timeslice must be at least one tick");
+        self.timeslice_ticks = ticks;
+        self.remaining_ticks = ticks;
+    }
+
+    #[must_use]
+    pub const fn relative_deadline(&self) -> Option<Duration<K::Clock>> {
+        self.relative_deadline
     }
 
-    pub fn get_next_thread(&mut self) -> Option<ForeignBox<Thread<K>>> {
-        let priority = self.ready_bitmask.get_highest_priority()?;
+    #[must_use]
+    pub const fn deadline(&self) -> Instant<K::Clock> {
+        self.deadline
+    }
 
-        let run_queue = &mut self.run_queues[priority as usize];
-        let thread = run_queue.pop_head();
-        if run_queue.is_empty() {
-            self.ready_bitmask.clear_priority(priority);
-        }
-        thread
+    pub fn set_relative_deadline(&mut self, relative_deadline: Option<Duration<K::Clock>>) {
+        self.relative_deadline = relative_deadline;
+    }
+
+    #[must_use]
+    pub const fn budget(&self) -> Option<Duration<K::Clock>> {
+        self.budget
+    }
+
+    pub fn set_budget(&mut self, budget: Option<Duration<K::Clock>>) {
+        self.budget = budget;
     }
+
+    /// Returns `true` if the thread has used up its budget for its current
+    /// work.
+    #[must_use]
+    pub const fn overrun(&self) -> bool {
+        self.overrun
+    }
+
+    /// Returns the number of times the thread has overrun its budget.
+    #[must_use]
+    pub const fn overruns(&self) -> u32 {
+        self.overruns
+    }
+
+    /// Accounts for a scheduler tick, returning `true` if the timeslice has
+    /// been used up.
+    fn consume_tick(&mut self) -> bool {
+        self.remaining_ticks = self.remaining_ticks.saturating_sub(1);
+        self.remaining_ticks == 0
+    }
+
+    fn refill(&mut self) {
+        self.remaining_ticks = self.timeslice_ticks;
+    }
+}
+
+/// The algorithm used for determining which thread to run next.
+///
+/// All methods are called with the scheduler lock held.
+pub trait SchedulerAlgorithm<K: Kernel>: Send + Sync {
+    /// An algorithm with no ready threads.
+    const NEW: Self;
+
+    /// Adds a ready thread to the algorithm.
+    fn schedule_thread(
+        &mut self,
+        kernel: K,
+        thread: ForeignBox<Thread<K>>,
+        reason: RescheduleReason,
+    );
+
+    /// Removes and returns the thread which should run next.
+    fn get_next_thread(&mut self) -> Option<ForeignBox<Thread<K>>>;
+
+    /// Returns `true` if any thread is ready to run.
+    fn has_ready_threads(&self) -> bool;
+
+    /// Changes the effective priority of `thread`, repositioning it if it is
+    /// ready to run.
+    ///
+    /// # Safety
+    /// The caller must have exclusive access to `thread`, which is guaranteed
+    /// by holding the scheduler lock.
+    unsafe fn set_effective_priority(&mut self, thread: NonNull<Thread<K>>, priority: Priority);
 }
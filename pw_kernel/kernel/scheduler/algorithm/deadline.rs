@@ -0,0 +1,195 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+use core::cmp::Ordering;
+use core::marker::PhantomData;
+use core::ptr::NonNull;
+
+use foreign_box::ForeignBox;
+use list::ForeignList;
+use pw_log::warn;
+use time::{Duration, Instant};
+
+use super::{
+    PriorityScheduler, RescheduleReason, RunQueue, SchedulerAlgorithm,
+    SchedulerAlgorithmThreadState,
+};
+use crate::Kernel;
+use crate::scheduler::Priority;
+use crate::scheduler::thread::ThreadOwner;
+use crate::thread::Thread;
+
+/// The order in which a [`DeadlineScheduler`] runs threads with a relative
+/// deadline.
+pub trait DeadlinePolicy<K: Kernel>: 'static {
+    /// Orders two ready threads, with the thread to run first ordered less.
+    fn cmp(a: &SchedulerAlgorithmThreadState<K>, b: &SchedulerAlgorithmThreadState<K>) -> Ordering;
+}
+
+/// Runs the ready thread with the earliest absolute deadline first.
+pub struct EarliestDeadlineFirst;
+
+impl<K: Kernel> DeadlinePolicy<K> for EarliestDeadlineFirst {
+    fn cmp(a: &SchedulerAlgorithmThreadState<K>, b: &SchedulerAlgorithmThreadState<K>) -> Ordering {
+        a.deadline.cmp(&b.deadline)
+    }
+}
+
+/// Runs the ready thread with the shortest period, its relative deadline,
+/// first.
+pub struct RateMonotonic;
+
+impl<K: Kernel> DeadlinePolicy<K> for RateMonotonic {
+    fn cmp(a: &SchedulerAlgorithmThreadState<K>, b: &SchedulerAlgorithmThreadState<K>) -> Ordering {
+        a.relative_deadline.cmp(&b.relative_deadline)
+    }
+}
+
+/// Earliest deadline first scheduler.
+pub type EdfScheduler<K> = DeadlineScheduler<K, EarliestDeadlineFirst>;
+
+/// Rate monotonic scheduler.
+pub type RateMonotonicScheduler<K> = DeadlineScheduler<K, RateMonotonic>;
+
+/// Scheduler for threads with a relative deadline.
+///
+/// Threads with a relative deadline are given an absolute deadline each time
+/// they are started or woken and ready threads are run in the order chosen by
+/// `P`.  Threads without a relative deadline, including the idle thread, run
+/// by priority only when no thread with a deadline is ready.
+///
+/// A thread which uses more than its budget of run time since it was last
+/// started or woken has overrun.  It is scheduled by priority, alongside the
+/// threads without a deadline, until it is next woken so that it can not
+/// starve threads which are meeting their deadlines.
+pub struct DeadlineScheduler<K: Kernel, P: DeadlinePolicy<K>> {
+    // Ready threads with a deadline, sorted by `P`.
+    deadline_queue: RunQueue<K>,
+    // Ready threads without a deadline or which have overrun their budget.
+    background: PriorityScheduler<K>,
+    _policy: PhantomData<P>,
+}
+
+unsafe impl<K: Kernel, P: DeadlinePolicy<K>> Sync for DeadlineScheduler<K, P> {}
+unsafe impl<K: Kernel, P: DeadlinePolicy<K>> Send for DeadlineScheduler<K, P> {}
+
+impl<K: Kernel, P: DeadlinePolicy<K>> DeadlineScheduler<K, P> {
+    #[allow(clippy::new_without_default)]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            deadline_queue: ForeignList::new(),
+            background: PriorityScheduler::new(),
+            _policy: PhantomData,
+        }
+    }
+}
+
+// Begins a new piece of work for a thread which has been started or woken.
+fn activate<K: Kernel>(kernel: K, thread: &mut Thread<K>, relative_deadline: Duration<K::Clock>) {
+    let run_time = thread.stats.run_time;
+    let state = &mut thread.algorithm_state;
+    state.deadline = kernel
+        .now()
+        .checked_add_duration(relative_deadline)
+        .unwrap_or(Instant::MAX);
+    state.activation_run_time = run_time;
+    state.overrun = false;
+}
+
+// Returns `true` if the thread has used up its budget for its current work.
+fn check_budget<K: Kernel>(thread: &mut Thread<K>) -> bool {
+    let run_time = thread.stats.run_time;
+    let state = &mut thread.algorithm_state;
+    if state.overrun {
+        return true;
+    }
+    let Some(budget) = state.budget else {
+        return false;
+    };
+    if run_time - state.activation_run_time <= budget {
+        return false;
+    }
+    state.overrun = true;
+    state.overruns = state.overruns.saturating_add(1);
+    warn!(
+        "Thread '{}' ({:#010x}) overran its budget",
+        thread.name as &str,
+        thread.id() as usize
+    );
+    true
+}
+
+impl<K: Kernel, P: DeadlinePolicy<K>> SchedulerAlgorithm<K> for DeadlineScheduler<K, P> {
+    const NEW: Self = Self::new();
+
+    fn schedule_thread(
+        &mut self,
+        kernel: K,
+        mut thread: ForeignBox<Thread<K>>,
+        reason: RescheduleReason,
+    ) {
+        let Some(relative_deadline) = thread.algorithm_state.relative_deadline else {
+            self.background.schedule_thread(kernel, thread, reason);
+            return;
+        };
+
+        // Starting or waking a thread begins a new piece of work.
+        if matches!(reason, RescheduleReason::Started | RescheduleReason::Woken) {
+            activate(kernel, &mut thread, relative_deadline);
+        }
+
+        if check_budget(&mut thread) {
+            self.background.schedule_thread(kernel, thread, reason);
+            return;
+        }
+
+        thread.owner = ThreadOwner::Scheduler;
+        // A preempted thread goes ahead of threads which compare equal so
+        // that it resumes as soon as possible.  Otherwise threads which
+        // compare equal are run in the order they became ready.
+        let tie = match reason {
+            RescheduleReason::Preempted => Ordering::Less,
+            _ => Ordering::Greater,
+        };
+        self.deadline_queue.sorted_insert_by(thread, |a, b| {
+            P::cmp(&a.algorithm_state, &b.algorithm_state).then(tie)
+        });
+    }
+
+    fn get_next_thread(&mut self) -> Option<ForeignBox<Thread<K>>> {
+        self.deadline_queue
+            .pop_head()
+            .or_else(|| self.background.get_next_thread())
+    }
+
+    fn has_ready_threads(&self) -> bool {
+        !self.deadline_queue.is_empty() || self.background.has_ready_threads()
+    }
+
+    unsafe fn set_effective_priority(
+        &mut self,
+        mut thread: NonNull<Thread<K>>,
+        priority: Priority,
+    ) {
+        let thread_ref = unsafe { thread.as_mut() };
+        // Priority has no effect on the order of threads with a deadline.
+        let state = &mut thread_ref.algorithm_state;
+        if state.relative_deadline.is_some() && !state.overrun {
+            state.effective_priority = priority;
+            return;
+        }
+        unsafe { self.background.set_effective_priority(thread, priority) };
+    }
+}
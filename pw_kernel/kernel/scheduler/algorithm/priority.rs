@@ -0,0 +1,165 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+use core::mem::MaybeUninit;
+use core::ptr::NonNull;
+
+use foreign_box::ForeignBox;
+use list::ForeignList;
+
+use super::{RescheduleReason, RunQueue, SchedulerAlgorithm};
+use crate::Kernel;
+use crate::scheduler::Priority;
+use crate::scheduler::priority_bitmask::PriorityBitmask;
+use crate::scheduler::thread::{State, ThreadOwner};
+use crate::thread::Thread;
+
+/// Fixed priority scheduler with round-robin scheduling within each priority
+/// level.
+pub struct PriorityScheduler<K: Kernel> {
+    // Array of run queues, one for each priority level.
+    // Index 0 is the lowest priority, NUM_PRIORITIES - 1 is the highest.
+    run_queues: [RunQueue<K>; Priority::NUM_PRIORITIES],
+    // Bitmask to track non-empty run queues.
+    ready_bitmask: PriorityBitmask,
+}
+
+unsafe impl<K: Kernel> Sync for PriorityScheduler<K> {}
+unsafe impl<K: Kernel> Send for PriorityScheduler<K> {}
+
+impl<K: Kernel> PriorityScheduler<K> {
+    #[allow(clippy::new_without_default)]
+    #[must_use]
+    pub const fn new() -> Self {
+        // Initialize the array of ForeignLists. There are a few limitations
+        // from running in a `const` context that make this more complicated
+        // than usual.
+        // - ForeignList does not implement `Copy`
+        // - `Default::default` and `core::array::from_fn` don't support `const`
+        // - `const` `for` loops are an unstable feature
+        //
+        // Instead a combination of `MaybeUninit`, `while`, and `transmute` are
+        // used.
+        let run_queues = {
+            let mut queues =
+                [const { MaybeUninit::<RunQueue<K>>::uninit() }; Priority::NUM_PRIORITIES];
+
+            let mut i = 0;
+            while i < Priority::NUM_PRIORITIES {
+                queues[i].write(ForeignList::new());
+                i += 1;
+            }
+
+            // SAFETY: All elements have been initiailzed in the loop above.
+            unsafe {
+                core::mem::transmute::<
+                    [MaybeUninit<RunQueue<K>>; Priority::NUM_PRIORITIES],
+                    [RunQueue<K>; Priority::NUM_PRIORITIES],
+                >(queues)
+            }
+        };
+
+        Self {
+            run_queues,
+            ready_bitmask: PriorityBitmask::new(),
+        }
+    }
+
+    fn enqueue(&mut self, mut thread: ForeignBox<Thread<K>>, reason: RescheduleReason) {
+        thread.owner = ThreadOwner::Scheduler;
+        let priority = thread.algorithm_state.effective_priority;
+        self.ready_bitmask.set_priority(priority);
+        let run_queue = &mut self.run_queues[priority as usize];
+        match reason {
+            // A preempted thread resumes the rest of its timeslice once the
+            // higher priority work is done.
+            RescheduleReason::Preempted => {
+                run_queue.push_front(thread);
+            }
+            RescheduleReason::Ticked => {
+                if thread.algorithm_state.consume_tick() {
+                    thread.algorithm_state.refill();
+                    run_queue.push_back(thread);
+                } else {
+                    run_queue.push_front(thread);
+                }
+            }
+            RescheduleReason::Started | RescheduleReason::Woken | RescheduleReason::Yielded => {
+                thread.algorithm_state.refill();
+                run_queue.push_back(thread);
+            }
+        }
+    }
+}
+
+impl<K: Kernel> SchedulerAlgorithm<K> for PriorityScheduler<K> {
+    const NEW: Self = Self::new();
+
+    fn schedule_thread(
+        &mut self,
+        _kernel: K,
+        thread: ForeignBox<Thread<K>>,
+        reason: RescheduleReason,
+    ) {
+        self.enqueue(thread, reason);
+    }
+
+    fn get_next_thread(&mut self) -> Option<ForeignBox<Thread<K>>> {
+        let priority = self.ready_bitmask.get_highest_priority()?;
+
+        let run_queue = &mut self.run_queues[priority as usize];
+        let thread = run_queue.pop_head();
+        if run_queue.is_empty() {
+            self.ready_bitmask.clear_priority(priority);
+        }
+        thread
+    }
+
+    fn has_ready_threads(&self) -> bool {
+        self.ready_bitmask.get_highest_priority().is_some()
+    }
+
+    // Moves a ready thread to the back of the run queue for its new priority.
+    unsafe fn set_effective_priority(
+        &mut self,
+        mut thread: NonNull<Thread<K>>,
+        priority: Priority,
+    ) {
+        let thread_ref = unsafe { thread.as_mut() };
+        let old_priority = thread_ref.algorithm_state.effective_priority;
+        if old_priority == priority {
+            return;
+        }
+
+        // Threads which are not in a run queue only need their priority
+        // updated.
+        if thread_ref.state != State::Ready || !matches!(thread_ref.owner, ThreadOwner::Scheduler) {
+            thread_ref.algorithm_state.effective_priority = priority;
+            return;
+        }
+
+        let run_queue = &mut self.run_queues[old_priority as usize];
+        // SAFETY: A ready thread owned by the scheduler is always in the run
+        // queue for its effective priority.
+        let Some(mut thread) = (unsafe { run_queue.remove_element(thread) }) else {
+            pw_assert::panic!("Ready thread not in run queue");
+        };
+        if run_queue.is_empty() {
+            self.ready_bitmask.clear_priority(old_priority);
+        }
+
+        thread.algorithm_state.effective_priority = priority;
+        self.enqueue(thread, RescheduleReason::Woken);
+    }
+}
@@ -0,0 +1,144 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+//! Layout of the kernel's process and thread lists for debug tooling.
+//!
+//! [`annotate_threads!`](crate::annotate_threads) places a
+//! [`ThreadsAnnotation`] in the `.pw_kernel.annotations.threads` section of
+//! the kernel image.  Like the stack annotations, the section is never loaded
+//! into memory.  Tools such as `k threads` use it to walk the process and
+//! thread lists of a running target over a debug connection without needing
+//! the kernel's debug info.
+
+use core::mem::offset_of;
+
+use list::{Adapter, Link, UnsafeList};
+
+use crate::scheduler::SchedulerState;
+use crate::scheduler::algorithm::SchedulerAlgorithmThreadState;
+use crate::scheduler::thread::{
+    self, Process, ProcessListAdapter, ProcessThreadListAdapter, State, Thread, ThreadState,
+};
+use crate::sync::spinlock::SpinLock;
+use crate::{Kernel, KernelState};
+
+/// Layout of the kernel's process and thread lists.
+///
+/// Every field is pointer sized.  Offsets are in bytes and a value of
+/// `usize::MAX` marks information which is not available.
+#[repr(C, packed(1))]
+pub struct ThreadsAnnotation {
+    /// Address of the kernel's [`KernelState`].
+    kernel_state: *const u8,
+
+    /// Offset of the process list's head link within the kernel state.
+    process_list_head: usize,
+
+    /// Offset of the next pointer within a list link.
+    link_next: usize,
+
+    /// Offset of the process list link within a process.
+    process_link: usize,
+    /// Offset of the name, a `&str`, within a process.
+    process_name: usize,
+    /// Offset of the thread list's head link within a process.
+    process_thread_list_head: usize,
+
+    /// Offset of the process' thread list link within a thread.
+    thread_link: usize,
+    /// Offset of the name, a `&str`, within a thread.
+    thread_name: usize,
+    /// Offset of the `u8` [`State`](crate::scheduler::thread::State) within a
+    /// thread.
+    thread_state: usize,
+    /// Offset of the `u8` effective priority within a thread.
+    thread_priority: usize,
+    /// Offset of the architecture specific state within a thread.  Its
+    /// address is also the thread's id.
+    thread_arch_state: usize,
+
+    /// Offset of the saved register frame pointer within the architecture
+    /// specific state.
+    saved_frame: usize,
+    /// Offset of the saved program counter from the saved register frame
+    /// pointer.
+    saved_pc: usize,
+
+    /// Address of the names of the thread states, an array of `&str` indexed
+    /// by the state's discriminant.
+    state_names: *const &'static str,
+    /// Number of thread states.
+    state_count: usize,
+    /// Discriminant of the running state, whose saved registers are stale.
+    running_state: usize,
+}
+
+// Names of the thread states, indexed by their discriminant.
+const STATE_NAMES: [&str; State::ALL.len()] = {
+    let mut names = [""; State::ALL.len()];
+    let mut i = 0;
+    while i < names.len() {
+        names[i] = thread::to_string(State::ALL[i]);
+        i += 1;
+    }
+    names
+};
+
+impl ThreadsAnnotation {
+    #[must_use]
+    pub const fn new<K: Kernel>(kernel_state: *const KernelState<K>) -> Self {
+        let saved_frame = match <K::ThreadState as ThreadState>::SAVED_FRAME_OFFSET {
+            Some(offset) => offset,
+            None => usize::MAX,
+        };
+        let state_names: &'static [&'static str; State::ALL.len()] = &STATE_NAMES;
+        Self {
+            kernel_state: kernel_state.cast(),
+            process_list_head: offset_of!(KernelState<K>, scheduler)
+                + SpinLock::<K, SchedulerState<K>>::DATA_OFFSET
+                + offset_of!(SchedulerState<K>, process_list)
+                + UnsafeList::<Process<K>, ProcessListAdapter<K>>::HEAD_OFFSET,
+            link_next: Link::NEXT_OFFSET,
+            process_link: <ProcessListAdapter<K> as Adapter<Process<K>>>::LINK_OFFSET,
+            process_name: offset_of!(Process<K>, name),
+            process_thread_list_head: offset_of!(Process<K>, thread_list)
+                + UnsafeList::<Thread<K>, ProcessThreadListAdapter<K>>::HEAD_OFFSET,
+            thread_link: <ProcessThreadListAdapter<K> as Adapter<Thread<K>>>::LINK_OFFSET,
+            thread_name: offset_of!(Thread<K>, name),
+            thread_state: offset_of!(Thread<K>, state),
+            thread_priority: offset_of!(Thread<K>, algorithm_state)
+                + SchedulerAlgorithmThreadState::<K>::EFFECTIVE_PRIORITY_OFFSET,
+            thread_arch_state: offset_of!(Thread<K>, arch_thread_state),
+            saved_frame,
+            saved_pc: <K::ThreadState as ThreadState>::SAVED_PC_OFFSET,
+            state_names: state_names.as_ptr(),
+            state_count: state_names.len(),
+            running_state: State::Running as usize,
+        }
+    }
+}
+
+/// Emits a [`ThreadsAnnotation`] for the kernel state at `$state`.
+///
+/// Invoked once by each architecture alongside its static
+/// [`KernelState`](crate::KernelState).
+#[macro_export]
+macro_rules! annotate_threads {
+    ($kernel:ty, $state:expr) => {{
+        #[unsafe(link_section = ".pw_kernel.annotations.threads")]
+        #[used]
+        static mut _THREADS_ANNOTATION: $crate::scheduler::annotations::ThreadsAnnotation =
+            $crate::scheduler::annotations::ThreadsAnnotation::new::<$kernel>($state);
+    }};
+}
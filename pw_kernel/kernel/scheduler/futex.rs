@@ -0,0 +1,157 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+//! Futex style waiting on user space addresses.
+//!
+//! A futex is a 32-bit word in a process' memory.  Threads block on the
+//! address of the word with [`futex_wait()`] and are woken with
+//! [`futex_wake()`].  The kernel keeps no per-futex state: each process has a
+//! single wait queue and waiting threads are tagged with the address they are
+//! waiting on.
+
+use core::cell::UnsafeCell;
+
+use memory_config::MemoryRegionType;
+use pw_status::{Error, Result};
+use time::Instant;
+
+use crate::Kernel;
+use crate::scheduler::thread::Process;
+use crate::scheduler::{SchedLockGuard, SchedulerState, WaitQueue, WaitType, WakeResult};
+use crate::sync::spinlock::SpinLockGuard;
+
+const FUTEX_DEBUG: bool = false;
+macro_rules! futex_debug {
+  ($($args:expr),*) => {{
+    log_if::debug_if!(FUTEX_DEBUG, $($args),*)
+  }}
+}
+
+/// Per process queue of threads blocked in [`futex_wait()`].
+pub struct FutexQueue<K: Kernel> {
+    // Safety: Only accessed with the scheduler lock held.
+    queue: UnsafeCell<WaitQueue<K>>,
+}
+
+unsafe impl<K: Kernel> Sync for FutexQueue<K> {}
+unsafe impl<K: Kernel> Send for FutexQueue<K> {}
+
+impl<K: Kernel> FutexQueue<K> {
+    #[allow(clippy::new_without_default)]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            queue: UnsafeCell::new(WaitQueue::new()),
+        }
+    }
+}
+
+/// Locks the futex queue of the current thread's process and returns it along
+/// with a pointer to the futex word at `addr`.
+fn lock_futex_queue<'lock, K: Kernel>(
+    kernel: K,
+    sched: SpinLockGuard<'lock, K, SchedulerState<K>>,
+    addr: usize,
+) -> Result<(SchedLockGuard<'lock, K, WaitQueue<K>>, *const u32)> {
+    if addr % align_of::<u32>() != 0 {
+        return Err(Error::InvalidArgument);
+    }
+    let Some(end) = addr.checked_add(size_of::<u32>()) else {
+        return Err(Error::InvalidArgument);
+    };
+
+    let process: *const Process<K> = sched.current_thread().process();
+
+    // Safety: Processes are statically allocated and the scheduler lock is
+    // held.
+    let process = unsafe { &*process };
+    if !process.range_has_access(MemoryRegionType::ReadWriteData, addr..end) {
+        return Err(Error::PermissionDenied);
+    }
+
+    // Safety: The futex queue is only accessed through this function, which
+    // is always called with the scheduler lock held.
+    let queue = unsafe {
+        SchedLockGuard::from_sched_guard(sched, &mut *process.futex_queue.queue.get(), kernel)
+    };
+    Ok((queue, core::ptr::with_exposed_provenance(addr)))
+}
+
+/// Blocks the current thread on the futex at `addr`.
+///
+/// The value of the futex is compared to `expected` with the scheduler lock
+/// held.  If they differ, [`Error::Unavailable`] is returned immediately.
+/// Otherwise the thread blocks until it is woken by [`futex_wake()`] or
+/// `deadline` is reached.
+///
+/// Since the comparison and the enqueueing of the thread happen atomically
+/// with respect to [`futex_wake()`], a wake issued after the futex value has
+/// been changed is never lost.
+pub fn futex_wait<K: Kernel>(
+    kernel: K,
+    addr: usize,
+    expected: u32,
+    deadline: Instant<K::Clock>,
+) -> Result<()> {
+    let sched = kernel.get_scheduler().lock(kernel);
+    let (mut queue, futex) = lock_futex_queue(kernel, sched, addr)?;
+
+    // Safety: `lock_futex_queue` verified that the process has read/write
+    // access to the aligned word at `addr`.
+    let value = unsafe { futex.read_volatile() };
+    if value != expected {
+        futex_debug!(
+            "Futex {:#010x}: value {:#010x} != expected {:#010x}",
+            addr as usize,
+            value as u32,
+            expected as u32
+        );
+        return Err(Error::Unavailable);
+    }
+
+    futex_debug!(
+        "Futex {:#010x}: wait by thread '{}' ({:#010x})",
+        addr as usize,
+        queue.sched().current_thread_name() as &str,
+        queue.sched().current_thread_id() as usize
+    );
+    queue.sched_mut().current_thread_mut().futex_addr = addr;
+    let (_, ret) = queue.wait_until(WaitType::Interruptible, deadline);
+    ret
+}
+
+/// Wakes up to `count` threads blocked on the futex at `addr`.
+///
+/// Returns the number of threads woken.
+pub fn futex_wake<K: Kernel>(kernel: K, addr: usize, count: usize) -> Result<usize> {
+    let sched = kernel.get_scheduler().lock(kernel);
+    let (mut queue, _) = lock_futex_queue(kernel, sched, addr)?;
+
+    let mut woken = 0;
+    while woken < count {
+        let result;
+        (queue, result) = queue.wake_one_matching(|thread| thread.futex_addr == addr);
+        if result == WakeResult::QueueEmpty {
+            break;
+        }
+        woken += 1;
+    }
+
+    futex_debug!(
+        "Futex {:#010x}: woke {} threads",
+        addr as usize,
+        woken as usize
+    );
+    Ok(woken)
+}
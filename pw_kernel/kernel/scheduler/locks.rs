@@ -50,6 +50,23 @@ pub struct SchedLockGuard<'lock, K: Kernel, T> {
 }
 
 impl<'lock, K: Kernel, T> SchedLockGuard<'lock, K, T> {
+    /// Wraps an already held scheduler lock into a guard for `inner`.
+    ///
+    /// # Safety
+    /// The caller must guarantee that `inner` is only ever accessed with the
+    /// scheduler lock held.
+    pub(super) unsafe fn from_sched_guard(
+        guard: SpinLockGuard<'lock, K, SchedulerState<K>>,
+        inner: &'lock mut T,
+        kernel: K,
+    ) -> Self {
+        Self {
+            guard,
+            inner,
+            kernel,
+        }
+    }
+
     #[must_use]
     pub fn sched(&self) -> &SpinLockGuard<'lock, K, SchedulerState<K>> {
         &self.guard
@@ -0,0 +1,198 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+//! Priority inheritance through owned wait queues.
+//!
+//! A [`WaitQueue`] may have an owner: the thread holding the resource its
+//! waiters are waiting on, such as a locked [`Mutex`](crate::sync::mutex::Mutex).
+//! The owner's effective priority is raised to that of its highest priority
+//! waiter so that a lower priority owner can not be preempted indefinitely by
+//! medium priority threads while a higher priority thread waits on it.
+//!
+//! Inheritance is transitive: when an owner is itself waiting in an owned wait
+//! queue, its new effective priority is propagated to that queue's owner and
+//! so on along the chain.
+
+use core::ptr::NonNull;
+
+use crate::Kernel;
+use crate::scheduler::algorithm::{RescheduleReason, SchedulerAlgorithm};
+use crate::scheduler::thread::{Thread, ThreadOwner};
+use crate::scheduler::{Priority, SchedLockGuard, WaitQueue, WakeResult};
+
+// Returns the priority `thread` inherits from its base priority and the
+// waiters of the wait queues it owns.
+//
+// Safety: The caller must hold the scheduler lock.
+unsafe fn inherited_priority<K: Kernel>(thread: &Thread<K>) -> Priority {
+    let mut priority = thread.algorithm_state.base_priority();
+    // SAFETY: Owned queues and their waiters are only accessed with the
+    // scheduler lock held.
+    let _ = unsafe {
+        thread
+            .owned_queues
+            .for_each(|queue| -> core::result::Result<(), ()> {
+                let _ = queue
+                    .queue
+                    .for_each(|waiter| -> core::result::Result<(), ()> {
+                        priority = priority.max(waiter.algorithm_state.effective_priority());
+                        Ok(())
+                    });
+                Ok(())
+            })
+    };
+    priority
+}
+
+/// Recomputes the effective priority of `thread` and propagates any change
+/// along the chain of wait queue owners.
+///
+/// # Safety
+/// The caller must hold the scheduler lock.
+pub(super) unsafe fn update_inherited_priority<K: Kernel>(
+    algorithm: &mut K::SchedulerAlgorithm,
+    mut thread: NonNull<Thread<K>>,
+) {
+    loop {
+        // SAFETY: Threads are only accessed with the scheduler lock held.
+        let priority = unsafe { inherited_priority(thread.as_ref()) };
+        if priority
+            == unsafe { thread.as_ref() }
+                .algorithm_state
+                .effective_priority()
+        {
+            // Propagation stops once a priority is unchanged.  This also
+            // bounds the walk if the chain of owners is a (deadlocked) cycle.
+            return;
+        }
+        // SAFETY: The scheduler lock is held.
+        unsafe { algorithm.set_effective_priority(thread, priority) };
+
+        let ThreadOwner::WaitQueue { queue, .. } = unsafe { thread.as_ref() }.owner else {
+            return;
+        };
+        // SAFETY: A wait queue outlives the threads waiting in it.
+        let Some(owner) = unsafe { queue.as_ref() }.owner else {
+            return;
+        };
+        thread = owner;
+    }
+}
+
+impl<K: Kernel> SchedLockGuard<'_, K, WaitQueue<K>> {
+    /// Makes the current thread the owner of the wait queue.
+    ///
+    /// The current thread inherits the priority of the queue's waiters until
+    /// ownership is cleared or transferred.
+    pub fn set_owner_to_current(&mut self) {
+        pw_assert::assert!(self.owner.is_none());
+        let owner = NonNull::from_mut(self.sched_mut().current_thread_mut());
+        let queue = NonNull::from_mut(&mut **self);
+        self.owner = Some(owner);
+        // SAFETY: The scheduler lock is held and the queue was not owned.
+        unsafe {
+            (*owner.as_ptr()).owned_queues.push_back_unchecked(queue);
+            update_inherited_priority(&mut self.sched_mut().algorithm, owner);
+        }
+    }
+
+    /// Clears the wait queue's owner, dropping any priority it inherited
+    /// from the queue's waiters.
+    ///
+    /// If the owner is the current thread and its priority drops, a
+    /// higher priority thread which is ready to run is switched to.
+    #[must_use]
+    pub fn clear_owner(mut self) -> Self {
+        let Some(owner) = self.owner else {
+            return self;
+        };
+        let owner_is_current = core::ptr::eq(owner.as_ptr(), self.sched().current_thread());
+        // SAFETY: Threads are only accessed with the scheduler lock held.
+        let previous_priority = unsafe { owner.as_ref() }
+            .algorithm_state
+            .effective_priority();
+
+        self.unset_owner();
+
+        let priority = unsafe { owner.as_ref() }
+            .algorithm_state
+            .effective_priority();
+        if owner_is_current && priority < previous_priority {
+            self.try_reschedule(RescheduleReason::Preempted)
+        } else {
+            self
+        }
+    }
+
+    fn unset_owner(&mut self) {
+        let Some(owner) = self.owner.take() else {
+            return;
+        };
+        let queue = NonNull::from_mut(&mut **self);
+        // SAFETY: The scheduler lock is held and an owned queue is always in
+        // its owner's list of owned queues.
+        unsafe {
+            (*owner.as_ptr())
+                .owned_queues
+                .unlink_element_unchecked(queue);
+            update_inherited_priority(&mut self.sched_mut().algorithm, owner);
+        }
+    }
+
+    /// Wakes the highest priority waiter and transfers ownership of the
+    /// queue to it.
+    ///
+    /// Ownership is handed over before the woken thread runs so that it
+    /// immediately inherits the priority of the remaining waiters.  If there
+    /// are no waiters, the queue is left without an owner.
+    #[allow(clippy::must_use_candidate)]
+    pub fn wake_one_and_transfer_ownership(mut self) -> (Self, WakeResult) {
+        self.unset_owner();
+
+        let mut highest: Option<(NonNull<Thread<K>>, Priority)> = None;
+        let _ = self
+            .queue
+            .for_each(|thread| -> core::result::Result<(), ()> {
+                let priority = thread.algorithm_state.effective_priority();
+                // Waiters of equal priority are woken in FIFO order.
+                if highest.is_none_or(|(_, highest_priority)| priority > highest_priority) {
+                    highest = Some((NonNull::from_ref(thread), priority));
+                }
+                Ok(())
+            });
+        let Some((next, _)) = highest else {
+            return (self, WakeResult::QueueEmpty);
+        };
+
+        // SAFETY: `next` was found in the queue above and the queue can not
+        // have changed since the scheduler lock is still held.
+        let Some(thread) = (unsafe { self.queue.remove_element(next) }) else {
+            pw_assert::panic!("Thread no longer in wait queue");
+        };
+
+        let queue = NonNull::from_mut(&mut **self);
+        self.owner = Some(next);
+        // SAFETY: The scheduler lock is held.  `next` has been removed from
+        // the queue so it only inherits from the remaining waiters.
+        unsafe {
+            (*next.as_ptr()).owned_queues.push_back_unchecked(queue);
+            let priority = inherited_priority(next.as_ref());
+            self.sched_mut()
+                .algorithm
+                .set_effective_priority(next, priority);
+        }
+
+        self.wake_thread(thread)
+    }
+}
@@ -0,0 +1,319 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+//! Lifecycle control of user space processes.
+//!
+//! A process is terminated by requesting each of its threads to terminate.
+//! Once its last thread has exited, the process' objects are closed, it enters
+//! [`ProcessState::Exited`] and its exit observer, if any, is notified.  An
+//! exited process can then be restarted from its entry point without
+//! resetting the rest of the system.
+//!
+//! A fault taken by a user space thread terminates that thread's process in
+//! the same way, recording a [`FaultReport`] for its supervisor instead of
+//! halting the system.
+
+use core::ptr::NonNull;
+
+use foreign_box::ForeignRc;
+use list::ForeignList;
+use pw_status::{Error, Result};
+
+use crate::Kernel;
+use crate::object::ProcessObject;
+use crate::scheduler::thread::{Process, ProcessState, State, Thread, ThreadListAdapter};
+use crate::scheduler::{SchedulerState, start_thread};
+use crate::sync::spinlock::SpinLockGuard;
+
+const PROCESS_MANAGER_DEBUG: bool = false;
+macro_rules! process_manager_debug {
+  ($($args:expr),*) => {{
+    log_if::debug_if!(PROCESS_MANAGER_DEBUG, $($args),*)
+  }}
+}
+
+/// Description of a fault taken by a user space thread.
+#[derive(Copy, Clone)]
+pub struct FaultReport {
+    /// Address of the faulting instruction, if known.
+    pub pc: Option<usize>,
+
+    /// Architecture specific cause of the fault, or
+    /// [`STACK_OVERFLOW_CAUSE`](super::stack_check::STACK_OVERFLOW_CAUSE) if
+    /// the thread overflowed its kernel stack.
+    pub cause: usize,
+
+    /// Address whose access caused the fault, if known.
+    pub address: Option<usize>,
+
+    /// Name of the faulting thread.
+    pub thread_name: &'static str,
+
+    /// Name of the faulting thread's process.
+    pub process_name: &'static str,
+}
+
+/// Sets the object which is notified when `process` exits or is restarted.
+///
+/// A process has at most one observer.
+///
+/// Returns:
+/// - `Ok(())`: `observer` is now notified when `process` exits.
+/// - `Err(Error::FailedPrecondition)`: `process` already has an observer.
+pub fn set_exit_observer<K: Kernel>(
+    kernel: K,
+    process: NonNull<Process<K>>,
+    observer: ForeignRc<K::AtomicUsize, ProcessObject<K>>,
+) -> Result<()> {
+    let sched_state = kernel.get_scheduler().lock(kernel);
+    // SAFETY: *process is only accessed with the scheduler lock held.
+    let exit_observer = unsafe { &mut (*process.as_ptr()).exit_observer };
+    if exit_observer.is_some() {
+        drop(sched_state);
+        drop(observer);
+        return Err(Error::FailedPrecondition);
+    }
+    *exit_observer = Some(observer);
+    Ok(())
+}
+
+/// Requests every thread of `process` to terminate.
+///
+/// Returns without waiting for the threads to exit.  Threads blocked in an
+/// interruptible wait are woken and all threads exit before returning to
+/// user space.
+///
+/// Returns:
+/// - `Ok(())`: Termination was requested or was already in progress.
+/// - `Err(Error::InvalidArgument)`: `process` is the current thread's process.
+/// - `Err(Error::FailedPrecondition)`: The process has exited or is being
+///   restarted.
+pub fn terminate_process<K: Kernel>(kernel: K, process: NonNull<Process<K>>) -> Result<()> {
+    let mut sched_state = kernel.get_scheduler().lock(kernel);
+    if core::ptr::eq(sched_state.current_thread().process, process.as_ptr()) {
+        return Err(Error::InvalidArgument);
+    }
+
+    // SAFETY: *process is only accessed with the scheduler lock held.
+    let process = unsafe { &mut *process.as_ptr() };
+    match process.state {
+        ProcessState::Running => {}
+        ProcessState::Terminating => return Ok(()),
+        ProcessState::Exited | ProcessState::Restarting => {
+            return Err(Error::FailedPrecondition);
+        }
+    }
+
+    process_manager_debug!("Terminating process '{}'", process.name as &str);
+    terminate_threads(kernel, &mut sched_state, process, Error::Cancelled);
+
+    Ok(())
+}
+
+/// Terminates the current thread's process after the current thread took a
+/// fault in user space.
+///
+/// A [`FaultReport`] is recorded for the process and, unless the process was
+/// already terminating, its exit status is set to `Err(Error::Internal)`.
+/// Every thread of the process, including the current one, is requested to
+/// terminate.
+///
+/// The caller must make sure that the current thread exits instead of
+/// returning to user space, either through
+/// [`exit_if_terminating()`](super::exit_if_terminating) or by returning from
+/// the exception into [`exit_thread()`](super::exit_thread).
+#[cfg(feature = "user_space")]
+pub fn fault_current_process<K: Kernel>(
+    kernel: K,
+    pc: Option<usize>,
+    cause: usize,
+    address: Option<usize>,
+) {
+    let mut sched_state = kernel.get_scheduler().lock(kernel);
+    let thread = sched_state.current_thread_mut();
+    thread.terminating = true;
+    let thread_name = thread.name;
+    // SAFETY: *process is only accessed with the scheduler lock held.
+    let process = unsafe { &mut *thread.process };
+
+    let report = FaultReport {
+        pc,
+        cause,
+        address,
+        thread_name,
+        process_name: process.name,
+    };
+    pw_log::error!(
+        "Fault in thread '{}' of process '{}': pc={:#010x} cause={:#010x} address={:#010x}",
+        report.thread_name as &str,
+        report.process_name as &str,
+        report.pc.unwrap_or(0) as usize,
+        report.cause as usize,
+        report.address.unwrap_or(0) as usize,
+    );
+    process.fault = Some(report);
+
+    if process.state == ProcessState::Running {
+        terminate_threads(kernel, &mut sched_state, process, Error::Internal);
+    }
+}
+
+// Marks `process` as terminating with `status` and requests each of its
+// threads which has not yet exited to terminate.
+fn terminate_threads<K: Kernel>(
+    kernel: K,
+    sched_state: &mut SpinLockGuard<'_, K, SchedulerState<K>>,
+    process: &mut Process<K>,
+    status: Error,
+) {
+    process.state = ProcessState::Terminating;
+    process.exit_status = Err(status);
+
+    // SAFETY: The process' thread list and threads are only accessed with the
+    // scheduler lock held.
+    unsafe {
+        process.thread_list.filter(|thread| {
+            // Threads which have already exited need no further action.  The
+            // running thread is terminated by its caller.
+            if matches!(thread.state, State::Ready | State::Waiting) {
+                let _ = sched_state.thread_terminate(kernel, NonNull::from(thread));
+            }
+            true
+        });
+    }
+}
+
+/// Restarts an exited `process` from its entry point.
+///
+/// Threads the process created from its thread pool are returned to the pool
+/// while its other threads are re-initialized and started again.  The
+/// process' objects are reopened before any of its threads run.
+///
+/// Returns:
+/// - `Ok(())`: The process was restarted.
+/// - `Err(Error::FailedPrecondition)`: The process has not exited or has no
+///   entry point.
+#[cfg(feature = "user_space")]
+pub fn restart_process<K: Kernel>(kernel: K, process: NonNull<Process<K>>) -> Result<()> {
+    let process = process.as_ptr();
+
+    let sched_state = kernel.get_scheduler().lock(kernel);
+    // SAFETY: *process is only accessed with the scheduler lock held.
+    let (initial_pc, initial_sp) = unsafe {
+        if (*process).state != ProcessState::Exited {
+            return Err(Error::FailedPrecondition);
+        }
+        let Some(entry) = (*process).entry else {
+            return Err(Error::FailedPrecondition);
+        };
+        (*process).state = ProcessState::Restarting;
+        entry
+    };
+    drop(sched_state);
+
+    // SAFETY: Processes are statically allocated and thread pools are
+    // protected by their own lock.
+    unsafe { (*process).thread_pool.reclaim(kernel) };
+
+    // Join the process' remaining threads so that they can be started again.
+    let mut threads = ForeignList::<Thread<K>, ThreadListAdapter<K>>::new();
+    loop {
+        let sched_state = kernel.get_scheduler().lock(kernel);
+        let mut first = None;
+        // SAFETY: The process' thread list is only accessed with the scheduler
+        // lock held.
+        let _ = unsafe {
+            (*process)
+                .thread_list
+                .for_each(|thread| -> core::result::Result<(), ()> {
+                    first = Some(thread.get_ref(kernel));
+                    // Stop iteration.
+                    Err(())
+                })
+        };
+        drop(sched_state);
+
+        let Some(thread) = first else {
+            break;
+        };
+        let Ok(thread) = thread.join(kernel) else {
+            pw_assert::panic!("Failed to join exited thread");
+        };
+        threads.push_back(thread);
+    }
+
+    // SAFETY: A process' object table is immutable.
+    unsafe { (*process).reopen_objects(kernel) };
+
+    let sched_state = kernel.get_scheduler().lock(kernel);
+    // SAFETY: *process is only accessed with the scheduler lock held.
+    let observer = unsafe {
+        (*process).state = ProcessState::Running;
+        (*process).exit_status = Ok(());
+        (*process).fault = None;
+        (*process).exit_observer.clone()
+    };
+    drop(sched_state);
+    if let Some(observer) = observer {
+        observer.process_restarted(kernel);
+    }
+
+    process_manager_debug!("Restarting process '{}'", unsafe { (*process).name }
+        as &str);
+    while let Some(mut thread) = threads.pop_head() {
+        let kernel_stack = thread.stack;
+        // SAFETY: `process` is the valid, statically allocated, parent process
+        // of the thread.
+        if let Err(e) = unsafe {
+            thread.initialize_non_priv_thread(
+                kernel,
+                kernel_stack,
+                initial_sp,
+                process,
+                initial_pc,
+                (0, 0, 0),
+            )
+        } {
+            pw_assert::panic!("Error re-initializing thread: {}", e as u32);
+        }
+        start_thread(kernel, thread);
+    }
+
+    Ok(())
+}
+
+/// Returns the status `process` exited with.
+///
+/// Returns:
+/// - `Ok(status)`: `Ok(())` if the process' threads exited on their own,
+///   otherwise the error the process was terminated with.
+/// - `Err(Error::FailedPrecondition)`: The process has not exited.
+pub fn exit_status<K: Kernel>(kernel: K, process: NonNull<Process<K>>) -> Result<Result<()>> {
+    let _sched_state = kernel.get_scheduler().lock(kernel);
+    // SAFETY: *process is only accessed with the scheduler lock held.
+    let process = unsafe { process.as_ref() };
+    if process.state != ProcessState::Exited {
+        return Err(Error::FailedPrecondition);
+    }
+    Ok(process.exit_status)
+}
+
+/// Returns the report of the last fault which terminated `process`, if any.
+///
+/// The report is cleared when the process is restarted.
+pub fn fault_report<K: Kernel>(kernel: K, process: NonNull<Process<K>>) -> Option<FaultReport> {
+    let _sched_state = kernel.get_scheduler().lock(kernel);
+    // SAFETY: *process is only accessed with the scheduler lock held.
+    unsafe { process.as_ref() }.fault
+}
@@ -0,0 +1,292 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+//! Kernel stack usage tracking and overflow detection.
+//!
+//! Every kernel stack is filled with a known pattern when its thread is
+//! initialized.  The most of a stack a thread has used is found by scanning
+//! for the first word, from the bottom of the stack, which no longer holds the
+//! pattern.
+//!
+//! The lowest [`Stack::GUARD_BYTES`](crate::Stack::GUARD_BYTES) of each stack
+//! are checked every time its thread is switched away from.  A thread which
+//! has written to them has overflowed, or is about to overflow, its stack.
+//! Rather than being resumed, it exits through the same path as a thread
+//! which took a fault: its process is terminated with a [`FaultReport`] and
+//! the thread exits once it is next scheduled.
+//!
+//! [`FaultReport`]: crate::scheduler::process_manager::FaultReport
+
+use kernel_config::{KernelConfig, KernelConfigInterface};
+use pw_log::{error, warn};
+use time::{Duration, Instant};
+
+use crate::Kernel;
+use crate::scheduler::SchedulerState;
+use crate::scheduler::thread::{Stack, Thread, ThreadArg as _, ThreadState as _};
+
+/// [`FaultReport::cause`] of a process whose thread overflowed its kernel
+/// stack.  No architecture uses this value as a fault cause.
+///
+/// [`FaultReport::cause`]: crate::scheduler::process_manager::FaultReport::cause
+pub const STACK_OVERFLOW_CAUSE: usize = usize::MAX;
+
+/// Number of threads whose stacks [`check_stack_usage()`] scans at a time.
+const CHECK_BATCH_SIZE: usize = 8;
+
+/// Progress of a thread through the handling of a kernel stack overflow.
+#[derive(Clone, Copy, PartialEq)]
+pub(super) enum StackOverflow {
+    /// No overflow has been detected.
+    None,
+    /// The thread overflowed its stack the last time it ran.
+    Detected,
+    /// The thread has been redirected into the thread exit path.
+    Exiting,
+}
+
+/// Kernel stack usage of a thread.
+#[derive(Clone, Copy)]
+pub struct StackUsage {
+    pub id: usize,
+    pub name: &'static str,
+    pub process_name: &'static str,
+
+    /// Size of the thread's kernel stack in bytes.
+    pub size: usize,
+    /// Most bytes of the stack the thread has used since it was started.
+    pub used: usize,
+
+    stack: Stack,
+}
+
+impl StackUsage {
+    // Usage is filled in by `scan()`, once the scheduler lock is released.
+    fn new<K: Kernel>(thread: &Thread<K>) -> Self {
+        Self {
+            id: thread.id(),
+            name: thread.name,
+            process_name: thread.process().name,
+            size: thread.stack.size(),
+            used: 0,
+            stack: thread.stack,
+        }
+    }
+
+    fn scan(&mut self) {
+        // Stacks are statically allocated, so a thread which has exited since
+        // its stack was snapshotted leaves a stale, but readable, stack.
+        self.used = self.stack.high_water_mark();
+    }
+
+    /// Returns the percentage of the stack used.
+    #[must_use]
+    pub fn percent_used(&self) -> usize {
+        if self.size == 0 {
+            return 0;
+        }
+        self.used * 100 / self.size
+    }
+}
+
+/// Copies the kernel stack usage of every thread in the system into `usage`.
+///
+/// Returns the number of threads in the system.  If this is larger than
+/// `usage`, only the first `usage.len()` threads are copied.  The thread list
+/// is copied with the scheduler lock held while the stacks are scanned
+/// without it.
+pub fn stack_usage<K: Kernel>(kernel: K, usage: &mut [Option<StackUsage>]) -> usize {
+    stack_usage_from(kernel, 0, usage)
+}
+
+// Like `stack_usage()` but skips the first `first` threads.
+fn stack_usage_from<K: Kernel>(kernel: K, first: usize, usage: &mut [Option<StackUsage>]) -> usize {
+    let sched_state = kernel.get_scheduler().lock(kernel);
+    let mut count = 0;
+    // SAFETY: The process and thread lists are only accessed with the
+    // scheduler lock held.
+    unsafe {
+        let _ = sched_state
+            .process_list
+            .for_each(|process| -> core::result::Result<(), ()> {
+                let _ = process
+                    .thread_list
+                    .for_each(|thread| -> core::result::Result<(), ()> {
+                        if let Some(slot) = count.checked_sub(first).and_then(|i| usage.get_mut(i))
+                        {
+                            *slot = Some(StackUsage::new(thread));
+                        }
+                        count += 1;
+                        Ok(())
+                    });
+                Ok(())
+            });
+    }
+    drop(sched_state);
+
+    for usage in usage.iter_mut().flatten() {
+        usage.scan();
+    }
+    count
+}
+
+// Marks the thread `id` as warned about.  Returns `false` if it has already
+// been warned about or no longer exists.
+fn mark_stack_warned<K: Kernel>(kernel: K, id: usize) -> bool {
+    let mut sched_state = kernel.get_scheduler().lock(kernel);
+    let mut marked = false;
+    // SAFETY: The process and thread lists are only accessed with the
+    // scheduler lock held.
+    unsafe {
+        sched_state.process_list.filter(|process| {
+            process.thread_list.filter(|thread| {
+                if thread.id() == id && !thread.stack_warned {
+                    thread.stack_warned = true;
+                    marked = true;
+                }
+                true
+            });
+            true
+        });
+    }
+    marked
+}
+
+/// Logs a warning for each thread whose kernel stack usage has reached
+/// [`KernelConfigInterface::STACK_WARNING_THRESHOLD_PERCENT`].
+///
+/// A thread is only warned about once each time it is started.  Returns the
+/// number of threads warned about.
+///
+/// Stacks are scanned a few threads at a time without holding the scheduler
+/// lock, so threads created or exiting during the check may be missed.
+pub fn check_stack_usage<K: Kernel>(kernel: K) -> usize {
+    let mut warned = 0;
+    let mut first = 0;
+    loop {
+        let mut batch = [None; CHECK_BATCH_SIZE];
+        let count = stack_usage_from(kernel, first, &mut batch);
+        for usage in batch.iter().flatten() {
+            if usage.percent_used() >= KernelConfig::STACK_WARNING_THRESHOLD_PERCENT
+                && mark_stack_warned(kernel, usage.id)
+            {
+                warned += 1;
+                warn!(
+                    "Thread '{}' ({:#010x}) of process '{}' has used {} of {} stack bytes",
+                    usage.name as &str,
+                    usage.id as usize,
+                    usage.process_name as &str,
+                    usage.used as usize,
+                    usage.size as usize
+                );
+            }
+        }
+
+        first += CHECK_BATCH_SIZE;
+        if first >= count {
+            return warned;
+        }
+    }
+}
+
+/// Runs [`check_stack_usage()`] every `interval`.
+///
+/// Meant to be the body of a dedicated, low priority, kernel thread.
+pub fn run<K: Kernel>(kernel: K, interval: Duration<K::Clock>) -> ! {
+    let mut deadline: Instant<K::Clock> = kernel.now();
+    loop {
+        check_stack_usage(kernel);
+        deadline = deadline + interval;
+        let _ = crate::sleep_until(kernel, deadline);
+    }
+}
+
+/// Checks the guard bytes of `thread`, which the scheduler is switching away
+/// from.
+///
+/// A thread's stack can only have grown while it ran, so this is the earliest
+/// point an overflow can be detected at.  The thread still runs on its stack
+/// until the context switch completes, so it is marked rather than terminated
+/// right away; see [`exit_if_overflowed()`].
+pub(super) fn check_outgoing_stack<K: Kernel>(thread: &mut Thread<K>) {
+    if thread.stack_overflow != StackOverflow::None || thread.stack.is_guard_intact() {
+        return;
+    }
+
+    error!(
+        "Stack overflow in thread '{}' ({:#010x}): {} byte stack at {:#010x}",
+        thread.name as &str,
+        thread.id() as usize,
+        thread.stack.size() as usize,
+        thread.stack.start().addr() as usize
+    );
+    thread.stack_overflow = StackOverflow::Detected;
+}
+
+/// Redirects `thread`, which the scheduler is about to switch to, into the
+/// thread exit path if it overflowed its stack.
+///
+/// The thread's saved context can not be trusted, so it is discarded and the
+/// thread starts again at the top of its stack in `exit_overflowed_thread()`.
+pub(super) fn exit_if_overflowed<K: Kernel>(
+    kernel: K,
+    sched_state: &SchedulerState<K>,
+    thread: &mut Thread<K>,
+) {
+    if thread.stack_overflow != StackOverflow::Detected {
+        return;
+    }
+    thread.stack_overflow = StackOverflow::Exiting;
+
+    let process = sched_state.kernel_process.get();
+
+    // SAFETY: The thread is not running so its kernel stack and architecture
+    // state are not in use.  The kernel process' `memory_config` is valid for
+    // the lifetime of the scheduler.
+    unsafe {
+        (*thread.arch_thread_state.get()).initialize_kernel_frame(
+            thread.stack,
+            &raw const (*process).memory_config,
+            exit_overflowed_thread::<K>,
+            (kernel.into_usize(), 0, 0),
+        );
+    }
+}
+
+// Entry point of a thread which overflowed its stack.  Terminates the
+// thread's process, if it is a user space process, and exits the thread.
+extern "C" fn exit_overflowed_thread<K: Kernel>(kernel: usize, _: usize, _: usize) {
+    // SAFETY: `kernel` was produced by `K::into_usize()`.
+    let kernel = unsafe { K::from_usize(kernel) };
+
+    #[cfg(feature = "user_space")]
+    {
+        let sched_state = kernel.get_scheduler().lock(kernel);
+        let thread = sched_state.current_thread();
+        let is_user_thread = !core::ptr::eq(thread.process, sched_state.kernel_process.get());
+        let stack_start = thread.stack.start().addr();
+        drop(sched_state);
+
+        if is_user_thread {
+            super::process_manager::fault_current_process(
+                kernel,
+                None,
+                STACK_OVERFLOW_CAUSE,
+                Some(stack_start),
+            );
+        }
+    }
+
+    super::exit_thread(kernel);
+}
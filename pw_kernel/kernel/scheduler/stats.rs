@@ -0,0 +1,259 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+//! Per-thread CPU time and scheduling statistics.
+
+use pw_log::info;
+use time::{Duration, Instant};
+
+use crate::Kernel;
+use crate::scheduler::Priority;
+use crate::scheduler::thread::{self, State, Thread};
+
+/// Scheduling statistics accumulated for a thread by the scheduler.
+pub struct ThreadStats<K: Kernel> {
+    /// Time the thread has spent running, not including its current run.
+    pub(super) run_time: Duration<K::Clock>,
+
+    /// Number of times the thread has been switched to.
+    pub(super) context_switches: u32,
+
+    /// Number of times the thread has been switched away from while it was
+    /// still ready to run.
+    pub(super) preemptions: u32,
+}
+
+impl<K: Kernel> ThreadStats<K> {
+    #[allow(clippy::new_without_default)]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            run_time: Duration::from_secs(0),
+            context_switches: 0,
+            preemptions: 0,
+        }
+    }
+}
+
+/// A copy of a thread's statistics taken by [`snapshot_threads`].
+#[derive(Clone, Copy)]
+pub struct ThreadSnapshot<K: Kernel> {
+    pub id: usize,
+    pub name: &'static str,
+    pub process_id: usize,
+    pub process_name: &'static str,
+    pub state: State,
+    pub priority: Priority,
+
+    /// Lowest address of the thread's kernel stack.
+    pub stack_start: usize,
+    /// Address one past the highest address of the thread's kernel stack.
+    pub stack_end: usize,
+
+    /// Total time the thread has spent running, including its current run.
+    pub run_time: Duration<K::Clock>,
+    pub context_switches: u32,
+    pub preemptions: u32,
+}
+
+impl<K: Kernel> ThreadSnapshot<K> {
+    fn new(thread: &Thread<K>, now: Instant<K::Clock>, running_since: Instant<K::Clock>) -> Self {
+        let process = thread.process();
+        let mut run_time = thread.stats.run_time;
+        if thread.state == State::Running {
+            run_time = run_time + (now - running_since);
+        }
+        Self {
+            id: thread.id(),
+            name: thread.name,
+            process_id: process.id(),
+            process_name: process.name,
+            state: thread.state,
+            priority: thread.algorithm_state.effective_priority(),
+            stack_start: thread.stack.start().addr(),
+            stack_end: thread.stack.end().addr(),
+            run_time,
+            context_switches: thread.stats.context_switches,
+            preemptions: thread.stats.preemptions,
+        }
+    }
+}
+
+/// Number of threads copied at a time by the functions which log every
+/// thread.  The scheduler lock is released while each batch is logged.
+const LOG_BATCH_SIZE: usize = 8;
+
+/// Copies the statistics of every thread in the system into `snapshots`.
+///
+/// Returns the number of threads in the system.  If this is larger than
+/// `snapshots`, only the first `snapshots.len()` threads are copied.  The
+/// snapshots are taken atomically with respect to the scheduler.
+pub fn snapshot_threads<K: Kernel>(
+    kernel: K,
+    snapshots: &mut [Option<ThreadSnapshot<K>>],
+) -> usize {
+    snapshot_threads_from(kernel, 0, snapshots)
+}
+
+/// Copies the statistics of the threads in the system, skipping the first
+/// `first`, into `snapshots`.
+///
+/// Returns the number of threads in the system.  Threads may be created or
+/// exit between calls, so successive calls with increasing `first` may skip
+/// or repeat threads.
+pub fn snapshot_threads_from<K: Kernel>(
+    kernel: K,
+    first: usize,
+    snapshots: &mut [Option<ThreadSnapshot<K>>],
+) -> usize {
+    let sched_state = kernel.get_scheduler().lock(kernel);
+    let now = kernel.now();
+    let mut count = 0;
+    // SAFETY: The process and thread lists are only accessed with the
+    // scheduler lock held.
+    unsafe {
+        let _ = sched_state
+            .process_list
+            .for_each(|process| -> core::result::Result<(), ()> {
+                let _ = process
+                    .thread_list
+                    .for_each(|thread| -> core::result::Result<(), ()> {
+                        if let Some(slot) = count
+                            .checked_sub(first)
+                            .and_then(|index| snapshots.get_mut(index))
+                        {
+                            *slot =
+                                Some(ThreadSnapshot::new(thread, now, sched_state.running_since));
+                        }
+                        count += 1;
+                        Ok(())
+                    });
+                Ok(())
+            });
+    }
+    count
+}
+
+// Calls `f` with a snapshot of every thread and the system uptime when it was
+// taken, without holding the scheduler lock.
+fn for_each_snapshot<K: Kernel>(
+    kernel: K,
+    mut f: impl FnMut(&ThreadSnapshot<K>, Duration<K::Clock>),
+) {
+    let mut first = 0;
+    loop {
+        let mut batch = [None; LOG_BATCH_SIZE];
+        let count = snapshot_threads_from(kernel, first, &mut batch);
+        let uptime = kernel.now() - Instant::MIN;
+        for snapshot in batch.iter().flatten() {
+            f(snapshot, uptime);
+        }
+        first += LOG_BATCH_SIZE;
+        if first >= count {
+            return;
+        }
+    }
+}
+
+/// Returns `part` as a fraction of `total` in tenths of a percent.
+#[must_use]
+pub fn permille<K: Kernel>(part: Duration<K::Clock>, total: Duration<K::Clock>) -> u64 {
+    let total = total.ticks().cast_unsigned();
+    if total == 0 {
+        return 0;
+    }
+    let part = u128::from(part.ticks().cast_unsigned());
+    #[allow(clippy::cast_possible_truncation)]
+    {
+        (part * 1000 / u128::from(total)) as u64
+    }
+}
+
+/// Logs the state of every process and thread.
+pub fn dump_all_threads<K: Kernel>(kernel: K) {
+    info!("List of all threads:");
+    let mut process_id = None;
+    for_each_snapshot(kernel, |snapshot, _uptime| {
+        if process_id != Some(snapshot.process_id) {
+            process_id = Some(snapshot.process_id);
+            info!(
+                "Process '{}' ({:#010x})",
+                snapshot.process_name as &str, snapshot.process_id as usize
+            );
+        }
+        info!(
+            "  - Thread '{}' ({:#010x}) state: {}",
+            snapshot.name as &str,
+            snapshot.id as usize,
+            thread::to_string(snapshot.state) as &str
+        );
+    });
+}
+
+// Logs the total CPU utilization of a process after its threads.
+fn log_process_cpu_usage<K: Kernel>(run_time: Duration<K::Clock>, uptime: Duration<K::Clock>) {
+    let process_permille = permille::<K>(run_time, uptime);
+    info!(
+        "  = Process cpu {}.{}%",
+        (process_permille / 10) as u64,
+        (process_permille % 10) as u64
+    );
+}
+
+/// Logs the CPU utilization of every process and thread since boot.
+pub fn dump_cpu_usage<K: Kernel>(kernel: K) {
+    info!(
+        "CPU usage over {} ms:",
+        (kernel.now() - Instant::MIN).as_millis().cast_unsigned() as u64
+    );
+
+    // The id and total run time of the process whose threads are being logged.
+    let mut process: Option<(usize, Duration<K::Clock>)> = None;
+    let mut uptime = Duration::from_secs(0);
+    for_each_snapshot(kernel, |snapshot, snapshot_uptime| {
+        uptime = snapshot_uptime;
+        match &mut process {
+            Some((id, run_time)) if *id == snapshot.process_id => {
+                *run_time = *run_time + snapshot.run_time;
+            }
+            _ => {
+                if let Some((_, run_time)) = process {
+                    log_process_cpu_usage::<K>(run_time, uptime);
+                }
+                process = Some((snapshot.process_id, snapshot.run_time));
+                info!(
+                    "Process '{}' ({:#010x})",
+                    snapshot.process_name as &str, snapshot.process_id as usize
+                );
+            }
+        }
+
+        let thread_permille = permille::<K>(snapshot.run_time, uptime);
+        info!(
+            "  - Thread '{}' ({:#010x}) {} priority {} cpu {}.{}% run {} ms switches {} preemptions {}",
+            snapshot.name as &str,
+            snapshot.id as usize,
+            thread::to_string(snapshot.state) as &str,
+            snapshot.priority as usize,
+            (thread_permille / 10) as u64,
+            (thread_permille % 10) as u64,
+            snapshot.run_time.as_millis().cast_unsigned() as u64,
+            snapshot.context_switches as u32,
+            snapshot.preemptions as u32
+        );
+    });
+    if let Some((_, run_time)) = process {
+        log_process_cpu_usage::<K>(run_time, uptime);
+    }
+}
@@ -24,12 +24,20 @@ use memory_config::{MemoryConfig as _, MemoryRegionType};
 use pw_atomic::{AtomicAdd, AtomicSub, AtomicZero};
 use pw_log::info;
 use pw_status::Result;
-use time::Instant;
+use time::{Duration, Instant};
 
 use crate::Kernel;
-use crate::object::{KernelObject, ObjectTable};
+use crate::object::{KernelObject, ObjectTable, ProcessObject};
 use crate::scheduler::algorithm::SchedulerAlgorithmThreadState;
-use crate::scheduler::{JoinResult, Priority, TryJoinResult, WaitQueue, WaitType};
+use crate::scheduler::futex::FutexQueue;
+use crate::scheduler::process_manager::FaultReport;
+use crate::scheduler::stack_check::StackOverflow;
+use crate::scheduler::stats::ThreadStats;
+use crate::scheduler::thread_pool::{ThreadPool, ThreadPoolSlot};
+use crate::scheduler::watchdog::ThreadWatchdog;
+use crate::scheduler::{
+    JoinResult, OwnedWaitQueueListAdapter, Priority, TryJoinResult, WaitQueue, WaitType,
+};
 use crate::sync::event::{Event, EventConfig, EventSignaler};
 
 /// The memory backing a thread's stack before it has been started.
@@ -77,6 +85,10 @@ pub struct Stack {
 
 #[allow(dead_code)]
 impl Stack {
+    /// Number of bytes at the bottom of the stack checked by
+    /// [`Stack::is_guard_intact()`].
+    pub const GUARD_BYTES: usize = 16;
+
     #[must_use]
     pub const fn from_slice(slice: &[MaybeUninit<u8>]) -> Self {
         let start: *const MaybeUninit<u8> = slice.as_ptr();
@@ -115,6 +127,49 @@ impl Stack {
         ptr >= self.start && ptr < self.end
     }
 
+    /// Returns the size of the stack in bytes.
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.end.addr() - self.start.addr()
+    }
+
+    /// Returns the most bytes of the stack used since it was last initialized.
+    ///
+    /// Calculated by counting the words, starting from the lowest address,
+    /// which still contain the pattern written by [`Stack::initialize()`].
+    #[must_use]
+    pub fn high_water_mark(&self) -> usize {
+        self.size() - self.unused_words(self.size() / size_of::<u32>()) * size_of::<u32>()
+    }
+
+    /// Returns `false` if any of the lowest [`Stack::GUARD_BYTES`] of the
+    /// stack have been written to since it was last initialized.
+    ///
+    /// Stacks grow down so a thread which has written to its guard bytes is
+    /// about to, or already has, overflowed its stack.
+    #[must_use]
+    pub fn is_guard_intact(&self) -> bool {
+        let guard_words = Self::GUARD_BYTES / size_of::<u32>();
+        self.size() < Self::GUARD_BYTES || self.unused_words(guard_words) == guard_words
+    }
+
+    // Returns the number of words, up to `max_words`, from the lowest address
+    // of the stack which still contain the unused stack pattern.
+    fn unused_words(&self, max_words: usize) -> usize {
+        let start = self.start.cast::<u32>();
+        let mut count = 0;
+        while count < max_words {
+            // SAFETY: `count` is less than the number of words in the stack
+            // and `initialize()` has checked that `start` is aligned.
+            let val = unsafe { start.add(count).read_volatile() };
+            if val != magic_values::UNUSED_STACK_PATTERN {
+                break;
+            }
+            count += 1;
+        }
+        count
+    }
+
     #[must_use]
     pub fn aligned_stack_allocation_mut<T: Sized>(
         sp: *mut MaybeUninit<u8>,
@@ -162,7 +217,10 @@ impl Stack {
 
 /// Runtime state of a Thread.
 // TODO: want to name this ThreadState, but collides with ArchThreadstate
+//
+// The discriminants are read from target memory by debug tooling.
 #[derive(Copy, Clone, PartialEq)]
+#[repr(u8)]
 pub enum State {
     /// Thread has been created but not initialized.
     New,
@@ -188,8 +246,32 @@ pub enum State {
     Waiting,
 }
 
+impl State {
+    /// Every state, indexed by its discriminant.
+    ///
+    /// Debug tooling reads the state names from the kernel image in this
+    /// order.
+    pub const ALL: [State; 7] = [
+        State::New,
+        State::Initial,
+        State::Ready,
+        State::Running,
+        State::Terminated,
+        State::Joined,
+        State::Waiting,
+    ];
+}
+
+const _: () = {
+    let mut i = 0;
+    while i < State::ALL.len() {
+        assert!(State::ALL[i] as usize == i);
+        i += 1;
+    }
+};
+
 // TODO: use From or Into trait (unclear how to do it with 'static str)
-pub(super) fn to_string(s: State) -> &'static str {
+pub const fn to_string(s: State) -> &'static str {
     match s {
         State::New => "New",
         State::Initial => "Initial",
@@ -204,6 +286,17 @@ pub(super) fn to_string(s: State) -> &'static str {
 pub trait ThreadState: 'static + Sized {
     const NEW: Self;
 
+    /// Offset of the pointer to the thread's saved register frame, or `None`
+    /// if the architecture does not save registers in memory.
+    ///
+    /// Used by debug tooling to find the saved registers of a thread which is
+    /// not running.
+    const SAVED_FRAME_OFFSET: Option<usize> = None;
+
+    /// Offset of the saved program counter from the saved register frame
+    /// pointer.
+    const SAVED_PC_OFFSET: usize = 0;
+
     // TODO: Maybe have a `MemoryConfigContext` super-trait of `ThreadState`?
     type MemoryConfig: memory_config::MemoryConfig;
 
@@ -241,6 +334,34 @@ pub trait ThreadState: 'static + Sized {
         initial_pc: usize,
         args: (usize, usize, usize),
     ) -> Result<()>;
+
+    /// Returns `true` if the thread will resume directly in user space, without
+    /// running any kernel code, when it is next switched to.
+    ///
+    /// Architectures which only ever context switch from kernel code can rely
+    /// on the default implementation.
+    #[cfg(feature = "user_space")]
+    fn resumes_in_user_space(&self) -> bool {
+        false
+    }
+}
+
+/// Lifecycle state of a [`Process`].
+#[derive(Copy, Clone, PartialEq)]
+pub enum ProcessState {
+    /// The process has live threads.
+    Running,
+
+    /// The process has been asked to terminate and is waiting for its threads
+    /// to exit.
+    Terminating,
+
+    /// All of the process' threads have exited and its objects have been
+    /// closed.
+    Exited,
+
+    /// The process is being restarted.
+    Restarting,
 }
 
 pub struct Process<K: Kernel> {
@@ -254,7 +375,32 @@ pub struct Process<K: Kernel> {
 
     object_table: ForeignBox<dyn ObjectTable<K>>,
 
-    thread_list: UnsafeList<Thread<K>, ProcessThreadListAdapter<K>>,
+    pub(super) thread_list: UnsafeList<Thread<K>, ProcessThreadListAdapter<K>>,
+
+    // Number of threads which have been added to the process and have not yet
+    // exited.
+    live_threads: usize,
+
+    // Threads of this process blocked in `futex_wait()`.
+    pub(super) futex_queue: FutexQueue<K>,
+
+    // Threads which user space can create at run time.
+    pub(super) thread_pool: ThreadPool<K>,
+
+    pub(super) state: ProcessState,
+
+    // Status the process exited, or is exiting, with.
+    pub(super) exit_status: Result<()>,
+
+    // Initial program counter and stack pointer the process' threads are
+    // restarted with.
+    pub(super) entry: Option<(usize, usize)>,
+
+    // Object notified when the process exits.
+    pub(super) exit_observer: Option<ForeignRc<K::AtomicUsize, ProcessObject<K>>>,
+
+    // Fault which terminated the process, if any.
+    pub(super) fault: Option<FaultReport>,
 }
 
 list::define_adapter!(pub ProcessListAdapter<K: Kernel> => Process<K>::link);
@@ -273,9 +419,31 @@ impl<K: Kernel> Process<K> {
             memory_config,
             object_table,
             thread_list: UnsafeList::new(),
+            live_threads: 0,
+            futex_queue: FutexQueue::new(),
+            thread_pool: ThreadPool::new(&mut []),
+            state: ProcessState::Running,
+            exit_status: Ok(()),
+            entry: None,
+            exit_observer: None,
+            fault: None,
         }
     }
 
+    /// Sets the initial program counter and stack pointer the process'
+    /// threads are started with when the process is restarted.
+    pub fn set_entry(&mut self, initial_pc: usize, initial_sp: usize) {
+        self.entry = Some((initial_pc, initial_sp));
+    }
+
+    /// Gives the process a pool of threads which user space can create at
+    /// run time.
+    ///
+    /// Must be called before any of the process' threads are started.
+    pub fn set_thread_pool(&mut self, slots: &'static mut [ThreadPoolSlot<K>]) {
+        self.thread_pool = ThreadPool::new(slots);
+    }
+
     pub fn get_object(
         &self,
         kernel: K,
@@ -298,6 +466,45 @@ impl<K: Kernel> Process<K> {
         unsafe {
             self.thread_list.push_front_unchecked(NonNull::from(thread));
         }
+        self.live_threads += 1;
+    }
+
+    /// Records the exit of one of the process' threads.
+    ///
+    /// Returns `true` if it was the last live thread of the process.  The
+    /// process is then marked as exited once its objects have been closed by
+    /// [`Process::objects_closed()`].
+    pub(super) fn thread_exited(&mut self) -> bool {
+        pw_assert::assert!(self.live_threads > 0);
+        self.live_threads -= 1;
+        self.live_threads == 0
+    }
+
+    /// Notifies every object the process holds a handle to that the process
+    /// has exited.
+    ///
+    /// Must be called without the scheduler lock held as objects may need to
+    /// block while cleaning up.
+    pub(super) fn close_objects(&self, kernel: K) {
+        self.object_table
+            .for_each_object(kernel, &mut |object| object.object_close(kernel));
+    }
+
+    /// Reverses [`Process::close_objects()`] before the process is restarted.
+    ///
+    /// Must be called without the scheduler lock held.
+    pub(super) fn reopen_objects(&self, kernel: K) {
+        self.object_table
+            .for_each_object(kernel, &mut |object| object.object_reopen(kernel));
+    }
+
+    /// Marks the process as exited once its objects have been closed.
+    ///
+    /// Returns the object to notify of the exit, which must be done without
+    /// the scheduler lock held.
+    pub(super) fn objects_closed(&mut self) -> Option<ForeignRc<K::AtomicUsize, ProcessObject<K>>> {
+        self.state = ProcessState::Exited;
+        self.exit_observer.clone()
     }
 
     /// # Safety
@@ -431,7 +638,10 @@ impl<K: Kernel> ThreadRef<K> {
     /// To wait for the thread to terminate, call [`ThreadRef::join()`] or
     /// [`ThreadRef::join_until()`].
     pub fn terminate(&mut self, kernel: K) -> Result<()> {
-        kernel.get_scheduler().lock(kernel).thread_terminate(self)
+        kernel
+            .get_scheduler()
+            .lock(kernel)
+            .thread_terminate(kernel, self.thread)
     }
 
     /// Returns the current state of the thread.
@@ -439,6 +649,16 @@ impl<K: Kernel> ThreadRef<K> {
         kernel.get_scheduler().lock(kernel).thread_get_state(self)
     }
 
+    /// Returns the priority the thread is scheduled at, including any
+    /// priority it inherits from threads waiting on it.
+    pub fn get_effective_priority(&self, kernel: K) -> Priority {
+        let _sched_state = kernel.get_scheduler().lock(kernel);
+        // SAFETY: Thread access is guarded by the scheduler lock.
+        unsafe { self.thread.as_ref() }
+            .algorithm_state
+            .effective_priority()
+    }
+
     /// Returns true if the thread is in the terminating state.
     ///
     /// Note: This is a parallel state to the state returned by [`ThreadRef::is_terminating()`].
@@ -510,11 +730,28 @@ pub struct Thread<K: Kernel> {
     pub(super) terminating: bool,
     pub(super) join_event: Option<EventSignaler<K>>,
 
+    // User space address the thread is waiting on while blocked in
+    // `futex_wait()`.
+    pub(super) futex_addr: usize,
+
+    // Wait queues owned by the thread whose waiters it inherits priority from.
+    pub(super) owned_queues: UnsafeList<WaitQueue<K>, OwnedWaitQueueListAdapter<K>>,
+
     // TODO - konkers: allow this to be tokenized.
     pub name: &'static str,
 
     /// The state for the scheduler algorithm.
-    pub algorithm_state: SchedulerAlgorithmThreadState,
+    pub algorithm_state: SchedulerAlgorithmThreadState<K>,
+
+    // CPU time and scheduling statistics, updated by the scheduler.
+    pub(super) stats: ThreadStats<K>,
+
+    pub(super) watchdog: ThreadWatchdog<K>,
+
+    // Set once a stack usage warning has been logged for the thread.
+    pub(super) stack_warned: bool,
+
+    pub(super) stack_overflow: StackOverflow,
 }
 
 list::define_adapter!(pub ThreadListAdapter<K: Kernel> => Thread<K>::active_link);
@@ -535,11 +772,58 @@ impl<K: Kernel> Thread<K> {
             ref_count: K::AtomicUsize::ZERO,
             terminating: false,
             join_event: None,
+            futex_addr: 0,
+            owned_queues: UnsafeList::new(),
             name,
             algorithm_state: SchedulerAlgorithmThreadState::new(priority),
+            stats: ThreadStats::new(),
+            watchdog: ThreadWatchdog::new(),
+            stack_warned: false,
+            stack_overflow: StackOverflow::None,
         }
     }
 
+    /// Sets the number of scheduler ticks the thread runs for before yielding
+    /// to other ready threads of the same priority.
+    ///
+    /// Must be called before the thread is started.
+    pub fn set_timeslice_ticks(&mut self, ticks: u32) {
+        pw_assert::assert!(self.state == State::New || self.state == State::Initial);
+        self.algorithm_state.set_timeslice_ticks(ticks);
+    }
+
+    /// Sets the maximum time allowed between the thread's watchdog check-ins.
+    ///
+    /// See [`watchdog`](crate::scheduler::watchdog).  Must be called before the
+    /// thread is started.
+    pub fn set_watchdog_interval(&mut self, interval: Duration<K::Clock>) {
+        pw_assert::assert!(self.state == State::New || self.state == State::Initial);
+        self.watchdog.interval = Some(interval);
+    }
+
+    /// Sets the time, relative to each time the thread is started or woken,
+    /// by which it should have completed its work.
+    ///
+    /// Only used by deadline based scheduler algorithms such as
+    /// [`EdfScheduler`](crate::scheduler::algorithm::EdfScheduler).  Must be
+    /// called before the thread is started.
+    pub fn set_relative_deadline(&mut self, relative_deadline: Duration<K::Clock>) {
+        pw_assert::assert!(self.state == State::New || self.state == State::Initial);
+        self.algorithm_state
+            .set_relative_deadline(Some(relative_deadline));
+    }
+
+    /// Sets the run time the thread may use each time it is started or woken.
+    ///
+    /// A thread which overruns its budget is scheduled by priority until it
+    /// is next woken.  Only used by deadline based scheduler algorithms
+    /// together with [`Thread::set_relative_deadline`].  Must be called before
+    /// the thread is started.
+    pub fn set_budget(&mut self, budget: Duration<K::Clock>) {
+        pw_assert::assert!(self.state == State::New || self.state == State::Initial);
+        self.algorithm_state.set_budget(Some(budget));
+    }
+
     pub fn get_object(
         &self,
         kernel: K,
@@ -959,6 +1243,72 @@ macro_rules! init_non_priv_thread {
     }};
 }
 
+/// Constructs a pool of `$num_threads` [`Thread`]s and kernel stacks in global
+/// static storage for use with [`Process::set_thread_pool()`].
+///
+/// # Safety
+///
+/// Each invocation of `init_non_priv_thread_pool!` must be executed at most
+/// once at run time.
+#[cfg(feature = "user_space")]
+#[macro_export]
+macro_rules! init_non_priv_thread_pool {
+    ($name:literal, $priority:expr, $num_threads:expr, $kernel_stack_size:expr $(,)?) => {{
+        use core::cell::UnsafeCell;
+        use core::mem::MaybeUninit;
+        use $crate::static_mut_ref;
+        use $crate::__private::foreign_box::ForeignBox;
+        use $crate::scheduler::thread::{Stack, StackStorage, StackStorageExt, Thread};
+        use $crate::scheduler::thread_pool::ThreadPoolSlot;
+
+        /// SAFETY: This must be executed at most once at run time.
+        unsafe fn __init_non_priv_thread_pool() -> &'static mut [ThreadPoolSlot<arch::Arch>] {
+            use pw_log::info;
+            info!(
+                "Allocating non-privileged thread pool '{}' ({} threads)",
+                $name as &'static str,
+                $num_threads as usize
+            );
+            // SAFETY: The caller promises that this function will be executed
+            // at most once.
+            let threads = unsafe {
+                static_mut_ref!([Thread<arch::Arch>; $num_threads] =
+                    [const { Thread::new($name, $priority) }; $num_threads])
+            };
+            static mut __STACKS: UnsafeCell<
+                MaybeUninit<[StackStorage<$kernel_stack_size>; $num_threads]>,
+            > = UnsafeCell::new(MaybeUninit::uninit());
+            $crate::annotate_stack!(
+                $name,
+                unsafe { __STACKS.get() },
+                $kernel_stack_size * $num_threads
+            );
+            // SAFETY: The caller promises that this function will be executed
+            // at most once.
+            let stacks = unsafe {
+                (*__STACKS.get()).write(
+                    [<StackStorage<$kernel_stack_size> as StackStorageExt>::ZEROED; $num_threads],
+                )
+            };
+
+            let mut stacks = stacks.iter();
+            let slots = threads.each_mut().map(|thread| {
+                let Some(stack) = stacks.next() else {
+                    $crate::macro_exports::pw_assert::panic!("Thread pool stack missing");
+                };
+                ThreadPoolSlot::new(ForeignBox::from(thread), Stack::from_slice(&stack.stack))
+            });
+            // SAFETY: The caller promises that this function will be executed
+            // at most once.
+            unsafe {
+                static_mut_ref!([ThreadPoolSlot<arch::Arch>; $num_threads] = slots)
+            }
+        }
+
+        __init_non_priv_thread_pool()
+    }};
+}
+
 /// Initializes a thread in the given storage.
 pub fn init_thread_in<K: Kernel, T: ThreadArg, const STACK_SIZE: usize>(
     kernel: K,
@@ -0,0 +1,255 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+//! Per process pools of threads which are created at run time.
+//!
+//! Threads declared in a process' system config are created before the
+//! scheduler starts.  Processes which need a number of threads only known at
+//! run time are instead given a pool of pre-allocated [`Thread`]s and kernel
+//! stacks.  User space starts a pool thread on a stack it provides with
+//! [`thread_create()`] and returns it to the pool with [`thread_join()`].
+
+use foreign_box::ForeignBox;
+use memory_config::MemoryRegionType;
+use pw_status::{Error, Result};
+use time::Instant;
+
+use crate::Kernel;
+use crate::scheduler::thread::{Process, Stack, Thread, ThreadRef};
+use crate::scheduler::{JoinResult, start_thread};
+use crate::sync::spinlock::SpinLock;
+
+const THREAD_POOL_DEBUG: bool = false;
+macro_rules! thread_pool_debug {
+  ($($args:expr),*) => {{
+    log_if::debug_if!(THREAD_POOL_DEBUG, $($args),*)
+  }}
+}
+
+enum SlotState<K: Kernel> {
+    /// The slot's thread is not running and may be created.
+    Free(ForeignBox<Thread<K>>),
+
+    /// The slot's thread has been started and not yet joined.
+    Started(ThreadRef<K>),
+
+    /// The slot's thread is in the process of being created or joined.
+    Busy,
+}
+
+/// A pre-allocated thread and kernel stack in a [`ThreadPool`].
+pub struct ThreadPoolSlot<K: Kernel> {
+    state: SlotState<K>,
+    kernel_stack: Stack,
+}
+
+impl<K: Kernel> ThreadPoolSlot<K> {
+    #[must_use]
+    pub fn new(thread: ForeignBox<Thread<K>>, kernel_stack: Stack) -> Self {
+        Self {
+            state: SlotState::Free(thread),
+            kernel_stack,
+        }
+    }
+}
+
+struct ThreadPoolSlots<K: Kernel>(&'static mut [ThreadPoolSlot<K>]);
+
+// Safety: Slots are only accessed with the pool's lock held.
+unsafe impl<K: Kernel> Send for ThreadPoolSlots<K> {}
+
+/// A process' pool of threads which user space can create at run time.
+///
+/// A thread's index in the pool is used as its handle.
+pub struct ThreadPool<K: Kernel> {
+    slots: SpinLock<K, ThreadPoolSlots<K>>,
+}
+
+impl<K: Kernel> ThreadPool<K> {
+    #[must_use]
+    pub const fn new(slots: &'static mut [ThreadPoolSlot<K>]) -> Self {
+        Self {
+            slots: SpinLock::new(ThreadPoolSlots(slots)),
+        }
+    }
+
+    /// Marks the first free slot busy and returns its index, thread and
+    /// kernel stack.
+    fn take_free(&self, kernel: K) -> Result<(usize, ForeignBox<Thread<K>>, Stack)> {
+        let mut slots = self.slots.lock(kernel);
+        let Some((index, slot)) = slots
+            .0
+            .iter_mut()
+            .enumerate()
+            .find(|(_, slot)| matches!(slot.state, SlotState::Free(_)))
+        else {
+            return Err(Error::ResourceExhausted);
+        };
+        let SlotState::Free(thread) = core::mem::replace(&mut slot.state, SlotState::Busy) else {
+            pw_assert::panic!("Thread pool slot not free");
+        };
+        Ok((index, thread, slot.kernel_stack))
+    }
+
+    /// Marks the started slot at `index` busy and returns its thread.
+    fn take_started(&self, kernel: K, index: usize) -> Result<ThreadRef<K>> {
+        let mut slots = self.slots.lock(kernel);
+        let Some(slot) = slots.0.get_mut(index) else {
+            return Err(Error::OutOfRange);
+        };
+        match core::mem::replace(&mut slot.state, SlotState::Busy) {
+            SlotState::Started(thread) => Ok(thread),
+            SlotState::Free(thread) => {
+                slot.state = SlotState::Free(thread);
+                Err(Error::NotFound)
+            }
+            // Only one join may wait on a thread at a time.
+            SlotState::Busy => Err(Error::AlreadyExists),
+        }
+    }
+
+    /// Joins every started thread and returns it to the pool.
+    ///
+    /// Only called once all of the process' threads have exited.
+    pub(super) fn reclaim(&self, kernel: K) {
+        let num_slots = self.slots.lock(kernel).0.len();
+        for index in 0..num_slots {
+            let Ok(thread) = self.take_started(kernel, index) else {
+                continue;
+            };
+            let Ok(thread) = thread.join(kernel) else {
+                pw_assert::panic!("Failed to join exited pool thread");
+            };
+            self.put(kernel, index, SlotState::Free(thread));
+        }
+    }
+
+    /// Returns the busy slot at `index` to `state`.
+    fn put(&self, kernel: K, index: usize, state: SlotState<K>) {
+        let mut slots = self.slots.lock(kernel);
+        let slot = &mut slots.0[index];
+        pw_assert::assert!(matches!(slot.state, SlotState::Busy));
+        slot.state = state;
+    }
+}
+
+/// Returns the process of the current thread.
+fn current_process<K: Kernel>(kernel: K) -> *mut Process<K> {
+    kernel.get_scheduler().lock(kernel).current_thread().process
+}
+
+/// Creates and starts a thread from the current process' thread pool.
+///
+/// The thread starts executing in user space at `entry` with `args` in its
+/// first argument registers and its stack pointer at the end of the
+/// `stack_start..stack_start + stack_size` region, which must be read/write
+/// data memory of the process.
+///
+/// Returns the handle of the new thread.
+#[cfg(feature = "user_space")]
+pub fn thread_create<K: Kernel>(
+    kernel: K,
+    entry: usize,
+    stack_start: usize,
+    stack_size: usize,
+    args: (usize, usize),
+) -> Result<u32> {
+    let Some(stack_end) = stack_start.checked_add(stack_size) else {
+        return Err(Error::InvalidArgument);
+    };
+    let Some(entry_end) = entry.checked_add(1) else {
+        return Err(Error::InvalidArgument);
+    };
+
+    let process = current_process(kernel);
+    // Safety: Processes are statically allocated and their memory configs and
+    // thread pools are never modified once they have threads.
+    let process_ref = unsafe { &*process };
+    if !process_ref.range_has_access(MemoryRegionType::ReadWriteData, stack_start..stack_end)
+        || !process_ref.range_has_access(MemoryRegionType::ReadOnlyExecutable, entry..entry_end)
+    {
+        return Err(Error::PermissionDenied);
+    }
+
+    let pool = &process_ref.thread_pool;
+    let (index, mut thread, kernel_stack) = pool.take_free(kernel)?;
+    let Ok(handle) = u32::try_from(index) else {
+        pool.put(kernel, index, SlotState::Free(thread));
+        return Err(Error::OutOfRange);
+    };
+
+    // Safety: `process` is the valid, statically allocated, parent process of
+    // the calling thread.
+    if let Err(e) = unsafe {
+        thread.initialize_non_priv_thread(
+            kernel,
+            kernel_stack,
+            stack_end,
+            process,
+            entry,
+            (args.0, args.1, 0),
+        )
+    } {
+        pool.put(kernel, index, SlotState::Free(thread));
+        return Err(e);
+    }
+
+    thread_pool_debug!(
+        "Process '{}': creating pool thread {} (entry: {:#010x})",
+        process_ref.name as &str,
+        handle as u32,
+        entry as usize
+    );
+    let thread_ref = start_thread(kernel, thread);
+    pool.put(kernel, index, SlotState::Started(thread_ref));
+    Ok(handle)
+}
+
+/// Waits for the pool thread `handle` of the current process to exit and
+/// returns it to the pool.
+///
+/// Returns:
+/// - `Ok(())`: The thread exited and its slot may be reused.
+/// - `Err(Error::DeadlineExceeded)`: The thread did not exit before
+///   `deadline`.  It remains joinable.
+/// - `Err(Error::OutOfRange)`: `handle` is past the end of the pool.
+/// - `Err(Error::NotFound)`: `handle` does not refer to a started thread.
+/// - `Err(Error::AlreadyExists)`: Another thread is joining `handle`.
+/// - `Err(Error::InvalidArgument)`: `handle` refers to the calling thread.
+pub fn thread_join<K: Kernel>(kernel: K, handle: u32, deadline: Instant<K::Clock>) -> Result<()> {
+    // Safety: Processes are statically allocated.
+    let pool = unsafe { &(*current_process(kernel)).thread_pool };
+    let index = usize::try_from(handle).map_err(|_| Error::OutOfRange)?;
+    let thread = pool.take_started(kernel, index)?;
+
+    // Safety: The thread's id is derived from its address and does not
+    // access any of its state.
+    let thread_id = unsafe { thread.thread.as_ref() }.id();
+    if thread_id == kernel.get_scheduler().lock(kernel).current_thread_id() {
+        pool.put(kernel, index, SlotState::Started(thread));
+        return Err(Error::InvalidArgument);
+    }
+
+    match thread.join_until(kernel, deadline) {
+        JoinResult::Joined(thread) => {
+            thread_pool_debug!("Joined pool thread {}", handle as u32);
+            pool.put(kernel, index, SlotState::Free(thread));
+            Ok(())
+        }
+        JoinResult::Err { error, thread } => {
+            pool.put(kernel, index, SlotState::Started(thread));
+            Err(error)
+        }
+    }
+}
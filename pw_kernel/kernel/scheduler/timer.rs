@@ -12,13 +12,16 @@
 // License for the specific language governing permissions and limitations under
 // the License.
 
+use core::cell::UnsafeCell;
 use core::ptr::NonNull;
 
 use foreign_box::ForeignBox;
 use list::{ForeignList, Link};
-use time::Instant;
+use pw_status::{Error, Result};
+use time::{Duration, Instant};
 
 use crate::scheduler::Kernel;
+use crate::sync::spinlock::{SpinLock, SpinLockGuard};
 
 list::define_adapter!(pub TimerCallbackListAdapter<K: Kernel> => Timer<K>::link);
 
@@ -68,6 +71,11 @@ impl<K: Kernel> Timer<K> {
     pub fn set(&mut self, deadline: Instant<K::Clock>) {
         self.deadline = deadline
     }
+
+    #[must_use]
+    pub fn deadline(&self) -> Instant<K::Clock> {
+        self.deadline
+    }
 }
 
 #[allow(dead_code)]
@@ -86,21 +94,29 @@ impl<K: Kernel> TimerQueue<K> {
         }
     }
 
+    /// Returns the deadline of the earliest timer in the queue.
+    #[must_use]
+    pub fn next_deadline(&self) -> Option<Instant<K::Clock>> {
+        let mut deadline = None;
+        // The queue is sorted by deadline so only the head needs inspecting.
+        let _ = self
+            .queue
+            .for_each(|timer| -> core::result::Result<(), ()> {
+                deadline = Some(timer.deadline);
+                Err(())
+            });
+        deadline
+    }
+
     pub fn get_next_exipred_callback(
         &mut self,
         now: Instant<K::Clock>,
     ) -> Option<ForeignBox<Timer<K>>> {
-        // TODO - konkers: An optimization opportunity exists here to implement
-        // a something like `peek_front(|front| front.deadline > now)` which
-        // would eliminate the popping and re-pushing of the non-expired
-        // callback.
-        let callback = self.queue.pop_head()?;
-        if callback.deadline > now {
-            self.queue.push_front(callback);
+        // Peek at the head so that a non-expired timer is left in place.
+        if self.next_deadline()? > now {
             return None;
         }
-
-        Some(callback)
+        self.queue.pop_head()
     }
 }
 
@@ -162,3 +178,245 @@ pub fn process_queue<K: Kernel>(kernel: K, now: Instant<K::Clock>) {
         timer_queue = kernel.get_timer_queue().lock(kernel);
     }
 }
+
+/// Returns the first deadline of a timer with `period` which is after `now`.
+///
+/// Deadlines are a whole number of periods after `deadline`, so a periodic
+/// timer does not drift however late it is processed.  Periods which were
+/// missed entirely are skipped rather than fired back to back.
+#[must_use]
+pub fn next_periodic_deadline<K: Kernel>(
+    deadline: Instant<K::Clock>,
+    period: Duration<K::Clock>,
+    now: Instant<K::Clock>,
+) -> Instant<K::Clock> {
+    let period_ticks = period.ticks().cast_unsigned();
+    let elapsed_periods = if now < deadline {
+        0
+    } else {
+        (now.ticks() - deadline.ticks()) / period_ticks
+    };
+    Instant::from_ticks(deadline.ticks() + (elapsed_periods + 1) * period_ticks)
+}
+
+/// Handler called when a [`KernelTimer`] expires.
+pub trait TimerHandler<K: Kernel>: Sync {
+    /// Called from the timer queue, usually in interrupt context, with the time
+    /// at which the expiry was processed.
+    ///
+    /// The handler may re-start or cancel the timer.
+    fn expired(&self, kernel: K, now: Instant<K::Clock>);
+}
+
+struct KernelTimerState<K: Kernel> {
+    handler: Option<&'static dyn TimerHandler<K>>,
+    /// Next expiry, or `None` while the timer is stopped.
+    deadline: Option<Instant<K::Clock>>,
+    period: Option<Duration<K::Clock>>,
+    /// Whether `KernelTimer::timer` is owned by the timer queue, either
+    /// because it is scheduled or because its expiry is being processed.
+    queued: bool,
+}
+
+struct KernelTimerExpiry<K: Kernel> {
+    timer: Option<&'static KernelTimer<K>>,
+}
+
+impl<K: Kernel> TimerCallback<K> for KernelTimerExpiry<K> {
+    fn callback(
+        &mut self,
+        kernel: K,
+        timer: ForeignBox<Timer<K>>,
+        now: Instant<K::Clock>,
+    ) -> Option<ForeignBox<Timer<K>>> {
+        let Some(kernel_timer) = self.timer else {
+            pw_assert::panic!("KernelTimer expired before being started");
+        };
+        kernel_timer.expire(kernel, timer, now)
+    }
+}
+
+/// A one-shot or periodic timer which can be safely re-started and cancelled.
+///
+/// Unlike a raw [`Timer`], whose ownership is passed back and forth with the
+/// timer queue, a `KernelTimer` keeps track of its queue entry itself.  It is
+/// intended to be statically allocated, typically as part of a driver or
+/// kernel object.
+pub struct KernelTimer<K: Kernel> {
+    state: SpinLock<K, KernelTimerState<K>>,
+    timer: UnsafeCell<Option<Timer<K>>>,
+    expiry: UnsafeCell<KernelTimerExpiry<K>>,
+}
+
+// Safety: `timer` and `expiry` are only accessed with the state lock held
+// while they are not owned by the timer queue.
+unsafe impl<K: Kernel> Sync for KernelTimer<K> {}
+unsafe impl<K: Kernel> Send for KernelTimer<K> {}
+
+impl<K: Kernel> KernelTimer<K> {
+    #[allow(clippy::new_without_default)]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            state: SpinLock::new(KernelTimerState {
+                handler: None,
+                deadline: None,
+                period: None,
+                queued: false,
+            }),
+            timer: UnsafeCell::new(None),
+            expiry: UnsafeCell::new(KernelTimerExpiry { timer: None }),
+        }
+    }
+
+    /// Returns `true` if the timer has been started and not yet expired or
+    /// been cancelled.  Periodic timers stay active until cancelled.
+    #[must_use]
+    pub fn is_active(&self, kernel: K) -> bool {
+        self.state.lock(kernel).deadline.is_some()
+    }
+
+    /// Starts the timer, calling `handler` at `deadline` and, if `period` is
+    /// given, every `period` thereafter.
+    ///
+    /// Re-starting an active timer replaces its deadline, period and handler.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is not positive.
+    pub fn start(
+        &'static self,
+        kernel: K,
+        handler: &'static dyn TimerHandler<K>,
+        deadline: Instant<K::Clock>,
+        period: Option<Duration<K::Clock>>,
+    ) {
+        if let Some(period) = period {
+            pw_assert::assert!(period.ticks() > 0);
+        }
+
+        let mut state = self.state.lock(kernel);
+        state.handler = Some(handler);
+        state.period = period;
+        if !state.queued {
+            // Safety: The timer is not owned by the timer queue and the state
+            // lock is held.
+            unsafe { (*self.expiry.get()).timer = Some(self) };
+        }
+        self.arm(kernel, state, deadline);
+    }
+
+    /// Re-starts the timer at `deadline`, keeping the handler and period it
+    /// was last started with.
+    ///
+    /// # Returns
+    /// - `Error::FailedPrecondition`: The timer has never been started.
+    pub fn restart(&self, kernel: K, deadline: Instant<K::Clock>) -> Result<()> {
+        let state = self.state.lock(kernel);
+        if state.handler.is_none() {
+            return Err(Error::FailedPrecondition);
+        }
+        self.arm(kernel, state, deadline);
+        Ok(())
+    }
+
+    fn arm(
+        &self,
+        kernel: K,
+        mut state: SpinLockGuard<'_, K, KernelTimerState<K>>,
+        deadline: Instant<K::Clock>,
+    ) {
+        state.deadline = Some(deadline);
+
+        if state.queued {
+            // Move the pending timer to its new deadline.  If it is not in the
+            // queue its expiry is being processed and `expire()` picks up the
+            // new deadline once the handler returns.
+            if let Some(mut timer) = self.take_from_queue(kernel) {
+                timer.set(deadline);
+                schedule_timer(kernel, timer);
+            }
+            return;
+        }
+
+        // Safety: The timer is not owned by the timer queue and the state lock
+        // is held.  `expiry` was pointed at this `'static` timer by `start()`.
+        let timer = unsafe {
+            let callback: *mut dyn TimerCallback<K> = self.expiry.get();
+            (*self.timer.get()).insert(Timer::new(deadline, ForeignBox::new_from_ptr(callback)))
+        };
+        state.queued = true;
+        // Safety: `start()` has established that this timer is `'static`.
+        schedule_timer(kernel, unsafe { ForeignBox::new_from_ptr(timer) });
+    }
+
+    /// Stops the timer.
+    ///
+    /// Once `cancel()` returns the handler will not be called again until the
+    /// timer is re-started, although a call already in progress on another CPU
+    /// may still complete.
+    pub fn cancel(&self, kernel: K) {
+        let mut state = self.state.lock(kernel);
+        state.deadline = None;
+        if state.queued {
+            if let Some(timer) = self.take_from_queue(kernel) {
+                Self::retire(timer);
+                state.queued = false;
+            }
+        }
+    }
+
+    fn take_from_queue(&self, kernel: K) -> Option<ForeignBox<Timer<K>>> {
+        // Safety: `timer` is `'static` and only ever inserted into the timer
+        // queue by this `KernelTimer`.
+        let timer = unsafe { (*self.timer.get()).as_mut().map(NonNull::from_mut)? };
+        unsafe { cancel_timer(kernel, timer) }
+    }
+
+    // Releases a timer which has been removed from the timer queue.
+    fn retire(mut timer: ForeignBox<Timer<K>>) {
+        if let Some(callback) = timer.callback.take() {
+            let _ = callback.consume();
+        }
+        let _ = timer.consume();
+    }
+
+    fn expire(
+        &self,
+        kernel: K,
+        mut timer: ForeignBox<Timer<K>>,
+        now: Instant<K::Clock>,
+    ) -> Option<ForeignBox<Timer<K>>> {
+        let mut state = self.state.lock(kernel);
+        match (state.deadline, state.handler) {
+            // Re-started with a later deadline while waiting to be processed.
+            (Some(deadline), _) if deadline > now => {
+                timer.set(deadline);
+                return Some(timer);
+            }
+            (Some(deadline), Some(handler)) => {
+                state.deadline = state
+                    .period
+                    .map(|period| next_periodic_deadline::<K>(deadline, period, now));
+
+                // Call the handler without the state lock held so that it can
+                // re-start or cancel the timer.
+                drop(state);
+                handler.expired(kernel, now);
+                state = self.state.lock(kernel);
+            }
+            // Cancelled while waiting to be processed.
+            _ => {}
+        }
+
+        if let Some(deadline) = state.deadline {
+            timer.set(deadline);
+            return Some(timer);
+        }
+
+        // The timer queue consumes the callback once `None` is returned.
+        state.queued = false;
+        let _ = timer.consume();
+        None
+    }
+}
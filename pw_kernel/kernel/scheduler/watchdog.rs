@@ -0,0 +1,114 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+//! Per-thread watchdog check-ins.
+//!
+//! A thread given a watchdog interval with
+//! [`Thread::set_watchdog_interval()`] must call [`check_in()`] at least once
+//! per interval from the time it is started.  A watchdog service polls
+//! [`find_overdue_thread()`] and only feeds the hardware watchdog while no
+//! thread is overdue.
+
+use time::{Duration, Instant};
+
+use crate::Kernel;
+use crate::scheduler::thread::{State, Thread};
+
+/// Watchdog state of a thread.
+pub struct ThreadWatchdog<K: Kernel> {
+    /// Maximum time between check-ins, or `None` if the thread is not watched.
+    pub(super) interval: Option<Duration<K::Clock>>,
+
+    /// Time of the thread's last check-in, or of it being started.
+    pub(super) last_check_in: Instant<K::Clock>,
+}
+
+impl<K: Kernel> ThreadWatchdog<K> {
+    #[allow(clippy::new_without_default)]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            interval: None,
+            last_check_in: Instant::MIN,
+        }
+    }
+}
+
+/// A watched thread which has not checked in within its interval.
+#[derive(Clone, Copy)]
+pub struct OverdueThread<K: Kernel> {
+    pub id: usize,
+    pub name: &'static str,
+    pub process_name: &'static str,
+    pub interval: Duration<K::Clock>,
+    pub last_check_in: Instant<K::Clock>,
+}
+
+impl<K: Kernel> OverdueThread<K> {
+    fn new(thread: &Thread<K>, interval: Duration<K::Clock>) -> Self {
+        Self {
+            id: thread.id(),
+            name: thread.name,
+            process_name: thread.process().name,
+            interval,
+            last_check_in: thread.watchdog.last_check_in,
+        }
+    }
+}
+
+/// Records a check-in for the current thread.
+///
+/// Has no effect if the current thread is not watched.
+pub fn check_in<K: Kernel>(kernel: K) {
+    let mut sched_state = kernel.get_scheduler().lock(kernel);
+    let now = kernel.now();
+    sched_state.current_thread_mut().watchdog.last_check_in = now;
+}
+
+/// Returns the first watched thread which has not checked in by `now`.
+///
+/// Threads which have not been started, have exited or are being terminated
+/// are not watched.
+pub fn find_overdue_thread<K: Kernel>(
+    kernel: K,
+    now: Instant<K::Clock>,
+) -> Option<OverdueThread<K>> {
+    let sched_state = kernel.get_scheduler().lock(kernel);
+    let mut overdue = None;
+    // SAFETY: The process and thread lists are only accessed with the
+    // scheduler lock held.
+    unsafe {
+        let _ = sched_state
+            .process_list
+            .for_each(|process| -> core::result::Result<(), ()> {
+                process
+                    .thread_list
+                    .for_each(|thread| -> core::result::Result<(), ()> {
+                        let Some(interval) = thread.watchdog.interval else {
+                            return Ok(());
+                        };
+                        let watched =
+                            matches!(thread.state, State::Ready | State::Running | State::Waiting)
+                                && !thread.terminating;
+                        if !watched || now <= thread.watchdog.last_check_in + interval {
+                            return Ok(());
+                        }
+                        overdue = Some(OverdueThread::new(thread, interval));
+                        // Stop iteration.
+                        Err(())
+                    })
+            });
+    }
+    overdue
+}
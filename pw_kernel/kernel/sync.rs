@@ -13,5 +13,7 @@
 // the License.
 
 pub mod event;
+pub mod event_flags;
 pub mod mutex;
+pub mod semaphore;
 pub mod spinlock;
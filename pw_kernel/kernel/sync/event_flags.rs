@@ -0,0 +1,179 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+use pw_status::{Error, Result};
+use time::Instant;
+
+use crate::Kernel;
+use crate::scheduler::{WaitQueueLock, WaitQueueLockGuard, WaitType};
+
+/// Configuration for the behavior of an [`EventFlags`].
+#[derive(Eq, PartialEq)]
+pub enum EventFlagsConfig {
+    /// A waiter which is satisfied clears the flags it waited on, returning
+    /// them to the un-set state.
+    AutoClear,
+    /// Flags remain set until [`clear`] is called.
+    ///
+    /// [`clear`]: EventFlags::clear
+    ManualClear,
+}
+
+/// Condition a waiter on an [`EventFlags`] waits for.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum FlagsWait {
+    /// Any of the flags in the mask are set.
+    Any,
+    /// All of the flags in the mask are set.
+    All,
+}
+
+impl FlagsWait {
+    const fn is_satisfied(self, flags: u32, mask: u32) -> bool {
+        match self {
+            FlagsWait::Any => flags & mask != 0,
+            FlagsWait::All => flags & mask == mask,
+        }
+    }
+}
+
+/// A set of 32 flags threads can block on.
+///
+/// Flags are set with [`set`] and cleared with [`clear`].  Threads use
+/// [`wait`] or [`wait_until`] to block until any or all of a mask of flags
+/// are set.
+///
+/// Flags are level triggered: waiters are woken whenever flags are set and
+/// then check the flags' current value.  A flag which is set and cleared again
+/// before a waiter gets to run is not observed by that waiter.
+///
+/// Like [`Mutex`], waits are not interruptible so that a terminating thread
+/// may still wait on the events it needs to clean up.
+///
+/// [`set`]: EventFlags::set
+/// [`clear`]: EventFlags::clear
+/// [`wait`]: EventFlags::wait
+/// [`wait_until`]: EventFlags::wait_until
+/// [`Mutex`]: super::mutex::Mutex
+pub struct EventFlags<K: Kernel> {
+    config: EventFlagsConfig,
+    flags: WaitQueueLock<K, u32>,
+}
+
+unsafe impl<K: Kernel> Sync for EventFlags<K> {}
+unsafe impl<K: Kernel> Send for EventFlags<K> {}
+
+impl<K: Kernel> EventFlags<K> {
+    /// Constructs a new `EventFlags` with all flags cleared.
+    #[must_use]
+    pub const fn new(kernel: K, config: EventFlagsConfig) -> Self {
+        Self {
+            config,
+            flags: WaitQueueLock::new(kernel, 0),
+        }
+    }
+
+    /// Returns the current value of the flags.
+    #[must_use]
+    pub fn get(&self) -> u32 {
+        *self.flags.lock()
+    }
+
+    /// Sets the flags in `mask` and wakes all waiters so that they can check
+    /// whether their condition is satisfied.
+    ///
+    /// # Interrupt context
+    ///
+    /// This method *is* safe to call in an interrupt context.
+    pub fn set(&self, mask: u32) {
+        let mut flags = self.flags.lock();
+        let new_flags = *flags | mask;
+        if new_flags == *flags {
+            return;
+        }
+        *flags = new_flags;
+        flags.wake_all();
+    }
+
+    /// Clears the flags in `mask`.
+    ///
+    /// # Interrupt context
+    ///
+    /// This method *is* safe to call in an interrupt context.
+    pub fn clear(&self, mask: u32) {
+        *self.flags.lock() &= !mask;
+    }
+
+    /// Waits until `wait` is satisfied for the flags in `mask`.
+    ///
+    /// Returns the flags in `mask` which were set.  If the configuration is
+    /// [`EventFlagsConfig::AutoClear`] those flags are also cleared.
+    ///
+    /// # Returns
+    /// - `Error::InvalidArgument`: `mask` is empty.
+    ///
+    /// # Interrupt context
+    ///
+    /// This method is *not* safe to call in an interrupt context.
+    pub fn wait(&self, mask: u32, wait: FlagsWait) -> Result<u32> {
+        self.wait_until(mask, wait, Instant::MAX)
+    }
+
+    /// Waits until `wait` is satisfied for the flags in `mask` or the
+    /// `deadline` is reached, whichever happens first.
+    ///
+    /// Returns the flags in `mask` which were set.  If the configuration is
+    /// [`EventFlagsConfig::AutoClear`] those flags are also cleared.
+    ///
+    /// # Returns
+    /// - `Error::InvalidArgument`: `mask` is empty.
+    /// - `Error::DeadlineExceeded`: `wait` was not satisfied before `deadline`.
+    ///
+    /// # Interrupt context
+    ///
+    /// This method is *not* safe to call in an interrupt context.
+    pub fn wait_until(
+        &self,
+        mask: u32,
+        wait: FlagsWait,
+        deadline: Instant<K::Clock>,
+    ) -> Result<u32> {
+        if mask == 0 {
+            return Err(Error::InvalidArgument);
+        }
+
+        let mut flags = self.flags.lock();
+        loop {
+            if wait.is_satisfied(*flags, mask) {
+                return Ok(self.take(&mut flags, mask));
+            }
+
+            let result;
+            (flags, result) = if deadline == Instant::MAX {
+                flags.wait(WaitType::NonInterruptible)
+            } else {
+                flags.wait_until(WaitType::NonInterruptible, deadline)
+            };
+            result?;
+        }
+    }
+
+    fn take(&self, flags: &mut WaitQueueLockGuard<'_, K, u32>, mask: u32) -> u32 {
+        let matched = **flags & mask;
+        if self.config == EventFlagsConfig::AutoClear {
+            **flags &= !matched;
+        }
+        matched
+    }
+}
@@ -20,7 +20,7 @@ use time::Instant;
 
 use crate::Kernel;
 use crate::scheduler::thread::Thread;
-use crate::scheduler::{WaitQueueLock, WaitType};
+use crate::scheduler::{WaitQueueLock, WaitQueueLockGuard, WaitType};
 
 const MUTEX_DEBUG: bool = false;
 macro_rules! mutex_debug {
@@ -108,9 +108,14 @@ impl<K: Kernel, T> Mutex<K, T> {
             );
             // Mutexes use uninterruptible waits because cleaning up a terminating
             // thread may involve aquisiation of mutex protected resources.
+            //
+            // Ownership of the wait queue, and with it priority inheritance,
+            // is handed to this thread by `unlock()` before it is woken.
             let res;
             (state, res) = state.wait(WaitType::NonInterruptible);
             pw_assert::debug_assert!(res.is_ok());
+        } else {
+            state = Self::take_ownership(state);
         }
         mutex_debug!(
             "Mutex {:#010x}: lock acquired by thread '{}' ({:#010x})",
@@ -175,6 +180,8 @@ impl<K: Kernel, T> Mutex<K, T> {
                 }
                 return Err(e);
             }
+        } else {
+            state = Self::take_ownership(state);
         }
         mutex_debug!(
             "Mutex {:#010x}: lock_until acquired by thread '{}' ({:#010x})",
@@ -205,7 +212,22 @@ impl<K: Kernel, T> Mutex<K, T> {
         // TODO - konkers: investigate using core::intrinsics::unlikely() or
         //                 core::hint::unlikely()
         if state.count > 0 {
-            state.wake_one();
+            state.operate_on_wait_queue(|queue| queue.wake_one_and_transfer_ownership());
+        } else {
+            state.operate_on_wait_queue(|queue| (queue.clear_owner(), ()));
         }
     }
+
+    // Makes the current thread the owner of the mutex's wait queue so that it
+    // inherits the priority of any threads which later wait on the mutex.
+    fn take_ownership(
+        state: WaitQueueLockGuard<'_, K, MutexState>,
+    ) -> WaitQueueLockGuard<'_, K, MutexState> {
+        state
+            .operate_on_wait_queue(|mut queue| {
+                queue.set_owner_to_current();
+                (queue, ())
+            })
+            .0
+    }
 }
@@ -0,0 +1,178 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+use pw_status::{Error, Result};
+use time::Instant;
+
+use crate::Kernel;
+use crate::scheduler::{WaitQueueLock, WaitType, WakeResult};
+
+const SEMAPHORE_DEBUG: bool = false;
+macro_rules! semaphore_debug {
+  ($($args:expr),*) => {{
+    log_if::debug_if!(SEMAPHORE_DEBUG, $($args),*)
+  }}
+}
+
+struct SemaphoreState {
+    count: usize,
+    max_count: usize,
+}
+
+/// A counting semaphore.
+///
+/// A `Semaphore` holds a count of available permits.  [`acquire`] and
+/// [`acquire_until`] take a permit, blocking while none are available, and
+/// [`release`] returns one.
+///
+/// Permits released while threads are waiting are handed directly to the
+/// waiter which has been waiting the longest rather than being added to the
+/// count, so a waiter can not have its permit taken by a thread which had not
+/// yet blocked.  Waiters are woken in FIFO order regardless of priority.
+///
+/// Like [`Mutex`], waits are not interruptible so that a terminating thread
+/// may still acquire the resources it needs to clean up.
+///
+/// [`acquire`]: Semaphore::acquire
+/// [`acquire_until`]: Semaphore::acquire_until
+/// [`release`]: Semaphore::release
+/// [`Mutex`]: super::mutex::Mutex
+pub struct Semaphore<K: Kernel> {
+    state: WaitQueueLock<K, SemaphoreState>,
+}
+
+unsafe impl<K: Kernel> Sync for Semaphore<K> {}
+unsafe impl<K: Kernel> Send for Semaphore<K> {}
+
+impl<K: Kernel> Semaphore<K> {
+    /// Constructs a new `Semaphore` with `initial_count` available permits
+    /// which can hold at most `max_count` permits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `initial_count` is greater than `max_count`.
+    #[must_use]
+    pub const fn new(kernel: K, initial_count: usize, max_count: usize) -> Self {
+        assert!(initial_count <= max_count);
+        Self {
+            state: WaitQueueLock::new(
+                kernel,
+                SemaphoreState {
+                    count: initial_count,
+                    max_count,
+                },
+            ),
+        }
+    }
+
+    /// Returns the number of available permits.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.state.lock().count
+    }
+
+    /// Takes a permit without blocking.
+    ///
+    /// # Returns
+    /// - `Error::Unavailable`: No permits are available.
+    ///
+    /// # Interrupt context
+    ///
+    /// This method *is* safe to call in an interrupt context.
+    pub fn try_acquire(&self) -> Result<()> {
+        let mut state = self.state.lock();
+        if state.count == 0 {
+            return Err(Error::Unavailable);
+        }
+        state.count -= 1;
+        Ok(())
+    }
+
+    /// Takes a permit, waiting until one is available.
+    ///
+    /// # Interrupt context
+    ///
+    /// This method is *not* safe to call in an interrupt context.
+    pub fn acquire(&self) {
+        let mut state = self.state.lock();
+        if state.count > 0 {
+            state.count -= 1;
+            return;
+        }
+
+        semaphore_debug!(
+            "Semaphore {:#010x}: acquire wait by thread '{}' ({:#010x})",
+            &raw const *self as usize,
+            state.sched().current_thread_name() as &str,
+            state.sched().current_thread_id() as usize
+        );
+
+        // A successful wait means `release()` handed its permit to this thread.
+        let (_state, result) = state.wait(WaitType::NonInterruptible);
+        pw_assert::debug_assert!(result.is_ok());
+    }
+
+    /// Takes a permit, waiting until one is available or the `deadline` is
+    /// reached, whichever happens first.
+    ///
+    /// # Returns
+    /// - `Error::DeadlineExceeded`: No permit became available before
+    ///   `deadline`.
+    ///
+    /// # Interrupt context
+    ///
+    /// This method is *not* safe to call in an interrupt context.
+    pub fn acquire_until(&self, deadline: Instant<K::Clock>) -> Result<()> {
+        let mut state = self.state.lock();
+        if state.count > 0 {
+            state.count -= 1;
+            return Ok(());
+        }
+
+        semaphore_debug!(
+            "Semaphore {:#010x}: acquire_until({}) wait by thread '{}' ({:#010x})",
+            &raw const *self as usize,
+            deadline.ticks() as u64,
+            state.sched().current_thread_name() as &str,
+            state.sched().current_thread_id() as usize
+        );
+
+        // A successful wait means `release()` handed its permit to this thread.
+        let (_state, result) = state.wait_until(WaitType::NonInterruptible, deadline);
+        result
+    }
+
+    /// Returns a permit, waking the longest waiting thread if there is one.
+    ///
+    /// # Returns
+    /// - `Error::ResourceExhausted`: The semaphore already holds `max_count`
+    ///   permits.
+    ///
+    /// # Interrupt context
+    ///
+    /// This method *is* safe to call in an interrupt context.
+    pub fn release(&self) -> Result<()> {
+        let state = self.state.lock();
+        // Waiters only exist while the count is zero.
+        if state.count == state.max_count {
+            return Err(Error::ResourceExhausted);
+        }
+
+        let (mut state, result) = state.wake_one();
+        if result == WakeResult::QueueEmpty {
+            state.count += 1;
+        }
+        Ok(())
+    }
+}
@@ -69,6 +69,9 @@ pub struct SpinLock<K: Kernel, T> {
 unsafe impl<K: Sync + Kernel, T: Send> Sync for SpinLock<K, T> {}
 
 impl<K: Kernel, T> SpinLock<K, T> {
+    // Offset of the protected data, for use in debug annotations.
+    pub(crate) const DATA_OFFSET: usize = core::mem::offset_of!(Self, data);
+
     pub const fn new(initial_value: T) -> Self {
         Self {
             data: UnsafeCell::new(initial_value),
@@ -17,12 +17,13 @@ use memory_config::MemoryRegionType;
 use pw_cast::CastInto as _;
 use pw_log::info;
 use pw_status::{Error, Result};
-use syscall_defs::{Signals, SysCallId, SysCallReturnValue};
-use time::{Clock, Instant};
+use syscall_defs::{ProcessFault, Signals, SysCallId, SysCallReturnValue};
+use time::{Clock, Duration, Instant};
 
 use crate::Kernel;
 use crate::interrupt_controller::InterruptController;
 use crate::object::{KernelObject, SyscallBuffer};
+use crate::scheduler::{self, futex, thread_pool};
 
 const SYSCALL_DEBUG: bool = false;
 
@@ -99,7 +100,26 @@ fn handle_object_wait<'a, K: Kernel>(kernel: K, mut args: K::SyscallArgs<'a>) ->
 
     let ret = object.object_wait(kernel, signal_mask, deadline);
     log_if::debug_if!(SYSCALL_DEBUG, "syscall: object_wait complete");
-    ret.map(|s| s.bits().into())
+    ret.map(|wait_return| wait_return.to_raw())
+}
+
+fn handle_object_raise_peer_user_signal<'a, K: Kernel>(
+    kernel: K,
+    mut args: K::SyscallArgs<'a>,
+) -> Result<u64> {
+    log_if::debug_if!(
+        SYSCALL_DEBUG,
+        "syscall: handling object_raise_peer_user_signal"
+    );
+    let handle = args.next_u32()?;
+
+    let object = lookup_handle(kernel, handle)?;
+    let ret = object.object_raise_peer_user_signal(kernel);
+    log_if::debug_if!(
+        SYSCALL_DEBUG,
+        "syscall: object_raise_peer_user_signal complete"
+    );
+    ret.map(|_| 0)
 }
 
 fn handle_channel_transact<'a, K: Kernel>(kernel: K, mut args: K::SyscallArgs<'a>) -> Result<u64> {
@@ -141,6 +161,57 @@ fn handle_channel_transact<'a, K: Kernel>(kernel: K, mut args: K::SyscallArgs<'a
     ret.map(|v| v.cast_into())
 }
 
+fn handle_channel_async_transact<'a, K: Kernel>(
+    kernel: K,
+    mut args: K::SyscallArgs<'a>,
+) -> Result<u64> {
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: handling channel_async_transact");
+    let handle = args.next_u32()?;
+    let send_data_addr = args.next_usize()?;
+    let send_data_len = args.next_usize()?;
+    let recv_data_addr = args.next_usize()?;
+    let recv_data_len = args.next_usize()?;
+
+    log_if::debug_if!(
+        SYSCALL_DEBUG,
+        "syscall: handling channel_async_transact({:#x}, {:#x}, {:#x}, {:#x}, {:#x})",
+        handle as u32,
+        send_data_addr as usize,
+        send_data_len as usize,
+        recv_data_addr as usize,
+        recv_data_len as usize,
+    );
+
+    let object = lookup_handle(kernel, handle)?;
+    let send_buffer = SyscallBuffer::new_in_current_process(
+        kernel,
+        MemoryRegionType::ReadOnlyData,
+        send_data_addr..(send_data_addr + send_data_len),
+    )?;
+    let recv_buffer = SyscallBuffer::new_in_current_process(
+        kernel,
+        MemoryRegionType::ReadWriteData,
+        recv_data_addr..(recv_data_addr + recv_data_len),
+    )?;
+
+    let ret = object.channel_async_transact(kernel, send_buffer, recv_buffer);
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: channel_async_transact complete");
+    ret.map(|_| 0)
+}
+
+fn handle_channel_async_cancel<'a, K: Kernel>(
+    kernel: K,
+    mut args: K::SyscallArgs<'a>,
+) -> Result<u64> {
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: handling channel_async_cancel");
+    let handle = args.next_u32()?;
+
+    let object = lookup_handle(kernel, handle)?;
+    let ret = object.channel_async_cancel(kernel);
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: channel_async_cancel complete");
+    ret.map(|_| 0)
+}
+
 fn handle_channel_read<'a, K: Kernel>(kernel: K, mut args: K::SyscallArgs<'a>) -> Result<u64> {
     log_if::debug_if!(SYSCALL_DEBUG, "syscall: handling channel_read");
     let handle = args.next_u32()?;
@@ -199,6 +270,245 @@ fn handle_interrupt_ack<'a, K: Kernel>(kernel: K, mut args: K::SyscallArgs<'a>)
     ret.map(|_| 0)
 }
 
+fn handle_wait_group_add<'a, K: Kernel>(kernel: K, mut args: K::SyscallArgs<'a>) -> Result<u64> {
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: handling wait_group_add");
+    let wait_group_handle = args.next_u32()?;
+    let object_handle = args.next_u32()?;
+    let signals = args.next_u32()?;
+    let user_data = args.next_usize()?;
+
+    let Some(signal_mask) = Signals::from_bits(signals) else {
+        log_if::debug_if!(
+            SYSCALL_DEBUG,
+            "syscall: WaitGroupAdd invalid signal mask: {:#010x}",
+            signals as usize
+        );
+
+        return Err(Error::InvalidArgument);
+    };
+
+    let wait_group = lookup_handle(kernel, wait_group_handle)?;
+    let object = lookup_handle(kernel, object_handle)?;
+    let ret = wait_group.wait_group_add(kernel, object, signal_mask, user_data);
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: wait_group_add complete");
+    ret.map(|_| 0)
+}
+
+fn handle_wait_group_remove<'a, K: Kernel>(kernel: K, mut args: K::SyscallArgs<'a>) -> Result<u64> {
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: handling wait_group_remove");
+    let wait_group_handle = args.next_u32()?;
+    let object_handle = args.next_u32()?;
+
+    let wait_group = lookup_handle(kernel, wait_group_handle)?;
+    let object = lookup_handle(kernel, object_handle)?;
+    let ret = wait_group.wait_group_remove(kernel, object);
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: wait_group_remove complete");
+    ret.map(|_| 0)
+}
+
+fn handle_futex_wait<'a, K: Kernel>(kernel: K, mut args: K::SyscallArgs<'a>) -> Result<u64> {
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: handling futex_wait");
+    let addr = args.next_usize()?;
+    let expected = args.next_u32()?;
+    let deadline = args.next_instant()?;
+
+    let ret = futex::futex_wait(kernel, addr, expected, deadline);
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: futex_wait complete");
+    ret.map(|_| 0)
+}
+
+fn handle_futex_wake<'a, K: Kernel>(kernel: K, mut args: K::SyscallArgs<'a>) -> Result<u64> {
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: handling futex_wake");
+    let addr = args.next_usize()?;
+    let count = args.next_usize()?;
+
+    let ret = futex::futex_wake(kernel, addr, count);
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: futex_wake complete");
+    ret.map(|v| v.cast_into())
+}
+
+fn handle_get_time<'a, K: Kernel>(kernel: K, _args: K::SyscallArgs<'a>) -> Result<u64> {
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: handling get_time");
+    Ok(kernel.now().ticks())
+}
+
+fn handle_sleep_until<'a, K: Kernel>(kernel: K, mut args: K::SyscallArgs<'a>) -> Result<u64> {
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: handling sleep_until");
+    let deadline = args.next_instant()?;
+
+    let ret = scheduler::sleep_until(kernel, deadline);
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: sleep_until complete");
+    ret.map(|_| 0)
+}
+
+fn handle_yield_timeslice<'a, K: Kernel>(kernel: K, _args: K::SyscallArgs<'a>) -> Result<u64> {
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: handling yield_timeslice");
+    scheduler::yield_timeslice(kernel);
+    Ok(0)
+}
+
+fn handle_thread_create<'a, K: Kernel>(kernel: K, mut args: K::SyscallArgs<'a>) -> Result<u64> {
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: handling thread_create");
+    let entry = args.next_usize()?;
+    let stack_start = args.next_usize()?;
+    let stack_size = args.next_usize()?;
+    let arg0 = args.next_usize()?;
+    let arg1 = args.next_usize()?;
+
+    let ret = thread_pool::thread_create(kernel, entry, stack_start, stack_size, (arg0, arg1));
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: thread_create complete");
+    ret.map(|handle| handle.cast_into())
+}
+
+fn handle_thread_join<'a, K: Kernel>(kernel: K, mut args: K::SyscallArgs<'a>) -> Result<u64> {
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: handling thread_join");
+    let deadline = args.next_instant()?;
+    let handle = args.next_u32()?;
+
+    let ret = thread_pool::thread_join(kernel, handle, deadline);
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: thread_join complete");
+    ret.map(|_| 0)
+}
+
+fn handle_thread_exit<'a, K: Kernel>(kernel: K, _args: K::SyscallArgs<'a>) -> Result<u64> {
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: handling thread_exit");
+    scheduler::exit_thread(kernel)
+}
+
+fn handle_process_terminate<'a, K: Kernel>(kernel: K, mut args: K::SyscallArgs<'a>) -> Result<u64> {
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: handling process_terminate");
+    let handle = args.next_u32()?;
+
+    let object = lookup_handle(kernel, handle)?;
+    let ret = object.process_terminate(kernel);
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: process_terminate complete");
+    ret.map(|_| 0)
+}
+
+fn handle_process_restart<'a, K: Kernel>(kernel: K, mut args: K::SyscallArgs<'a>) -> Result<u64> {
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: handling process_restart");
+    let handle = args.next_u32()?;
+
+    let object = lookup_handle(kernel, handle)?;
+    let ret = object.process_restart(kernel);
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: process_restart complete");
+    ret.map(|_| 0)
+}
+
+fn handle_process_get_exit_status<'a, K: Kernel>(
+    kernel: K,
+    mut args: K::SyscallArgs<'a>,
+) -> Result<u64> {
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: handling process_get_exit_status");
+    let handle = args.next_u32()?;
+
+    let object = lookup_handle(kernel, handle)?;
+    let ret = object.process_exit_status(kernel);
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: process_get_exit_status complete");
+    // A process' exit status is returned as a positive error code.
+    ret.map(|status| match status {
+        Ok(()) => 0,
+        Err(error) => error as u64,
+    })
+}
+
+fn handle_process_get_fault<'a, K: Kernel>(kernel: K, mut args: K::SyscallArgs<'a>) -> Result<u64> {
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: handling process_get_fault");
+    let handle = args.next_u32()?;
+    let fault_addr = args.next_usize()?;
+
+    let object = lookup_handle(kernel, handle)?;
+    let mut buffer = SyscallBuffer::new_in_current_process(
+        kernel,
+        MemoryRegionType::ReadWriteData,
+        fault_addr..(fault_addr + size_of::<ProcessFault>()),
+    )?;
+
+    let ret = object.process_fault(kernel).and_then(|report| {
+        let fault = ProcessFault::new(report.pc, report.cause, report.address);
+        // SAFETY: `ProcessFault` is `repr(C)` and consists solely of `usize`
+        // fields, so it has no padding bytes.
+        let bytes = unsafe {
+            core::slice::from_raw_parts((&raw const fault).cast::<u8>(), size_of::<ProcessFault>())
+        };
+        buffer.as_mut_slice()?.copy_from_slice(bytes);
+        Ok(())
+    });
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: process_get_fault complete");
+    ret.map(|_| 0)
+}
+
+fn handle_watchdog_check_in<'a, K: Kernel>(kernel: K, _args: K::SyscallArgs<'a>) -> Result<u64> {
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: handling watchdog_check_in");
+    scheduler::watchdog::check_in(kernel);
+    Ok(0)
+}
+
+fn handle_timer_set<'a, K: Kernel>(kernel: K, mut args: K::SyscallArgs<'a>) -> Result<u64> {
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: handling timer_set");
+    let deadline = args.next_instant()?;
+    let period = args.next_u64()?;
+    let handle = args.next_u32()?;
+
+    let period = match period {
+        0 => None,
+        ticks => Some(Duration::from_ticks(
+            i64::try_from(ticks).map_err(|_| Error::InvalidArgument)?,
+        )),
+    };
+
+    let object = lookup_handle(kernel, handle)?;
+    let ret = object.timer_set(kernel, deadline, period);
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: timer_set complete");
+    ret.map(|_| 0)
+}
+
+fn handle_timer_cancel<'a, K: Kernel>(kernel: K, mut args: K::SyscallArgs<'a>) -> Result<u64> {
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: handling timer_cancel");
+    let handle = args.next_u32()?;
+
+    let object = lookup_handle(kernel, handle)?;
+    let ret = object.timer_cancel(kernel);
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: timer_cancel complete");
+    ret.map(|_| 0)
+}
+
+fn handle_uart_read<'a, K: Kernel>(kernel: K, mut args: K::SyscallArgs<'a>) -> Result<u64> {
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: handling uart_read");
+    let handle = args.next_u32()?;
+    let buffer_addr = args.next_usize()?;
+    let buffer_len = args.next_usize()?;
+
+    let object = lookup_handle(kernel, handle)?;
+    let buffer = SyscallBuffer::new_in_current_process(
+        kernel,
+        MemoryRegionType::ReadWriteData,
+        buffer_addr..(buffer_addr + buffer_len),
+    )?;
+
+    let ret = object.uart_read(kernel, buffer);
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: uart_read complete");
+    ret.map(|v| v.cast_into())
+}
+
+fn handle_uart_write<'a, K: Kernel>(kernel: K, mut args: K::SyscallArgs<'a>) -> Result<u64> {
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: handling uart_write");
+    let handle = args.next_u32()?;
+    let buffer_addr = args.next_usize()?;
+    let buffer_len = args.next_usize()?;
+
+    let object = lookup_handle(kernel, handle)?;
+    let buffer = SyscallBuffer::new_in_current_process(
+        kernel,
+        MemoryRegionType::ReadOnlyData,
+        buffer_addr..(buffer_addr + buffer_len),
+    )?;
+
+    let ret = object.uart_write(kernel, buffer);
+    log_if::debug_if!(SYSCALL_DEBUG, "syscall: uart_write complete");
+    ret.map(|v| v.cast_into())
+}
+
 fn handle_debug_log<'a, K: Kernel>(kernel: K, mut args: K::SyscallArgs<'a>) -> Result<u64> {
     let buffer_addr = args.next_usize()?;
     let buffer_len = args.next_usize()?;
@@ -243,7 +553,29 @@ pub fn handle_syscall<'a, K: Kernel>(
         SysCallId::ChannelTransact => handle_channel_transact(kernel, args),
         SysCallId::ChannelRead => handle_channel_read(kernel, args),
         SysCallId::ChannelRespond => handle_channel_respond(kernel, args),
+        SysCallId::ChannelAsyncTransact => handle_channel_async_transact(kernel, args),
+        SysCallId::ChannelAsyncCancel => handle_channel_async_cancel(kernel, args),
+        SysCallId::ObjectRaisePeerUserSignal => handle_object_raise_peer_user_signal(kernel, args),
         SysCallId::InterruptAck => handle_interrupt_ack(kernel, args),
+        SysCallId::WaitGroupAdd => handle_wait_group_add(kernel, args),
+        SysCallId::WaitGroupRemove => handle_wait_group_remove(kernel, args),
+        SysCallId::FutexWait => handle_futex_wait(kernel, args),
+        SysCallId::FutexWake => handle_futex_wake(kernel, args),
+        SysCallId::GetTime => handle_get_time(kernel, args),
+        SysCallId::SleepUntil => handle_sleep_until(kernel, args),
+        SysCallId::YieldTimeslice => handle_yield_timeslice(kernel, args),
+        SysCallId::ThreadCreate => handle_thread_create(kernel, args),
+        SysCallId::ThreadJoin => handle_thread_join(kernel, args),
+        SysCallId::ThreadExit => handle_thread_exit(kernel, args),
+        SysCallId::ProcessTerminate => handle_process_terminate(kernel, args),
+        SysCallId::ProcessRestart => handle_process_restart(kernel, args),
+        SysCallId::ProcessGetExitStatus => handle_process_get_exit_status(kernel, args),
+        SysCallId::ProcessGetFault => handle_process_get_fault(kernel, args),
+        SysCallId::WatchdogCheckIn => handle_watchdog_check_in(kernel, args),
+        SysCallId::TimerSet => handle_timer_set(kernel, args),
+        SysCallId::TimerCancel => handle_timer_cancel(kernel, args),
+        SysCallId::UartRead => handle_uart_read(kernel, args),
+        SysCallId::UartWrite => handle_uart_write(kernel, args),
         // TODO: Remove this syscall when logging is added.
         SysCallId::DebugPutc => {
             let arg = args.next_u32()?;
@@ -274,5 +606,11 @@ pub fn handle_syscall<'a, K: Kernel>(
 #[allow(dead_code)]
 pub fn raw_handle_syscall<'a, K: Kernel>(kernel: K, id: u16, args: K::SyscallArgs<'a>) -> i64 {
     let ret_val: SysCallReturnValue = handle_syscall(kernel, id, args).into();
+
+    // Threads terminated during the system call exit instead of returning to
+    // user space.
+    #[cfg(feature = "user_space")]
+    scheduler::exit_if_terminating(kernel);
+
     ret_val.0
 }
@@ -13,5 +13,11 @@
 // the License.
 #![no_std]
 
+mod object;
+mod scheduler;
+mod shell;
 mod stack;
 mod sync;
+#[cfg(test)]
+mod test_utils;
+mod timer;
@@ -0,0 +1,393 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "arch_arm_cortex_m")]
+    use arch_arm_cortex_m::Arch;
+    #[cfg(feature = "arch_host")]
+    use arch_host::HostArch as Arch;
+    #[cfg(feature = "arch_riscv")]
+    use arch_riscv::Arch;
+    use kernel::__private::foreign_box::static_foreign_rc;
+    use kernel::object::{ChannelHandlerObject, ChannelInitiatorObject, KernelObject};
+    use kernel::{Instant, Priority};
+    use memory_config::MemoryRegionType;
+    use pw_status::Error;
+    use syscall_defs::Signals;
+    use unittest::test;
+
+    use crate::test_utils::{buffer, start_thread};
+
+    type AtomicUsize = <Arch as kernel::Arch>::AtomicUsize;
+
+    #[test]
+    fn peer_user_signal_is_cleared_on_wait() -> unittest::Result<()> {
+        let handler = unsafe {
+            static_foreign_rc!(
+                AtomicUsize,
+                ChannelHandlerObject<Arch>,
+                ChannelHandlerObject::new(Arch)
+            )
+        };
+        let initiator = unsafe {
+            static_foreign_rc!(
+                AtomicUsize,
+                ChannelInitiatorObject<Arch>,
+                ChannelInitiatorObject::new(handler.clone())
+            )
+        };
+        handler.set_initiator(Arch, initiator.clone());
+
+        // Handler to initiator.
+        unittest::assert_true!(handler.object_raise_peer_user_signal(Arch).is_ok());
+        let wait_return =
+            unittest::unwrap!(initiator.object_wait(Arch, Signals::USER, Instant::MIN));
+        unittest::assert_true!(wait_return.pending_signals.contains(Signals::USER));
+        unittest::assert_matches!(
+            initiator.object_wait(Arch, Signals::USER, Instant::MIN),
+            Err(Error::DeadlineExceeded)
+        );
+
+        // Initiator to handler.
+        unittest::assert_true!(initiator.object_raise_peer_user_signal(Arch).is_ok());
+        let wait_return = unittest::unwrap!(handler.object_wait(Arch, Signals::USER, Instant::MIN));
+        unittest::assert_true!(wait_return.pending_signals.contains(Signals::USER));
+        unittest::assert_matches!(
+            handler.object_wait(Arch, Signals::USER, Instant::MIN),
+            Err(Error::DeadlineExceeded)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn peer_user_signal_requires_connected_initiator() -> unittest::Result<()> {
+        let handler = unsafe {
+            static_foreign_rc!(
+                AtomicUsize,
+                ChannelHandlerObject<Arch>,
+                ChannelHandlerObject::new(Arch)
+            )
+        };
+
+        unittest::assert_matches!(
+            handler.object_raise_peer_user_signal(Arch),
+            Err(Error::FailedPrecondition)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn handler_close_aborts_transaction_in_flight() -> unittest::Result<()> {
+        let handler = unsafe {
+            static_foreign_rc!(
+                AtomicUsize,
+                ChannelHandlerObject<Arch>,
+                ChannelHandlerObject::new(Arch)
+            )
+        };
+        let initiator = unsafe {
+            static_foreign_rc!(
+                AtomicUsize,
+                ChannelInitiatorObject<Arch>,
+                ChannelInitiatorObject::new(handler.clone())
+            )
+        };
+        handler.set_initiator(Arch, initiator.clone());
+
+        let mut send_data = [0u8; 4];
+        let mut recv_data = [0u8; 4];
+        unittest::assert_true!(
+            initiator
+                .channel_async_transact(
+                    Arch,
+                    buffer(MemoryRegionType::ReadOnlyData, &mut send_data),
+                    buffer(MemoryRegionType::ReadWriteData, &mut recv_data),
+                )
+                .is_ok()
+        );
+
+        handler.object_close(Arch);
+
+        // The surviving initiator has `Signals::ERROR` raised and can no longer
+        // start transactions.
+        let wait_return =
+            unittest::unwrap!(initiator.object_wait(Arch, Signals::ERROR, Instant::MIN));
+        unittest::assert_true!(wait_return.pending_signals.contains(Signals::ERROR));
+        unittest::assert_matches!(
+            initiator.channel_transact(
+                Arch,
+                buffer(MemoryRegionType::ReadOnlyData, &mut send_data),
+                buffer(MemoryRegionType::ReadWriteData, &mut recv_data),
+                Instant::MAX,
+            ),
+            Err(Error::Aborted)
+        );
+        unittest::assert_matches!(
+            initiator.object_raise_peer_user_signal(Arch),
+            Err(Error::Aborted)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn initiator_close_drops_pending_transaction() -> unittest::Result<()> {
+        let handler = unsafe {
+            static_foreign_rc!(
+                AtomicUsize,
+                ChannelHandlerObject<Arch>,
+                ChannelHandlerObject::new(Arch)
+            )
+        };
+        let initiator = unsafe {
+            static_foreign_rc!(
+                AtomicUsize,
+                ChannelInitiatorObject<Arch>,
+                ChannelInitiatorObject::new(handler.clone())
+            )
+        };
+        handler.set_initiator(Arch, initiator.clone());
+
+        let mut send_data = [0u8; 4];
+        let mut recv_data = [0u8; 4];
+        unittest::assert_true!(
+            initiator
+                .channel_async_transact(
+                    Arch,
+                    buffer(MemoryRegionType::ReadOnlyData, &mut send_data),
+                    buffer(MemoryRegionType::ReadWriteData, &mut recv_data),
+                )
+                .is_ok()
+        );
+
+        initiator.object_close(Arch);
+
+        let wait_return = unittest::unwrap!(handler.object_wait(
+            Arch,
+            Signals::READABLE | Signals::ERROR,
+            Instant::MIN
+        ));
+        unittest::assert_true!(wait_return.pending_signals.contains(Signals::ERROR));
+        unittest::assert_false!(wait_return.pending_signals.contains(Signals::READABLE));
+
+        let mut read_data = [0u8; 4];
+        unittest::assert_matches!(
+            handler.channel_read(
+                Arch,
+                0,
+                buffer(MemoryRegionType::ReadWriteData, &mut read_data)
+            ),
+            Err(Error::Aborted)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn async_transact_completes_with_response() -> unittest::Result<()> {
+        let handler = unsafe {
+            static_foreign_rc!(
+                AtomicUsize,
+                ChannelHandlerObject<Arch>,
+                ChannelHandlerObject::new(Arch)
+            )
+        };
+        let initiator = unsafe {
+            static_foreign_rc!(
+                AtomicUsize,
+                ChannelInitiatorObject<Arch>,
+                ChannelInitiatorObject::new(handler.clone())
+            )
+        };
+        handler.set_initiator(Arch, initiator.clone());
+
+        let mut send_data = [1u8, 2, 3, 4];
+        let mut recv_data = [0u8; 4];
+        unittest::assert_true!(
+            initiator
+                .channel_async_transact(
+                    Arch,
+                    buffer(MemoryRegionType::ReadOnlyData, &mut send_data),
+                    buffer(MemoryRegionType::ReadWriteData, &mut recv_data),
+                )
+                .is_ok()
+        );
+
+        // Only one transaction may be pending at a time.
+        unittest::assert_matches!(
+            initiator.channel_async_transact(
+                Arch,
+                buffer(MemoryRegionType::ReadOnlyData, &mut send_data),
+                buffer(MemoryRegionType::ReadWriteData, &mut recv_data),
+            ),
+            Err(Error::ResourceExhausted)
+        );
+
+        let wait_return =
+            unittest::unwrap!(handler.object_wait(Arch, Signals::READABLE, Instant::MIN));
+        unittest::assert_true!(wait_return.pending_signals.contains(Signals::READABLE));
+
+        let mut read_data = [0u8; 4];
+        unittest::assert_eq!(
+            handler.channel_read(
+                Arch,
+                0,
+                buffer(MemoryRegionType::ReadWriteData, &mut read_data)
+            ),
+            Ok(4)
+        );
+        unittest::assert_eq!(read_data, send_data);
+
+        let mut response = [5u8, 6];
+        unittest::assert_true!(
+            handler
+                .channel_respond(Arch, buffer(MemoryRegionType::ReadOnlyData, &mut response))
+                .is_ok()
+        );
+
+        // The initiator is signaled with the length of the response.
+        let wait_return =
+            unittest::unwrap!(initiator.object_wait(Arch, Signals::READABLE, Instant::MIN));
+        unittest::assert_true!(wait_return.pending_signals.contains(Signals::WRITEABLE));
+        unittest::assert_eq!(wait_return.user_data, 2);
+        unittest::assert_eq!(recv_data, [5, 6, 0, 0]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn async_cancel_withdraws_pending_transaction() -> unittest::Result<()> {
+        let handler = unsafe {
+            static_foreign_rc!(
+                AtomicUsize,
+                ChannelHandlerObject<Arch>,
+                ChannelHandlerObject::new(Arch)
+            )
+        };
+        let initiator = unsafe {
+            static_foreign_rc!(
+                AtomicUsize,
+                ChannelInitiatorObject<Arch>,
+                ChannelInitiatorObject::new(handler.clone())
+            )
+        };
+        handler.set_initiator(Arch, initiator.clone());
+
+        // There is nothing to cancel before a transaction is started.
+        unittest::assert_matches!(
+            initiator.channel_async_cancel(Arch),
+            Err(Error::FailedPrecondition)
+        );
+
+        let mut send_data = [0u8; 4];
+        let mut recv_data = [0u8; 4];
+        unittest::assert_true!(
+            initiator
+                .channel_async_transact(
+                    Arch,
+                    buffer(MemoryRegionType::ReadOnlyData, &mut send_data),
+                    buffer(MemoryRegionType::ReadWriteData, &mut recv_data),
+                )
+                .is_ok()
+        );
+        unittest::assert_true!(initiator.channel_async_cancel(Arch).is_ok());
+
+        // The handler no longer sees the transaction and the initiator may
+        // start another one.
+        unittest::assert_matches!(
+            handler.object_wait(Arch, Signals::READABLE, Instant::MIN),
+            Err(Error::DeadlineExceeded)
+        );
+        let wait_return =
+            unittest::unwrap!(initiator.object_wait(Arch, Signals::WRITEABLE, Instant::MIN));
+        unittest::assert_true!(wait_return.pending_signals.contains(Signals::WRITEABLE));
+        unittest::assert_false!(wait_return.pending_signals.contains(Signals::READABLE));
+        unittest::assert_true!(
+            initiator
+                .channel_async_transact(
+                    Arch,
+                    buffer(MemoryRegionType::ReadOnlyData, &mut send_data),
+                    buffer(MemoryRegionType::ReadWriteData, &mut recv_data),
+                )
+                .is_ok()
+        );
+
+        Ok(())
+    }
+    fn respond_entry(kernel: Arch, handler: *const ChannelHandlerObject<Arch>) {
+        // SAFETY: `static_foreign_rc!` allocates the handler statically.
+        let handler = unsafe { &*handler };
+        if handler
+            .object_wait(kernel, Signals::READABLE, Instant::MAX)
+            .is_ok()
+        {
+            let mut response = [7u8];
+            let _ = handler.channel_respond(
+                kernel,
+                buffer(MemoryRegionType::ReadOnlyData, &mut response),
+            );
+        }
+    }
+
+    #[test]
+    fn transact_leaves_peer_user_signal_pending() -> unittest::Result<()> {
+        let handler = unsafe {
+            static_foreign_rc!(
+                AtomicUsize,
+                ChannelHandlerObject<Arch>,
+                ChannelHandlerObject::new(Arch)
+            )
+        };
+        let initiator = unsafe {
+            static_foreign_rc!(
+                AtomicUsize,
+                ChannelInitiatorObject<Arch>,
+                ChannelInitiatorObject::new(handler.clone())
+            )
+        };
+        handler.set_initiator(Arch, initiator.clone());
+
+        unittest::assert_true!(handler.object_raise_peer_user_signal(Arch).is_ok());
+
+        let responder = start_thread!(
+            "channel responder",
+            Priority::Level25,
+            respond_entry,
+            core::ptr::from_ref::<ChannelHandlerObject<Arch>>(&handler)
+        );
+
+        let mut send_data = [0u8; 4];
+        let mut recv_data = [0u8; 4];
+        unittest::assert_eq!(
+            initiator.channel_transact(
+                Arch,
+                buffer(MemoryRegionType::ReadOnlyData, &mut send_data),
+                buffer(MemoryRegionType::ReadWriteData, &mut recv_data),
+                Instant::MAX,
+            ),
+            Ok(1)
+        );
+        unittest::unwrap!(responder.join(Arch)).consume();
+
+        // The transaction's internal wait did not consume `Signals::USER`.
+        let wait_return =
+            unittest::unwrap!(initiator.object_wait(Arch, Signals::USER, Instant::MIN));
+        unittest::assert_true!(wait_return.pending_signals.contains(Signals::USER));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "arch_arm_cortex_m")]
+    use arch_arm_cortex_m::Arch;
+    #[cfg(feature = "arch_host")]
+    use arch_host::HostArch as Arch;
+    #[cfg(feature = "arch_riscv")]
+    use arch_riscv::Arch;
+    use kernel::__private::foreign_box::static_foreign_rc;
+    use kernel::object::{KernelObject, TimerObject};
+    use kernel::{Arch as _, Duration, Instant};
+    use pw_status::Error;
+    use syscall_defs::Signals;
+    use unittest::test;
+
+    type AtomicUsize = <Arch as kernel::Arch>::AtomicUsize;
+
+    fn sleep_for(duration: Duration<<Arch as kernel::Arch>::Clock>) -> unittest::Result<()> {
+        unittest::assert_true!(kernel::sleep_until(Arch, Arch.now() + duration).is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn timer_object_reports_each_expiry_once() -> unittest::Result<()> {
+        let timer =
+            unsafe { static_foreign_rc!(AtomicUsize, TimerObject<Arch>, TimerObject::new()) };
+
+        // The timer can not be set until the object has been attached.
+        unittest::assert_matches!(
+            timer.timer_set(Arch, Arch.now(), None),
+            Err(Error::FailedPrecondition)
+        );
+        // SAFETY: `static_foreign_rc!` allocates the object statically.
+        unsafe { timer.attach(Arch) };
+
+        unittest::assert_matches!(
+            timer.timer_set(Arch, Arch.now(), Some(Duration::from_ticks(0))),
+            Err(Error::InvalidArgument)
+        );
+
+        // Nothing is reported before the timer fires.
+        unittest::assert_true!(
+            timer
+                .timer_set(Arch, Arch.now() + Duration::from_millis(5), None)
+                .is_ok()
+        );
+        unittest::assert_matches!(
+            timer.object_wait(Arch, Signals::READABLE, Instant::MIN),
+            Err(Error::DeadlineExceeded)
+        );
+
+        // Waiting for the expiry returns it once and then clears it.
+        let wait_return = unittest::unwrap!(timer.object_wait(
+            Arch,
+            Signals::READABLE,
+            Arch.now() + Duration::from_millis(100)
+        ));
+        unittest::assert_true!(wait_return.pending_signals.contains(Signals::READABLE));
+        unittest::assert_matches!(
+            timer.object_wait(Arch, Signals::READABLE, Instant::MIN),
+            Err(Error::DeadlineExceeded)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn cancelled_timer_object_does_not_fire() -> unittest::Result<()> {
+        let timer =
+            unsafe { static_foreign_rc!(AtomicUsize, TimerObject<Arch>, TimerObject::new()) };
+        // SAFETY: `static_foreign_rc!` allocates the object statically.
+        unsafe { timer.attach(Arch) };
+
+        let period = Duration::from_millis(2);
+        unittest::assert_true!(
+            timer
+                .timer_set(Arch, Arch.now() + period, Some(period))
+                .is_ok()
+        );
+        let wait_return = unittest::unwrap!(timer.object_wait(
+            Arch,
+            Signals::READABLE,
+            Arch.now() + Duration::from_millis(100)
+        ));
+        unittest::assert_true!(wait_return.pending_signals.contains(Signals::READABLE));
+
+        unittest::assert_true!(timer.timer_cancel(Arch).is_ok());
+        // Drop an expiry which raced with the cancel.
+        let _ = timer.object_wait(Arch, Signals::READABLE, Instant::MIN);
+
+        sleep_for(Duration::from_millis(10))?;
+        unittest::assert_matches!(
+            timer.object_wait(Arch, Signals::READABLE, Instant::MIN),
+            Err(Error::DeadlineExceeded)
+        );
+
+        Ok(())
+    }
+}
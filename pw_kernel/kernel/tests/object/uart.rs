@@ -0,0 +1,187 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "arch_arm_cortex_m")]
+    use arch_arm_cortex_m::Arch;
+    #[cfg(feature = "arch_host")]
+    use arch_host::HostArch as Arch;
+    #[cfg(feature = "arch_riscv")]
+    use arch_riscv::Arch;
+    use kernel::__private::foreign_box::static_foreign_rc;
+    use kernel::Instant;
+    use kernel::char_device::{CharDevice, CharDeviceListener};
+    use kernel::object::{KernelObject, UartObject};
+    use kernel::sync::spinlock::SpinLock;
+    use memory_config::MemoryRegionType;
+    use pw_status::{Error, Result};
+    use syscall_defs::Signals;
+    use unittest::test;
+
+    use crate::test_utils::buffer;
+
+    type AtomicUsize = <Arch as kernel::Arch>::AtomicUsize;
+
+    const WRITE_CAPACITY: usize = 4;
+
+    struct FakeDeviceState {
+        received: Option<u8>,
+        queued: usize,
+        listener: Option<&'static dyn CharDeviceListener<Arch>>,
+    }
+
+    /// A device which holds at most one received byte and accepts up to
+    /// `WRITE_CAPACITY` bytes before it has to be drained.
+    struct FakeDevice {
+        state: SpinLock<Arch, FakeDeviceState>,
+    }
+
+    impl FakeDevice {
+        const fn new() -> Self {
+            Self {
+                state: SpinLock::new(FakeDeviceState {
+                    received: None,
+                    queued: 0,
+                    listener: None,
+                }),
+            }
+        }
+
+        fn receive(&self, byte: u8) {
+            let mut state = self.state.lock(Arch);
+            state.received = Some(byte);
+            let listener = state.listener;
+            drop(state);
+            if let Some(listener) = listener {
+                listener.readable(Arch);
+            }
+        }
+
+        fn drain(&self) {
+            let mut state = self.state.lock(Arch);
+            state.queued = 0;
+            let listener = state.listener;
+            drop(state);
+            if let Some(listener) = listener {
+                listener.writable(Arch);
+            }
+        }
+    }
+
+    impl CharDevice<Arch> for FakeDevice {
+        fn read(&self, kernel: Arch, buffer: &mut [u8]) -> Result<usize> {
+            let mut state = self.state.lock(kernel);
+            match (buffer.first_mut(), state.received) {
+                (Some(first), Some(byte)) => {
+                    *first = byte;
+                    state.received = None;
+                    Ok(1)
+                }
+                _ => Ok(0),
+            }
+        }
+
+        fn write(&self, kernel: Arch, buffer: &[u8]) -> Result<usize> {
+            let mut state = self.state.lock(kernel);
+            let len = buffer.len().min(WRITE_CAPACITY - state.queued);
+            state.queued += len;
+            Ok(len)
+        }
+
+        fn set_listener(&self, kernel: Arch, listener: &'static dyn CharDeviceListener<Arch>) {
+            self.state.lock(kernel).listener = Some(listener);
+        }
+    }
+
+    #[test]
+    fn readable_tracks_received_data() -> unittest::Result<()> {
+        static DEVICE: FakeDevice = FakeDevice::new();
+        let uart =
+            unsafe { static_foreign_rc!(AtomicUsize, UartObject<Arch>, UartObject::new(&DEVICE)) };
+        // SAFETY: `static_foreign_rc!` allocates the object statically.
+        unsafe { uart.attach(Arch) };
+
+        // Nothing has been received, so reading clears `Signals::READABLE`.
+        let mut data = [0u8; 2];
+        unittest::assert_eq!(
+            uart.uart_read(Arch, buffer(MemoryRegionType::ReadWriteData, &mut data)),
+            Ok(0)
+        );
+        unittest::assert_matches!(
+            uart.object_wait(Arch, Signals::READABLE, Instant::MIN),
+            Err(Error::DeadlineExceeded)
+        );
+
+        DEVICE.receive(0x5a);
+        let wait_return =
+            unittest::unwrap!(uart.object_wait(Arch, Signals::READABLE, Instant::MIN));
+        unittest::assert_true!(wait_return.pending_signals.contains(Signals::READABLE));
+
+        unittest::assert_eq!(
+            uart.uart_read(Arch, buffer(MemoryRegionType::ReadWriteData, &mut data)),
+            Ok(1)
+        );
+        unittest::assert_eq!(data[0], 0x5a);
+        unittest::assert_matches!(
+            uart.object_wait(Arch, Signals::READABLE, Instant::MIN),
+            Err(Error::DeadlineExceeded)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn writeable_tracks_write_buffer_space() -> unittest::Result<()> {
+        static DEVICE: FakeDevice = FakeDevice::new();
+        let uart =
+            unsafe { static_foreign_rc!(AtomicUsize, UartObject<Arch>, UartObject::new(&DEVICE)) };
+        // SAFETY: `static_foreign_rc!` allocates the object statically.
+        unsafe { uart.attach(Arch) };
+
+        let mut data = [0u8; WRITE_CAPACITY + 2];
+        unittest::assert_eq!(
+            uart.uart_write(Arch, buffer(MemoryRegionType::ReadOnlyData, &mut data)),
+            Ok(WRITE_CAPACITY)
+        );
+        unittest::assert_matches!(
+            uart.object_wait(Arch, Signals::WRITEABLE, Instant::MIN),
+            Err(Error::DeadlineExceeded)
+        );
+
+        DEVICE.drain();
+        let wait_return =
+            unittest::unwrap!(uart.object_wait(Arch, Signals::WRITEABLE, Instant::MIN));
+        unittest::assert_true!(wait_return.pending_signals.contains(Signals::WRITEABLE));
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_requires_writeable_buffer() -> unittest::Result<()> {
+        static DEVICE: FakeDevice = FakeDevice::new();
+        let uart =
+            unsafe { static_foreign_rc!(AtomicUsize, UartObject<Arch>, UartObject::new(&DEVICE)) };
+        // SAFETY: `static_foreign_rc!` allocates the object statically.
+        unsafe { uart.attach(Arch) };
+
+        let mut data = [0u8; 2];
+        unittest::assert_eq!(
+            uart.uart_read(Arch, buffer(MemoryRegionType::ReadOnlyData, &mut data)),
+            Err(Error::PermissionDenied)
+        );
+
+        Ok(())
+    }
+}
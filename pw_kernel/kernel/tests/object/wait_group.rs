@@ -0,0 +1,154 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "arch_arm_cortex_m")]
+    use arch_arm_cortex_m::Arch;
+    #[cfg(feature = "arch_host")]
+    use arch_host::HostArch as Arch;
+    #[cfg(feature = "arch_riscv")]
+    use arch_riscv::Arch;
+    use kernel::__private::foreign_box::{ForeignRc, static_foreign_rc, upcast_foreign_rc};
+    use kernel::Instant;
+    use kernel::object::{InterruptObject, KernelObject, WaitGroupObject};
+    use pw_status::Error;
+    use syscall_defs::{Signals, WaitReturn};
+    use unittest::test;
+
+    type AtomicUsize = <Arch as kernel::Arch>::AtomicUsize;
+    type ObjectRef = ForeignRc<AtomicUsize, dyn KernelObject<Arch>>;
+
+    fn ack_irqs(_signal_mask: Signals) {}
+
+    #[test]
+    fn wait_returns_user_data_of_signaled_member() -> unittest::Result<()> {
+        let wait_group = unsafe {
+            static_foreign_rc!(AtomicUsize, WaitGroupObject<Arch>, WaitGroupObject::new())
+        };
+        let object_a = unsafe {
+            static_foreign_rc!(
+                AtomicUsize,
+                InterruptObject<Arch>,
+                InterruptObject::new(ack_irqs)
+            )
+        };
+        let object_b = unsafe {
+            static_foreign_rc!(
+                AtomicUsize,
+                InterruptObject<Arch>,
+                InterruptObject::new(ack_irqs)
+            )
+        };
+
+        let a: ObjectRef = upcast_foreign_rc!(object_a.clone() => dyn KernelObject<Arch>);
+        let b: ObjectRef = upcast_foreign_rc!(object_b.clone() => dyn KernelObject<Arch>);
+        unittest::assert_true!(
+            wait_group
+                .wait_group_add(Arch, a, Signals::INTERRUPT_A, 1)
+                .is_ok()
+        );
+        unittest::assert_true!(
+            wait_group
+                .wait_group_add(Arch, b, Signals::INTERRUPT_A, 2)
+                .is_ok()
+        );
+
+        object_b.interrupt(Arch, Signals::INTERRUPT_A);
+
+        let wait_return =
+            unittest::unwrap!(wait_group.object_wait(Arch, Signals::READABLE, Instant::MAX));
+        unittest::assert_eq!(wait_return.user_data, 2);
+        unittest::assert_eq!(
+            wait_return.pending_signals.bits(),
+            Signals::INTERRUPT_A.bits()
+        );
+
+        // Once the member's signal is cleared, waiting times out.
+        unittest::assert_true!(object_b.interrupt_ack(Arch, Signals::INTERRUPT_A).is_ok());
+        unittest::assert_matches!(
+            wait_group.object_wait(Arch, Signals::READABLE, Instant::MIN),
+            Err(Error::DeadlineExceeded)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn object_can_only_be_in_one_wait_group() -> unittest::Result<()> {
+        let wait_group_a = unsafe {
+            static_foreign_rc!(AtomicUsize, WaitGroupObject<Arch>, WaitGroupObject::new())
+        };
+        let wait_group_b = unsafe {
+            static_foreign_rc!(AtomicUsize, WaitGroupObject<Arch>, WaitGroupObject::new())
+        };
+        let object = unsafe {
+            static_foreign_rc!(
+                AtomicUsize,
+                InterruptObject<Arch>,
+                InterruptObject::new(ack_irqs)
+            )
+        };
+        let object: ObjectRef = upcast_foreign_rc!(object => dyn KernelObject<Arch>);
+
+        unittest::assert_true!(
+            wait_group_a
+                .wait_group_add(Arch, object.clone(), Signals::INTERRUPT_A, 0)
+                .is_ok()
+        );
+        unittest::assert_matches!(
+            wait_group_b.wait_group_add(Arch, object.clone(), Signals::INTERRUPT_A, 0),
+            Err(Error::ResourceExhausted)
+        );
+        unittest::assert_matches!(
+            wait_group_b.wait_group_remove(Arch, object.clone()),
+            Err(Error::NotFound)
+        );
+        unittest::assert_true!(wait_group_a.wait_group_remove(Arch, object.clone()).is_ok());
+        unittest::assert_true!(
+            wait_group_b
+                .wait_group_add(Arch, object.clone(), Signals::INTERRUPT_A, 0)
+                .is_ok()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn user_data_is_limited_to_max() -> unittest::Result<()> {
+        let wait_group = unsafe {
+            static_foreign_rc!(AtomicUsize, WaitGroupObject<Arch>, WaitGroupObject::new())
+        };
+        let object = unsafe {
+            static_foreign_rc!(
+                AtomicUsize,
+                InterruptObject<Arch>,
+                InterruptObject::new(ack_irqs)
+            )
+        };
+        let object: ObjectRef = upcast_foreign_rc!(object => dyn KernelObject<Arch>);
+
+        unittest::assert_matches!(
+            wait_group.wait_group_add(
+                Arch,
+                object,
+                Signals::INTERRUPT_A,
+                WaitReturn::MAX_USER_DATA + 1
+            ),
+            Err(Error::InvalidArgument)
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,179 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "arch_arm_cortex_m")]
+    use arch_arm_cortex_m::Arch;
+    #[cfg(feature = "arch_host")]
+    use arch_host::HostArch as Arch;
+    #[cfg(feature = "arch_riscv")]
+    use arch_riscv::Arch;
+    use foreign_box::ForeignBox;
+    use kernel::scheduler::algorithm::{
+        EdfScheduler, PriorityScheduler, RateMonotonicScheduler, RescheduleReason,
+        SchedulerAlgorithm,
+    };
+    use kernel::scheduler::stats::{ThreadSnapshot, snapshot_threads};
+    use kernel::scheduler::thread::{State, Thread};
+    use kernel::scheduler::watchdog;
+    use kernel::{Arch as _, Duration, Instant, Priority};
+    use unittest::test;
+
+    // Returns a thread backed by static storage.  The thread is never
+    // initialized or run; it is only passed through the algorithm's queues.
+    //
+    // Each expansion must be executed at most once at run time.
+    macro_rules! static_thread {
+        ($name:literal, $priority:expr) => {
+            ForeignBox::from(unsafe {
+                kernel::static_mut_ref!(Thread<Arch> = Thread::new($name, $priority))
+            })
+        };
+    }
+
+    fn next_thread_name<A: SchedulerAlgorithm<Arch>>(algorithm: &mut A) -> Option<&'static str> {
+        let thread = algorithm.get_next_thread()?;
+        let name = thread.name;
+        let _ = thread.consume();
+        Some(name)
+    }
+
+    #[test]
+    fn ticks_rotate_thread_once_timeslice_is_consumed() -> unittest::Result<()> {
+        let mut algorithm = PriorityScheduler::<Arch>::new();
+        let mut long = static_thread!("long", Priority::DEFAULT_PRIORITY);
+        long.set_timeslice_ticks(2);
+        let short = static_thread!("short", Priority::DEFAULT_PRIORITY);
+
+        algorithm.schedule_thread(Arch, long, RescheduleReason::Started);
+        algorithm.schedule_thread(Arch, short, RescheduleReason::Started);
+
+        let long = unittest::unwrap!(algorithm.get_next_thread().ok_or(()));
+        unittest::assert_eq!(long.name, "long");
+
+        // The first tick leaves one tick of the timeslice.
+        algorithm.schedule_thread(Arch, long, RescheduleReason::Ticked);
+        let long = unittest::unwrap!(algorithm.get_next_thread().ok_or(()));
+        unittest::assert_eq!(long.name, "long");
+
+        // The second tick consumes the timeslice.
+        algorithm.schedule_thread(Arch, long, RescheduleReason::Ticked);
+        unittest::assert_eq!(next_thread_name(&mut algorithm), Some("short"));
+        unittest::assert_eq!(next_thread_name(&mut algorithm), Some("long"));
+        unittest::assert_eq!(next_thread_name(&mut algorithm), None);
+        Ok(())
+    }
+
+    #[test]
+    fn yield_forfeits_remaining_timeslice() -> unittest::Result<()> {
+        let mut algorithm = PriorityScheduler::<Arch>::new();
+        let mut yielder = static_thread!("yielder", Priority::DEFAULT_PRIORITY);
+        yielder.set_timeslice_ticks(3);
+        let other = static_thread!("other", Priority::DEFAULT_PRIORITY);
+
+        algorithm.schedule_thread(Arch, yielder, RescheduleReason::Started);
+        algorithm.schedule_thread(Arch, other, RescheduleReason::Started);
+
+        let yielder = unittest::unwrap!(algorithm.get_next_thread().ok_or(()));
+        unittest::assert_eq!(yielder.name, "yielder");
+        algorithm.schedule_thread(Arch, yielder, RescheduleReason::Yielded);
+
+        unittest::assert_eq!(next_thread_name(&mut algorithm), Some("other"));
+        unittest::assert_eq!(next_thread_name(&mut algorithm), Some("yielder"));
+        Ok(())
+    }
+
+    #[test]
+    fn edf_runs_earliest_deadline_first() -> unittest::Result<()> {
+        let mut algorithm = EdfScheduler::<Arch>::new();
+        let background = static_thread!("background", Priority::Level25);
+        let mut late = static_thread!("late", Priority::DEFAULT_PRIORITY);
+        late.set_relative_deadline(Duration::from_millis(100));
+        let mut early = static_thread!("early", Priority::DEFAULT_PRIORITY);
+        early.set_relative_deadline(Duration::from_millis(10));
+
+        algorithm.schedule_thread(Arch, background, RescheduleReason::Started);
+        algorithm.schedule_thread(Arch, late, RescheduleReason::Started);
+        algorithm.schedule_thread(Arch, early, RescheduleReason::Started);
+
+        // Threads without a deadline only run once no thread with a deadline
+        // is ready, regardless of priority.
+        unittest::assert_eq!(next_thread_name(&mut algorithm), Some("early"));
+        unittest::assert_eq!(next_thread_name(&mut algorithm), Some("late"));
+        unittest::assert_eq!(next_thread_name(&mut algorithm), Some("background"));
+        unittest::assert_eq!(next_thread_name(&mut algorithm), None);
+        Ok(())
+    }
+
+    #[test]
+    fn rate_monotonic_runs_shortest_period_first() -> unittest::Result<()> {
+        let mut algorithm = RateMonotonicScheduler::<Arch>::new();
+        let background = static_thread!("background", Priority::Level25);
+        let mut slow = static_thread!("slow", Priority::Level20);
+        slow.set_relative_deadline(Duration::from_millis(100));
+        let mut fast = static_thread!("fast", Priority::Level1);
+        fast.set_relative_deadline(Duration::from_millis(10));
+
+        algorithm.schedule_thread(Arch, background, RescheduleReason::Started);
+        algorithm.schedule_thread(Arch, slow, RescheduleReason::Started);
+        algorithm.schedule_thread(Arch, fast, RescheduleReason::Started);
+
+        let fast = unittest::unwrap!(algorithm.get_next_thread().ok_or(()));
+        unittest::assert_eq!(fast.name, "fast");
+
+        // Base priority has no effect on threads with a period, so waking the
+        // thread again puts it back ahead of the longer period.
+        algorithm.schedule_thread(Arch, fast, RescheduleReason::Woken);
+        unittest::assert_eq!(next_thread_name(&mut algorithm), Some("fast"));
+        unittest::assert_eq!(next_thread_name(&mut algorithm), Some("slow"));
+        unittest::assert_eq!(next_thread_name(&mut algorithm), Some("background"));
+        unittest::assert_eq!(next_thread_name(&mut algorithm), None);
+        Ok(())
+    }
+
+    #[test]
+    fn snapshot_accounts_for_running_thread() -> unittest::Result<()> {
+        // Sleeping switches to another thread and back, guaranteeing at least
+        // one context switch onto the current thread.
+        unittest::assert_true!(
+            kernel::sleep_until(Arch, Arch.now() + Duration::from_millis(1)).is_ok()
+        );
+
+        let mut snapshots: [Option<ThreadSnapshot<Arch>>; 16] = [None; 16];
+        let count = snapshot_threads(Arch, &mut snapshots);
+        unittest::assert_true!(count > 0);
+
+        let running = unittest::unwrap!(
+            snapshots
+                .iter()
+                .flatten()
+                .find(|snapshot| snapshot.state == State::Running)
+                .ok_or(())
+        );
+        unittest::assert_true!(running.context_switches > 0);
+        unittest::assert_true!(running.run_time > Duration::from_secs(0));
+        Ok(())
+    }
+
+    #[test]
+    fn unwatched_threads_are_never_overdue() -> unittest::Result<()> {
+        // The test thread has no watchdog interval so checking in has no
+        // effect and it is never reported, no matter how much time passes.
+        watchdog::check_in(Arch);
+        unittest::assert_true!(watchdog::find_overdue_thread(Arch, Arch.now()).is_none());
+        unittest::assert_true!(watchdog::find_overdue_thread(Arch, Instant::MAX).is_none());
+        Ok(())
+    }
+}
@@ -0,0 +1,289 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+#[cfg(test)]
+mod tests {
+    use core::fmt::Write;
+    use core::sync::atomic::{AtomicBool, Ordering};
+
+    #[cfg(feature = "arch_arm_cortex_m")]
+    use arch_arm_cortex_m::Arch;
+    #[cfg(feature = "arch_host")]
+    use arch_host::HostArch as Arch;
+    #[cfg(feature = "arch_riscv")]
+    use arch_riscv::Arch;
+    use kernel::Priority;
+    use kernel::char_device::{CharDevice, CharDeviceListener};
+    use kernel::sync::spinlock::SpinLock;
+    use pw_status::{Error, Result};
+    use shell::{Args, Command, Output, Shell};
+    use unittest::test;
+
+    use crate::test_utils::start_thread;
+
+    const TEXT_SIZE: usize = 128;
+
+    /// Bytes written with `core::fmt`.
+    struct Text {
+        bytes: [u8; TEXT_SIZE],
+        len: usize,
+    }
+
+    impl Text {
+        const fn new() -> Self {
+            Self {
+                bytes: [0; TEXT_SIZE],
+                len: 0,
+            }
+        }
+
+        fn format(args: core::fmt::Arguments<'_>) -> Self {
+            let mut text = Self::new();
+            let _ = text.write_fmt(args);
+            text
+        }
+
+        fn as_bytes(&self) -> &[u8] {
+            &self.bytes[..self.len]
+        }
+
+        fn push(&mut self, bytes: &[u8]) -> usize {
+            let len = bytes.len().min(TEXT_SIZE - self.len);
+            self.bytes[self.len..self.len + len].copy_from_slice(&bytes[..len]);
+            self.len += len;
+            len
+        }
+    }
+
+    impl Write for Text {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            if self.push(s.as_bytes()) < s.len() {
+                return Err(core::fmt::Error);
+            }
+            Ok(())
+        }
+    }
+
+    struct FakeConsoleState {
+        input: Text,
+        read: usize,
+        output: Text,
+        listener: Option<&'static dyn CharDeviceListener<Arch>>,
+    }
+
+    /// A console which replays typed input and captures everything written
+    /// to it.
+    struct FakeConsole {
+        state: SpinLock<Arch, FakeConsoleState>,
+    }
+
+    impl FakeConsole {
+        const fn new() -> Self {
+            Self {
+                state: SpinLock::new(FakeConsoleState {
+                    input: Text::new(),
+                    read: 0,
+                    output: Text::new(),
+                    listener: None,
+                }),
+            }
+        }
+
+        /// Types `input` into the shell and returns what the shell writes in
+        /// response.
+        ///
+        /// The shell runs at a higher priority than the tests, so it handles
+        /// all of `input` before this returns.
+        fn type_input(&self, input: core::fmt::Arguments<'_>) -> Text {
+            let mut state = self.state.lock(Arch);
+            state.input = Text::format(input);
+            state.read = 0;
+            state.output = Text::new();
+            let listener = state.listener;
+            drop(state);
+            if let Some(listener) = listener {
+                listener.readable(Arch);
+            }
+
+            core::mem::replace(&mut self.state.lock(Arch).output, Text::new())
+        }
+    }
+
+    impl CharDevice<Arch> for FakeConsole {
+        fn read(&self, kernel: Arch, buffer: &mut [u8]) -> Result<usize> {
+            let mut state = self.state.lock(kernel);
+            let unread = &state.input.as_bytes()[state.read..];
+            let len = buffer.len().min(unread.len());
+            buffer[..len].copy_from_slice(&unread[..len]);
+            state.read += len;
+            Ok(len)
+        }
+
+        fn write(&self, kernel: Arch, buffer: &[u8]) -> Result<usize> {
+            // Output beyond the capture size is dropped rather than blocking
+            // the shell.
+            self.state.lock(kernel).output.push(buffer);
+            Ok(buffer.len())
+        }
+
+        fn set_listener(&self, kernel: Arch, listener: &'static dyn CharDeviceListener<Arch>) {
+            self.state.lock(kernel).listener = Some(listener);
+        }
+    }
+
+    fn echo(_kernel: Arch, out: &mut Output<'_>, args: &mut Args<'_>) -> Result<()> {
+        let mut separator = "";
+        for arg in args {
+            let _ = write!(out, "{separator}{arg}");
+            separator = " ";
+        }
+        let _ = writeln!(out);
+        Ok(())
+    }
+
+    fn fail(_kernel: Arch, _out: &mut Output<'_>, _args: &mut Args<'_>) -> Result<()> {
+        Err(Error::Unavailable)
+    }
+
+    static COMMANDS: [Command<Arch>; 2] = [
+        Command {
+            name: "echo",
+            help: "Echoes its arguments",
+            handler: echo,
+        },
+        Command {
+            name: "fail",
+            help: "Always fails",
+            handler: fail,
+        },
+    ];
+
+    // The tests never run `reboot`.
+    fn reset() -> ! {
+        #[expect(clippy::empty_loop)]
+        loop {}
+    }
+
+    static CONSOLE: FakeConsole = FakeConsole::new();
+    static SHELL: Shell<Arch> = Shell::new(Arch, &CONSOLE, &COMMANDS, &[], reset);
+
+    fn shell_entry(kernel: Arch, shell: &'static Shell<Arch>) {
+        let _ = shell.run(kernel);
+    }
+
+    /// Returns the console of a shell shared by all tests, starting the
+    /// shell on first use.
+    fn console() -> &'static FakeConsole {
+        static STARTED: AtomicBool = AtomicBool::new(false);
+        if !STARTED.swap(true, Ordering::SeqCst) {
+            // The shell outlives the tests, blocked waiting for input.
+            let _ = start_thread!("shell", Priority::Level25, shell_entry, &SHELL);
+        }
+        &CONSOLE
+    }
+
+    #[test]
+    fn backspace_erases_the_last_character() -> unittest::Result<()> {
+        let output = console().type_input(format_args!("echo hellp\x08o\r"));
+        unittest::assert_eq!(output.as_bytes(), b"echo hellp\x08 \x08o\r\nhello\r\n> ");
+
+        // Backspace on an empty line is ignored.
+        let output = console().type_input(format_args!("\x7f\r"));
+        unittest::assert_eq!(output.as_bytes(), b"\r\n> ");
+
+        Ok(())
+    }
+
+    #[test]
+    fn commands_are_dispatched_by_name() -> unittest::Result<()> {
+        let output = console().type_input(format_args!("  echo  a b\r\n"));
+        unittest::assert_eq!(output.as_bytes(), b"  echo  a b\r\na b\r\n> ");
+
+        let output = console().type_input(format_args!("fail\r"));
+        unittest::assert_eq!(
+            output.as_bytes(),
+            b"fail\r\nfail: failed with Unavailable\r\n> "
+        );
+
+        let output = console().type_input(format_args!("bogus\r"));
+        unittest::assert_eq!(
+            output.as_bytes(),
+            b"bogus\r\nUnknown command 'bogus', try 'help'\r\n> "
+        );
+
+        Ok(())
+    }
+
+    fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+        haystack
+            .windows(needle.len())
+            .any(|window| window == needle)
+    }
+
+    #[test]
+    fn thread_commands_write_to_the_shell() -> unittest::Result<()> {
+        // The capture only holds the start of the output.
+        let output = console().type_input(format_args!("threads\r"));
+        unittest::assert_true!(output.as_bytes().starts_with(b"threads\r\n0x"));
+        unittest::assert_true!(contains(output.as_bytes(), b" priority "));
+
+        let output = console().type_input(format_args!("cpu\r"));
+        unittest::assert_true!(output.as_bytes().starts_with(b"cpu\r\nCPU usage over "));
+        unittest::assert_true!(contains(output.as_bytes(), b"% run "));
+
+        Ok(())
+    }
+
+    static MEMORY: [u8; 4] = [0xde, 0xad, 0xbe, 0xef];
+
+    #[test]
+    fn mem_parses_decimal_and_hexadecimal_arguments() -> unittest::Result<()> {
+        let addr = MEMORY.as_ptr().expose_provenance();
+
+        let output = console().type_input(format_args!("mem {addr:#x} 0x4\r"));
+        let expected = Text::format(format_args!(
+            "mem {addr:#x} 0x4\r\n{addr:#010x}: de ad be ef\r\n> "
+        ));
+        unittest::assert_eq!(output.as_bytes(), expected.as_bytes());
+
+        let output = console().type_input(format_args!("mem {addr} 2\r"));
+        let expected = Text::format(format_args!("mem {addr} 2\r\n{addr:#010x}: de ad\r\n> "));
+        unittest::assert_eq!(output.as_bytes(), expected.as_bytes());
+
+        Ok(())
+    }
+
+    #[test]
+    fn mem_rejects_invalid_arguments() -> unittest::Result<()> {
+        let output = console().type_input(format_args!("mem 0xzz 4\r"));
+        unittest::assert_eq!(
+            output.as_bytes(),
+            b"mem 0xzz 4\r\nmem: failed with InvalidArgument\r\n> "
+        );
+
+        let output = console().type_input(format_args!("mem 16\r"));
+        unittest::assert_eq!(
+            output.as_bytes(),
+            b"mem 16\r\nmem: failed with InvalidArgument\r\n> "
+        );
+
+        let output = console().type_input(format_args!("mem 16 257\r"));
+        unittest::assert_eq!(
+            output.as_bytes(),
+            b"mem 16 257\r\nmem: failed with OutOfRange\r\n> "
+        );
+
+        Ok(())
+    }
+}
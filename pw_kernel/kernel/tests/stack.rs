@@ -15,10 +15,20 @@
 #[cfg(test)]
 mod tests {
     use core::mem::MaybeUninit;
+    use core::sync::atomic::{AtomicBool, Ordering};
 
-    use kernel::scheduler::thread::{Stack, StackStorage};
+    #[cfg(feature = "arch_arm_cortex_m")]
+    use arch_arm_cortex_m::Arch;
+    #[cfg(feature = "arch_host")]
+    use arch_host::HostArch as Arch;
+    #[cfg(feature = "arch_riscv")]
+    use arch_riscv::Arch;
+    use kernel::scheduler::thread::{self, Stack, StackStorage, StackStorageExt as _, Thread};
+    use kernel::{Arch as _, Duration, Priority};
     use unittest::test;
 
+    use crate::test_utils::STACK_SIZE;
+
     #[test]
     fn stack_initialize_fills_stack_with_pattern() -> unittest::Result<()> {
         const STACK_SIZE: usize = 1024;
@@ -42,4 +52,78 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn stack_high_water_mark_tracks_deepest_write() -> unittest::Result<()> {
+        const STACK_SIZE: usize = 256;
+        let mut storage = StackStorage::<STACK_SIZE> {
+            stack: [MaybeUninit::uninit(); STACK_SIZE],
+        };
+
+        let stack = Stack::from_slice(&mut storage.stack);
+        stack.initialize();
+        unittest::assert_eq!(stack.size(), STACK_SIZE);
+        unittest::assert_eq!(stack.high_water_mark(), 0);
+        unittest::assert_true!(stack.is_guard_intact());
+
+        let words = storage.stack.as_mut_ptr() as *mut u32;
+        let word_count = STACK_SIZE / size_of::<u32>();
+
+        // Stacks grow down so using 64 bytes writes the highest 16 words.
+        unsafe { words.add(word_count - 16).write_volatile(0) };
+        unittest::assert_eq!(stack.high_water_mark(), 64);
+        unittest::assert_true!(stack.is_guard_intact());
+
+        // Writing the lowest word of the stack overwrites the guard.
+        unsafe { words.write_volatile(0) };
+        unittest::assert_eq!(stack.high_water_mark(), STACK_SIZE);
+        unittest::assert_false!(stack.is_guard_intact());
+
+        // Re-initializing the stack resets its usage.
+        stack.initialize();
+        unittest::assert_eq!(stack.high_water_mark(), 0);
+        unittest::assert_true!(stack.is_guard_intact());
+
+        Ok(())
+    }
+
+    static OVERFLOWED_THREAD_RESUMED: AtomicBool = AtomicBool::new(false);
+
+    fn overflow_entry(kernel: Arch, lowest_word: *mut u32) {
+        // Stand in for the thread running off the bottom of its stack.
+        unsafe { lowest_word.write_volatile(0) };
+
+        // The overflow is detected when the thread is switched away from and
+        // it exits, rather than resuming, once it is switched back to.
+        let _ = kernel::sleep_until(kernel, kernel.now() + Duration::from_millis(1));
+        OVERFLOWED_THREAD_RESUMED.store(true, Ordering::SeqCst);
+    }
+
+    #[test]
+    fn overflowed_thread_exits_instead_of_resuming() -> unittest::Result<()> {
+        let thread = unsafe {
+            kernel::static_mut_ref!(Thread<Arch> = Thread::new("overflow", Priority::Level25))
+        };
+        let stack =
+            unsafe { kernel::static_mut_ref!(StackStorage<STACK_SIZE> = StackStorage::ZEROED) };
+        let lowest_word = stack.stack.as_mut_ptr().cast::<u32>();
+
+        // The higher priority thread runs until it sleeps.
+        let overflowed = kernel::start_thread(
+            Arch,
+            thread::init_thread_in(
+                Arch,
+                thread,
+                stack,
+                "overflow",
+                Priority::Level25,
+                overflow_entry,
+                lowest_word,
+            ),
+        );
+        unittest::unwrap!(overflowed.join(Arch)).consume();
+        unittest::assert_false!(OVERFLOWED_THREAD_RESUMED.load(Ordering::SeqCst));
+
+        Ok(())
+    }
 }
@@ -12,4 +12,8 @@
 // License for the specific language governing permissions and limitations under
 // the License.
 
+mod event_flags;
+mod futex;
+mod mutex;
+mod semaphore;
 mod spinlock;
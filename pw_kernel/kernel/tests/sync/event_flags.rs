@@ -0,0 +1,102 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+#[cfg(test)]
+mod tests {
+    use core::sync::atomic::{AtomicU32, Ordering};
+
+    #[cfg(feature = "arch_arm_cortex_m")]
+    use arch_arm_cortex_m::Arch;
+    #[cfg(feature = "arch_host")]
+    use arch_host::HostArch as Arch;
+    #[cfg(feature = "arch_riscv")]
+    use arch_riscv::Arch;
+    use kernel::sync::event_flags::{EventFlags, EventFlagsConfig, FlagsWait};
+    use kernel::{Arch as _, Duration, Priority};
+    use pw_status::Error;
+    use unittest::test;
+
+    use crate::test_utils::start_thread;
+
+    #[test]
+    fn wait_any_returns_set_flags() -> unittest::Result<()> {
+        let flags = EventFlags::<Arch>::new(Arch, EventFlagsConfig::ManualClear);
+        flags.set(0b0110);
+
+        unittest::assert_eq!(flags.wait(0b0011, FlagsWait::Any), Ok(0b0010));
+        unittest::assert_eq!(flags.get(), 0b0110);
+
+        flags.clear(0b0100);
+        unittest::assert_eq!(flags.get(), 0b0010);
+
+        Ok(())
+    }
+
+    #[test]
+    fn auto_clear_clears_matched_flags() -> unittest::Result<()> {
+        let flags = EventFlags::<Arch>::new(Arch, EventFlagsConfig::AutoClear);
+        flags.set(0b0111);
+
+        unittest::assert_eq!(flags.wait(0b0011, FlagsWait::All), Ok(0b0011));
+        unittest::assert_eq!(flags.get(), 0b0100);
+
+        Ok(())
+    }
+
+    #[test]
+    fn wait_until_times_out_and_rejects_empty_mask() -> unittest::Result<()> {
+        let flags = EventFlags::<Arch>::new(Arch, EventFlagsConfig::ManualClear);
+        flags.set(0b0001);
+
+        let deadline = Arch.now() + Duration::from_millis(10);
+        unittest::assert_eq!(
+            flags.wait_until(0b0011, FlagsWait::All, deadline),
+            Err(Error::DeadlineExceeded)
+        );
+        unittest::assert_eq!(flags.wait(0, FlagsWait::Any), Err(Error::InvalidArgument));
+
+        Ok(())
+    }
+
+    static ALL_FLAGS: EventFlags<Arch> = EventFlags::new(Arch, EventFlagsConfig::ManualClear);
+    static OBSERVED: AtomicU32 = AtomicU32::new(0);
+
+    fn wait_all_entry(_kernel: Arch, flags: &'static EventFlags<Arch>) {
+        if let Ok(observed) = flags.wait(0b0011, FlagsWait::All) {
+            OBSERVED.store(observed, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn wait_all_blocks_until_every_flag_is_set() -> unittest::Result<()> {
+        // The higher priority waiter runs as soon as it is started and blocks
+        // on the flags.
+        let waiter = start_thread!(
+            "event flags waiter",
+            Priority::Level25,
+            wait_all_entry,
+            &ALL_FLAGS
+        );
+
+        // Setting one flag wakes the waiter, which goes back to waiting.
+        ALL_FLAGS.set(0b0001);
+        unittest::assert_eq!(OBSERVED.load(Ordering::SeqCst), 0);
+
+        ALL_FLAGS.set(0b0010);
+        unittest::unwrap!(waiter.join(Arch)).consume();
+        unittest::assert_eq!(OBSERVED.load(Ordering::SeqCst), 0b0011);
+
+        Ok(())
+    }
+}
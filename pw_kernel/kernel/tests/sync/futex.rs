@@ -0,0 +1,77 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+#[cfg(test)]
+mod tests {
+    use core::sync::atomic::AtomicU32;
+
+    #[cfg(feature = "arch_arm_cortex_m")]
+    use arch_arm_cortex_m::Arch;
+    #[cfg(feature = "arch_host")]
+    use arch_host::HostArch as Arch;
+    #[cfg(feature = "arch_riscv")]
+    use arch_riscv::Arch;
+    use kernel::Instant;
+    use kernel::scheduler::futex::{futex_wait, futex_wake};
+    use pw_status::Error;
+    use unittest::test;
+
+    #[test]
+    fn wait_returns_unavailable_on_value_mismatch() -> unittest::Result<()> {
+        let futex = AtomicU32::new(1);
+
+        unittest::assert_matches!(
+            futex_wait(Arch, futex.as_ptr().expose_provenance(), 0, Instant::MAX),
+            Err(Error::Unavailable)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn wait_times_out_when_not_woken() -> unittest::Result<()> {
+        let futex = AtomicU32::new(1);
+
+        unittest::assert_matches!(
+            futex_wait(Arch, futex.as_ptr().expose_provenance(), 1, Instant::MIN),
+            Err(Error::DeadlineExceeded)
+        );
+
+        // The timed out thread is no longer waiting.
+        unittest::assert_eq!(
+            unittest::unwrap!(futex_wake(
+                Arch,
+                futex.as_ptr().expose_provenance(),
+                usize::MAX
+            )),
+            0
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn unaligned_address_is_rejected() -> unittest::Result<()> {
+        let futex = AtomicU32::new(0);
+        let addr = futex.as_ptr().expose_provenance() + 1;
+
+        unittest::assert_matches!(
+            futex_wait(Arch, addr, 0, Instant::MIN),
+            Err(Error::InvalidArgument)
+        );
+        unittest::assert_matches!(futex_wake(Arch, addr, 1), Err(Error::InvalidArgument));
+
+        Ok(())
+    }
+}
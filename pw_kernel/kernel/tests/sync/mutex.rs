@@ -0,0 +1,145 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+#[cfg(test)]
+mod tests {
+    use core::sync::atomic::{AtomicBool, Ordering};
+
+    #[cfg(feature = "arch_arm_cortex_m")]
+    use arch_arm_cortex_m::Arch;
+    #[cfg(feature = "arch_host")]
+    use arch_host::HostArch as Arch;
+    #[cfg(feature = "arch_riscv")]
+    use arch_riscv::Arch;
+    use kernel::sync::mutex::Mutex;
+    use kernel::{Duration, Kernel, Priority};
+    use pw_status::Error;
+    use unittest::test;
+
+    use crate::test_utils::start_thread;
+
+    fn current_priority() -> Priority {
+        Arch.get_scheduler()
+            .lock(Arch)
+            .current_thread()
+            .algorithm_state
+            .effective_priority()
+    }
+
+    fn lock_and_increment_entry(_kernel: Arch, mutex: &'static Mutex<Arch, u32>) {
+        *mutex.lock() += 1;
+    }
+
+    #[test]
+    fn holder_inherits_priority_of_waiter() -> unittest::Result<()> {
+        static MUTEX: Mutex<Arch, u32> = Mutex::new(Arch, 0);
+        let base_priority = current_priority();
+
+        let mut guard = MUTEX.lock();
+
+        // The higher priority waiter runs as soon as it is started and blocks
+        // on the mutex.
+        let waiter = start_thread!(
+            "mutex waiter",
+            Priority::Level25,
+            lock_and_increment_entry,
+            &MUTEX
+        );
+        unittest::assert_eq!(current_priority(), Priority::Level25);
+
+        *guard += 1;
+        drop(guard);
+
+        // Unlocking hands the mutex to the waiter, which runs to completion
+        // before this thread, and drops the inherited priority.
+        unittest::assert_eq!(current_priority(), base_priority);
+        unittest::unwrap!(waiter.join(Arch)).consume();
+        unittest::assert_eq!(*MUTEX.lock(), 2);
+
+        Ok(())
+    }
+
+    static OUTER: Mutex<Arch, ()> = Mutex::new(Arch, ());
+    static INNER: Mutex<Arch, ()> = Mutex::new(Arch, ());
+
+    fn lock_outer_then_inner_entry(_kernel: Arch, _arg: usize) {
+        let _outer = OUTER.lock();
+        let _inner = INNER.lock();
+    }
+
+    fn lock_outer_entry(_kernel: Arch, _arg: usize) {
+        let _outer = OUTER.lock();
+    }
+
+    #[test]
+    fn inheritance_is_transitive() -> unittest::Result<()> {
+        let base_priority = current_priority();
+
+        let inner = INNER.lock();
+
+        // `middle` takes `OUTER` then blocks on `INNER` held by this thread.
+        let middle = start_thread!(
+            "middle",
+            Priority::Level20,
+            lock_outer_then_inner_entry,
+            0usize
+        );
+        unittest::assert_eq!(current_priority(), Priority::Level20);
+
+        // `high` blocks on `OUTER` held by `middle` which in turn is blocked on
+        // this thread.
+        let high = start_thread!("high", Priority::Level25, lock_outer_entry, 0usize);
+        unittest::assert_eq!(middle.get_effective_priority(Arch), Priority::Level25);
+        unittest::assert_eq!(current_priority(), Priority::Level25);
+
+        drop(inner);
+        unittest::assert_eq!(current_priority(), base_priority);
+
+        unittest::unwrap!(high.join(Arch)).consume();
+        unittest::unwrap!(middle.join(Arch)).consume();
+
+        Ok(())
+    }
+
+    static TIMEOUT_MUTEX: Mutex<Arch, ()> = Mutex::new(Arch, ());
+    static TIMED_OUT: AtomicBool = AtomicBool::new(false);
+
+    fn lock_until_times_out_entry(_kernel: Arch, _arg: usize) {
+        let deadline = Arch.now() + Duration::from_millis(50);
+        if let Err(Error::DeadlineExceeded) = TIMEOUT_MUTEX.lock_until(deadline) {
+            TIMED_OUT.store(true, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn inherited_priority_dropped_when_waiter_times_out() -> unittest::Result<()> {
+        let base_priority = current_priority();
+
+        let _guard = TIMEOUT_MUTEX.lock();
+
+        let waiter = start_thread!(
+            "mutex timeout waiter",
+            Priority::Level25,
+            lock_until_times_out_entry,
+            0usize
+        );
+        unittest::assert_eq!(current_priority(), Priority::Level25);
+
+        unittest::unwrap!(waiter.join(Arch)).consume();
+        unittest::assert_true!(TIMED_OUT.load(Ordering::SeqCst));
+        unittest::assert_eq!(current_priority(), base_priority);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+#[cfg(test)]
+mod tests {
+    use core::sync::atomic::{AtomicBool, Ordering};
+
+    #[cfg(feature = "arch_arm_cortex_m")]
+    use arch_arm_cortex_m::Arch;
+    #[cfg(feature = "arch_host")]
+    use arch_host::HostArch as Arch;
+    #[cfg(feature = "arch_riscv")]
+    use arch_riscv::Arch;
+    use kernel::sync::semaphore::Semaphore;
+    use kernel::{Arch as _, Duration, Priority};
+    use pw_status::Error;
+    use unittest::test;
+
+    use crate::test_utils::start_thread;
+
+    #[test]
+    fn try_acquire_and_release_respect_counts() -> unittest::Result<()> {
+        let semaphore = Semaphore::<Arch>::new(Arch, 1, 2);
+
+        unittest::assert_true!(semaphore.try_acquire().is_ok());
+        unittest::assert_eq!(semaphore.try_acquire(), Err(Error::Unavailable));
+        unittest::assert_eq!(semaphore.count(), 0);
+
+        unittest::assert_true!(semaphore.release().is_ok());
+        unittest::assert_true!(semaphore.release().is_ok());
+        unittest::assert_eq!(semaphore.release(), Err(Error::ResourceExhausted));
+        unittest::assert_eq!(semaphore.count(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn acquire_until_times_out_without_permits() -> unittest::Result<()> {
+        let semaphore = Semaphore::<Arch>::new(Arch, 0, 1);
+
+        let deadline = Arch.now() + Duration::from_millis(10);
+        unittest::assert_eq!(
+            semaphore.acquire_until(deadline),
+            Err(Error::DeadlineExceeded)
+        );
+        unittest::assert_eq!(semaphore.count(), 0);
+
+        Ok(())
+    }
+
+    static HANDOFF_SEMAPHORE: Semaphore<Arch> = Semaphore::new(Arch, 0, 1);
+    static ACQUIRED: AtomicBool = AtomicBool::new(false);
+
+    fn acquire_entry(_kernel: Arch, semaphore: &'static Semaphore<Arch>) {
+        semaphore.acquire();
+        ACQUIRED.store(true, Ordering::SeqCst);
+    }
+
+    #[test]
+    fn release_hands_permit_to_waiter() -> unittest::Result<()> {
+        // The higher priority waiter runs as soon as it is started and blocks
+        // on the semaphore.
+        let waiter = start_thread!(
+            "semaphore waiter",
+            Priority::Level25,
+            acquire_entry,
+            &HANDOFF_SEMAPHORE
+        );
+        unittest::assert_false!(ACQUIRED.load(Ordering::SeqCst));
+
+        // The permit goes straight to the waiter, which runs to completion
+        // before this thread, and is never added to the count.
+        unittest::assert_true!(HANDOFF_SEMAPHORE.release().is_ok());
+        unittest::assert_true!(ACQUIRED.load(Ordering::SeqCst));
+        unittest::assert_eq!(HANDOFF_SEMAPHORE.count(), 0);
+        unittest::unwrap!(waiter.join(Arch)).consume();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+//! Helpers shared by the kernel integration tests.
+
+#[cfg(feature = "arch_arm_cortex_m")]
+pub use arch_arm_cortex_m::Arch;
+#[cfg(feature = "arch_host")]
+pub use arch_host::HostArch as Arch;
+#[cfg(feature = "arch_riscv")]
+pub use arch_riscv::Arch;
+use kernel::object::SyscallBuffer;
+use memory_config::MemoryRegionType;
+
+/// Size of the stacks of threads started with [`start_thread!`].
+pub const STACK_SIZE: usize = 2048;
+
+/// Starts a kernel thread backed by static storage.
+///
+/// Each expansion must be executed at most once at run time.
+macro_rules! start_thread {
+    ($name:literal, $priority:expr, $entry:expr, $arg:expr) => {{
+        use kernel::scheduler::thread::{self, StackStorage, StackStorageExt as _, Thread};
+
+        use $crate::test_utils::{Arch, STACK_SIZE};
+
+        let thread = unsafe { kernel::static_mut_ref!(Thread<Arch> = Thread::new($name, $priority)) };
+        let stack =
+            unsafe { kernel::static_mut_ref!(StackStorage<STACK_SIZE> = StackStorage::ZEROED) };
+        kernel::start_thread(
+            Arch,
+            thread::init_thread_in(Arch, thread, stack, $name, $priority, $entry, $arg),
+        )
+    }};
+}
+pub(crate) use start_thread;
+
+/// Returns a buffer in the current process covering `data`.
+pub fn buffer(access_type: MemoryRegionType, data: &mut [u8]) -> SyscallBuffer {
+    let start = data.as_mut_ptr().expose_provenance();
+    SyscallBuffer::new_in_current_process(Arch, access_type, start..start + data.len()).unwrap()
+}
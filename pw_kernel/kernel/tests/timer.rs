@@ -0,0 +1,165 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+#[cfg(test)]
+mod tests {
+    use core::sync::atomic::{AtomicU32, Ordering};
+
+    #[cfg(feature = "arch_arm_cortex_m")]
+    use arch_arm_cortex_m::Arch;
+    #[cfg(feature = "arch_host")]
+    use arch_host::HostArch as Arch;
+    #[cfg(feature = "arch_riscv")]
+    use arch_riscv::Arch;
+    use kernel::scheduler::timer::{KernelTimer, TimerHandler, next_periodic_deadline};
+    use kernel::{Arch as _, Duration, Instant};
+    use unittest::test;
+
+    struct CountingHandler {
+        count: AtomicU32,
+    }
+
+    impl CountingHandler {
+        const fn new() -> Self {
+            Self {
+                count: AtomicU32::new(0),
+            }
+        }
+
+        fn count(&self) -> u32 {
+            self.count.load(Ordering::SeqCst)
+        }
+    }
+
+    impl TimerHandler<Arch> for CountingHandler {
+        fn expired(&self, _kernel: Arch, _now: Instant<<Arch as kernel::Arch>::Clock>) {
+            self.count.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn sleep_for(duration: Duration<<Arch as kernel::Arch>::Clock>) -> unittest::Result<()> {
+        unittest::assert_true!(kernel::sleep_until(Arch, Arch.now() + duration).is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn one_shot_timer_fires_once() -> unittest::Result<()> {
+        static TIMER: KernelTimer<Arch> = KernelTimer::new();
+        static HANDLER: CountingHandler = CountingHandler::new();
+
+        TIMER.start(Arch, &HANDLER, Arch.now() + Duration::from_millis(1), None);
+        unittest::assert_true!(TIMER.is_active(Arch));
+
+        sleep_for(Duration::from_millis(10))?;
+        unittest::assert_eq!(HANDLER.count(), 1);
+        unittest::assert_false!(TIMER.is_active(Arch));
+
+        Ok(())
+    }
+
+    #[test]
+    fn periodic_timer_fires_until_cancelled() -> unittest::Result<()> {
+        static TIMER: KernelTimer<Arch> = KernelTimer::new();
+        static HANDLER: CountingHandler = CountingHandler::new();
+
+        let period = Duration::from_millis(2);
+        TIMER.start(Arch, &HANDLER, Arch.now() + period, Some(period));
+
+        sleep_for(Duration::from_millis(20))?;
+        unittest::assert_true!(TIMER.is_active(Arch));
+        TIMER.cancel(Arch);
+        unittest::assert_false!(TIMER.is_active(Arch));
+
+        let count = HANDLER.count();
+        unittest::assert_true!(count > 1);
+
+        sleep_for(Duration::from_millis(10))?;
+        unittest::assert_eq!(HANDLER.count(), count);
+
+        Ok(())
+    }
+
+    #[test]
+    fn periodic_deadlines_do_not_drift() -> unittest::Result<()> {
+        let start = Instant::from_ticks(1000);
+        let period = Duration::from_ticks(10);
+        let deadline_after = |periods: u64| Instant::from_ticks(1000 + periods * 10);
+
+        // Processed on time.
+        unittest::assert_true!(
+            next_periodic_deadline::<Arch>(start, period, start) == deadline_after(1)
+        );
+
+        // A late handler does not push back later deadlines.
+        unittest::assert_true!(
+            next_periodic_deadline::<Arch>(
+                start,
+                period,
+                deadline_after(0) + Duration::from_ticks(3)
+            ) == deadline_after(1)
+        );
+
+        // Periods which were missed entirely are skipped.
+        unittest::assert_true!(
+            next_periodic_deadline::<Arch>(
+                start,
+                period,
+                deadline_after(3) + Duration::from_ticks(7)
+            ) == deadline_after(4)
+        );
+        unittest::assert_true!(
+            next_periodic_deadline::<Arch>(start, period, deadline_after(5)) == deadline_after(6)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn cancelled_timer_does_not_fire() -> unittest::Result<()> {
+        static TIMER: KernelTimer<Arch> = KernelTimer::new();
+        static HANDLER: CountingHandler = CountingHandler::new();
+
+        TIMER.start(Arch, &HANDLER, Arch.now() + Duration::from_millis(5), None);
+        TIMER.cancel(Arch);
+
+        sleep_for(Duration::from_millis(10))?;
+        unittest::assert_eq!(HANDLER.count(), 0);
+
+        // A cancelled timer can be restarted with its previous handler.
+        unittest::assert_true!(
+            TIMER
+                .restart(Arch, Arch.now() + Duration::from_millis(1))
+                .is_ok()
+        );
+        sleep_for(Duration::from_millis(10))?;
+        unittest::assert_eq!(HANDLER.count(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sleep_wakes_up_at_deadline() -> unittest::Result<()> {
+        // With tickless idle enabled the scheduler tick is suppressed while
+        // sleeping, so the wakeup relies on the timer being programmed for
+        // the deadline.
+        let deadline = Arch.now() + Duration::from_millis(25);
+        unittest::assert_true!(kernel::sleep_until(Arch, deadline).is_ok());
+
+        let now = Arch.now();
+        unittest::assert_true!(now >= deadline);
+        unittest::assert_true!(now < deadline + Duration::from_millis(100));
+
+        Ok(())
+    }
+}
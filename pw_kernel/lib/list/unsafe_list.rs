@@ -54,6 +54,11 @@ mod link {
         const UNLINKED_VALUE: Option<NonNull<Link>> =
             Some(NonNull::new(usize::MAX as *mut Link).unwrap());
 
+        /// Offset of the pointer to the next link, for use by debug tooling
+        /// which walks lists in target memory.
+        #[doc(hidden)]
+        pub const NEXT_OFFSET: usize = core::mem::offset_of!(Link, next);
+
         #[must_use]
         pub const fn new() -> Self {
             Self {
@@ -208,6 +213,12 @@ macro_rules! define_adapter {
 }
 
 impl<T, A: Adapter<T>> UnsafeList<T, A> {
+    /// Offset of the pointer to the first element's link, for use by debug
+    /// tooling which walks lists in target memory.
+    #[doc(hidden)]
+    pub const HEAD_OFFSET: usize =
+        core::mem::offset_of!(Self, inner) + core::mem::offset_of!(UnsafeListInner, head);
+
     #[must_use]
     pub const fn new() -> Self {
         Self {
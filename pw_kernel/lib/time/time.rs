@@ -156,6 +156,14 @@ impl<Clock: crate::Clock> Duration<Clock> {
         _phantom: PhantomData,
     };
 
+    #[must_use]
+    pub const fn from_ticks(ticks: i64) -> Self {
+        Self {
+            ticks,
+            _phantom: PhantomData,
+        }
+    }
+
     #[must_use]
     pub const fn ticks(self) -> i64 {
         self.ticks
@@ -193,6 +201,15 @@ impl<Clock: crate::Clock> Duration<Clock> {
         }
     }
 
+    /// Returns the duration in whole milliseconds, rounded towards zero.
+    ///
+    /// Saturates if the duration does not fit in an `i64` of milliseconds.
+    #[must_use]
+    pub fn as_millis(self) -> i64 {
+        let millis = i128::from(self.ticks) * 1000 / i128::from(Clock::TICKS_PER_SEC);
+        i64::try_from(millis).unwrap_or(if millis < 0 { i64::MIN } else { i64::MAX })
+    }
+
     #[must_use]
     pub const fn checked_add(self, rhs: Duration<Clock>) -> Option<Self> {
         if let Some(ticks) = self.ticks.checked_add(rhs.ticks) {
@@ -312,6 +329,22 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn duration_as_millis_rounds_towards_zero() -> unittest::Result<()> {
+        unittest::assert_eq!(Duration::<TestClock>::from_millis(1234).as_millis(), 1234);
+        unittest::assert_eq!(Duration::<TestClock>::from_millis(-1234).as_millis(), -1234);
+        unittest::assert_eq!(
+            Duration::<HighResTestClock>::from_nanos(1_999_999).as_millis(),
+            1
+        );
+        unittest::assert_eq!(
+            Duration::<HighResTestClock>::from_nanos(-1_999_999).as_millis(),
+            -1
+        );
+        unittest::assert_eq!(Duration::<TestClock>::MAX.as_millis(), i64::MAX);
+        Ok(())
+    }
+
     #[test]
     fn duration_checked_addition_returns_correct_values() -> unittest::Result<()> {
         let ten_ms = Duration::<TestClock>::from_millis(10);
@@ -0,0 +1,475 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+//! Interactive kernel shell for inspecting a running system.
+//!
+//! The shell reads lines from a kernel owned [`CharDevice`], typically the
+//! console UART, and runs the command named by the first word.  Besides the
+//! built-in commands, targets can register their own with [`Shell::new()`].
+//!
+//! Targets enable the shell by setting `kernel.shell` in their system config,
+//! which generates a `declare_shell!()` macro defining the shell and a
+//! `start_shell()` function which runs it in a kernel thread.
+//!
+//! Built-in commands:
+//! - `help`: Lists the available commands.
+//! - `threads`: Lists the state and priority of every thread.
+//! - `cpu`: Lists the CPU usage of every process and thread since boot.
+//! - `procs`: Lists every process and its number of threads.
+//! - `stacks`: Lists the kernel stack, and its usage, of every thread.
+//! - `mem <addr> <len>`: Dumps `len` bytes of memory starting at `addr`.
+//! - `irqs`: Lists the IRQs with a handler in the kernel interrupt table.
+//! - `reboot`: Resets the system.
+//!
+//! The shell is meant for bring-up and debugging.  `mem` in particular reads
+//! arbitrary addresses with kernel privileges and faults on unmapped ones.
+
+#![no_std]
+
+use core::fmt::Write;
+
+use kernel::char_device::{CharDevice, CharDeviceListener};
+use kernel::interrupt_controller::InterruptTableEntry;
+use kernel::scheduler::stack_check::stack_usage;
+use kernel::scheduler::stats::{ThreadSnapshot, permille, snapshot_threads};
+use kernel::scheduler::thread;
+use kernel::sync::event::{Event, EventConfig};
+use kernel::{Duration, Instant, Kernel};
+use pw_status::{Error, Result};
+
+/// Maximum length of a command line.  Longer lines are truncated.
+const MAX_LINE_LEN: usize = 80;
+
+/// Maximum number of threads listed by the thread commands.
+const MAX_THREADS: usize = 32;
+
+/// Maximum number of bytes dumped by a single `mem` command.
+const MAX_MEM_DUMP_LEN: usize = 256;
+
+const PROMPT: &str = "> ";
+
+/// Arguments following a command's name.
+pub type Args<'a> = core::str::SplitAsciiWhitespace<'a>;
+
+/// Output of a command, written to the shell's device.
+pub type Output<'a> = dyn Write + 'a;
+
+/// A shell command.
+pub struct Command<K: Kernel> {
+    /// Name the command is invoked by.
+    pub name: &'static str,
+
+    /// One line description listed by `help`.
+    pub help: &'static str,
+
+    /// Runs the command.  Errors are reported to the user by the shell.
+    pub handler: fn(kernel: K, out: &mut Output<'_>, args: &mut Args<'_>) -> Result<()>,
+}
+
+/// An interactive shell on a [`CharDevice`].
+pub struct Shell<K: Kernel> {
+    device: &'static dyn CharDevice<K>,
+    commands: &'static [Command<K>],
+    interrupt_table: &'static [InterruptTableEntry],
+    reset: fn() -> !,
+    readable: Event<K>,
+    writable: Event<K>,
+}
+
+impl<K: Kernel> Shell<K> {
+    /// Creates a shell on `device` offering the built-in commands as well as
+    /// `commands`.
+    ///
+    /// `interrupt_table` is listed by the `irqs` command.  Targets with a
+    /// generated kernel interrupt table pass
+    /// `codegen::PW_KERNEL_INTERRUPT_TABLE`, others an empty slice.  `reset`
+    /// is called by the `reboot` command.  Commands in `commands` take
+    /// precedence over built-in commands of the same name.
+    #[must_use]
+    pub const fn new(
+        kernel: K,
+        device: &'static dyn CharDevice<K>,
+        commands: &'static [Command<K>],
+        interrupt_table: &'static [InterruptTableEntry],
+        reset: fn() -> !,
+    ) -> Self {
+        Self {
+            device,
+            commands,
+            interrupt_table,
+            reset,
+            readable: Event::new(kernel, EventConfig::AutoReset),
+            writable: Event::new(kernel, EventConfig::AutoReset),
+        }
+    }
+
+    /// Runs the shell.
+    ///
+    /// Meant to be the body of a dedicated kernel thread.  The thread should
+    /// run at a low priority so that the shell does not disturb the system it
+    /// is inspecting.
+    ///
+    /// # Returns
+    /// Only returns if the thread is terminated while waiting on the device.
+    pub fn run(&'static self, kernel: K) -> Result<()> {
+        self.device.set_listener(kernel, self);
+
+        let mut out = DeviceWriter {
+            shell: self,
+            kernel,
+        };
+        let mut line = [0u8; MAX_LINE_LEN];
+        loop {
+            let _ = out.write_str(PROMPT);
+            let len = self.read_line(kernel, &mut out, &mut line)?;
+            let Ok(line) = core::str::from_utf8(&line[..len]) else {
+                let _ = writeln!(out, "Invalid input");
+                continue;
+            };
+            self.execute(kernel, &mut out, line);
+        }
+    }
+
+    // Reads a line into `line`, echoing it back.  Returns the length of the
+    // line.
+    fn read_line(
+        &self,
+        kernel: K,
+        out: &mut DeviceWriter<'_, K>,
+        line: &mut [u8; MAX_LINE_LEN],
+    ) -> Result<usize> {
+        let mut len = 0;
+        loop {
+            let mut byte = [0u8; 1];
+            if self.device.read(kernel, &mut byte)? == 0 {
+                self.readable.wait()?;
+                continue;
+            }
+
+            match byte[0] {
+                b'\r' => {
+                    out.write_bytes(b"\r\n");
+                    return Ok(len);
+                }
+                // Terminals end lines with a carriage return, possibly
+                // followed by a line feed.
+                b'\n' => {}
+                // Backspace and delete.
+                0x08 | 0x7f => {
+                    if len > 0 {
+                        len -= 1;
+                        let _ = out.write_str("\x08 \x08");
+                    }
+                }
+                byte => {
+                    if let Some(slot) = line.get_mut(len) {
+                        *slot = byte;
+                        len += 1;
+                        out.write_bytes(&[byte]);
+                    }
+                }
+            }
+        }
+    }
+
+    fn execute(&self, kernel: K, out: &mut DeviceWriter<'_, K>, line: &str) {
+        let mut args = line.split_ascii_whitespace();
+        let Some(name) = args.next() else {
+            return;
+        };
+
+        if name == "help" {
+            self.help(out);
+            return;
+        }
+        if name == "irqs" {
+            self.irqs(out);
+            return;
+        }
+        if name == "reboot" {
+            let _ = writeln!(out, "Rebooting");
+            (self.reset)();
+        }
+
+        let builtins = builtin_commands::<K>();
+        let Some(command) = self
+            .commands
+            .iter()
+            .chain(builtins.iter())
+            .find(|command| command.name == name)
+        else {
+            let _ = writeln!(out, "Unknown command '{name}', try 'help'");
+            return;
+        };
+
+        if let Err(error) = (command.handler)(kernel, out, &mut args) {
+            let _ = writeln!(out, "{name}: failed with {error:?}");
+        }
+    }
+
+    fn help(&self, out: &mut DeviceWriter<'_, K>) {
+        let _ = writeln!(out, "{:<10} Lists the available commands", "help");
+        for command in self.commands.iter().chain(builtin_commands::<K>().iter()) {
+            let _ = writeln!(out, "{:<10} {}", command.name, command.help);
+        }
+        let _ = writeln!(
+            out,
+            "{:<10} Lists the IRQs with a kernel interrupt handler",
+            "irqs"
+        );
+        let _ = writeln!(out, "{:<10} Resets the system", "reboot");
+    }
+
+    fn irqs(&self, out: &mut DeviceWriter<'_, K>) {
+        for (irq, handler) in self.interrupt_table.iter().enumerate() {
+            if let Some(handler) = handler {
+                let _ = writeln!(out, "{irq:>4} {:#010x}", *handler as usize);
+            }
+        }
+    }
+}
+
+impl<K: Kernel> CharDeviceListener<K> for Shell<K> {
+    fn readable(&self, _kernel: K) {
+        self.readable.get_signaler().signal();
+    }
+
+    fn writable(&self, _kernel: K) {
+        self.writable.get_signaler().signal();
+    }
+}
+
+// Writes to the shell's device, blocking while its write buffer is full.
+struct DeviceWriter<'a, K: Kernel> {
+    shell: &'a Shell<K>,
+    kernel: K,
+}
+
+impl<K: Kernel> DeviceWriter<'_, K> {
+    fn write_bytes(&mut self, mut bytes: &[u8]) {
+        while !bytes.is_empty() {
+            match self.shell.device.write(self.kernel, bytes) {
+                Ok(0) => {
+                    if self.shell.writable.wait().is_err() {
+                        return;
+                    }
+                }
+                Ok(len) => bytes = &bytes[len..],
+                Err(_) => return,
+            }
+        }
+    }
+}
+
+impl<K: Kernel> Write for DeviceWriter<'_, K> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        // Terminals expect a carriage return before each line feed.
+        let mut lines = s.split('\n');
+        if let Some(first) = lines.next() {
+            self.write_bytes(first.as_bytes());
+        }
+        for line in lines {
+            self.write_bytes(b"\r\n");
+            self.write_bytes(line.as_bytes());
+        }
+        Ok(())
+    }
+}
+
+fn builtin_commands<K: Kernel>() -> [Command<K>; 5] {
+    [
+        Command {
+            name: "threads",
+            help: "Lists the state and priority of every thread",
+            handler: threads,
+        },
+        Command {
+            name: "cpu",
+            help: "Lists the CPU usage of every process and thread since boot",
+            handler: cpu,
+        },
+        Command {
+            name: "procs",
+            help: "Lists every process and its number of threads",
+            handler: procs,
+        },
+        Command {
+            name: "stacks",
+            help: "Lists the kernel stack, and its usage, of every thread",
+            handler: stacks,
+        },
+        Command {
+            name: "mem",
+            help: "<addr> <len>: Dumps memory",
+            handler: mem,
+        },
+    ]
+}
+
+// Parses a decimal or `0x` prefixed hexadecimal number.
+fn parse_usize(arg: Option<&str>) -> Result<usize> {
+    let arg = arg.ok_or(Error::InvalidArgument)?;
+    let parsed = match arg.strip_prefix("0x") {
+        Some(hex) => usize::from_str_radix(hex, 16),
+        None => arg.parse(),
+    };
+    parsed.map_err(|_| Error::InvalidArgument)
+}
+
+fn snapshot<K: Kernel>(
+    kernel: K,
+    out: &mut Output<'_>,
+    snapshots: &mut [Option<ThreadSnapshot<K>>; MAX_THREADS],
+) {
+    let count = snapshot_threads(kernel, snapshots);
+    if count > MAX_THREADS {
+        let _ = writeln!(out, "Only showing {MAX_THREADS} of {count} threads",);
+    }
+}
+
+fn threads<K: Kernel>(kernel: K, out: &mut Output<'_>, _args: &mut Args<'_>) -> Result<()> {
+    let mut snapshots = [None; MAX_THREADS];
+    snapshot(kernel, out, &mut snapshots);
+
+    for snapshot in snapshots.iter().flatten() {
+        let _ = writeln!(
+            out,
+            "{:#010x} {:<24} {:<10} priority {:<2} process {}",
+            snapshot.id,
+            snapshot.name,
+            thread::to_string(snapshot.state),
+            snapshot.priority as usize,
+            snapshot.process_name
+        );
+    }
+    Ok(())
+}
+
+fn cpu<K: Kernel>(kernel: K, out: &mut Output<'_>, _args: &mut Args<'_>) -> Result<()> {
+    let mut snapshots = [None; MAX_THREADS];
+    snapshot(kernel, out, &mut snapshots);
+    let uptime = kernel.now() - Instant::MIN;
+    let _ = writeln!(out, "CPU usage over {} ms:", uptime.as_millis());
+
+    // Threads are listed grouped by process, after the process's total.
+    let mut snapshots = snapshots.iter().flatten().peekable();
+    while let Some(first) = snapshots.peek().copied() {
+        let mut process_snapshots = snapshots.clone();
+        let mut process_run_time = Duration::from_secs(0);
+        while let Some(snapshot) =
+            process_snapshots.next_if(|snapshot| snapshot.process_id == first.process_id)
+        {
+            process_run_time = process_run_time + snapshot.run_time;
+        }
+        let process_permille = permille::<K>(process_run_time, uptime);
+        let _ = writeln!(
+            out,
+            "{:#010x} {:<24} {}.{}%",
+            first.process_id,
+            first.process_name,
+            process_permille / 10,
+            process_permille % 10
+        );
+
+        while let Some(snapshot) =
+            snapshots.next_if(|snapshot| snapshot.process_id == first.process_id)
+        {
+            let thread_permille = permille::<K>(snapshot.run_time, uptime);
+            let _ = writeln!(
+                out,
+                "  {:<24} {}.{}% run {} ms switches {} preemptions {}",
+                snapshot.name,
+                thread_permille / 10,
+                thread_permille % 10,
+                snapshot.run_time.as_millis(),
+                snapshot.context_switches,
+                snapshot.preemptions
+            );
+        }
+    }
+    Ok(())
+}
+
+fn procs<K: Kernel>(kernel: K, out: &mut Output<'_>, _args: &mut Args<'_>) -> Result<()> {
+    let mut snapshots = [None; MAX_THREADS];
+    snapshot(kernel, out, &mut snapshots);
+
+    // Threads are listed grouped by process.
+    let mut snapshots = snapshots.iter().flatten().peekable();
+    while let Some(first) = snapshots.next() {
+        let mut thread_count = 1;
+        while snapshots
+            .next_if(|snapshot| snapshot.process_id == first.process_id)
+            .is_some()
+        {
+            thread_count += 1;
+        }
+        let _ = writeln!(
+            out,
+            "{:#010x} {:<24} {} threads",
+            first.process_id, first.process_name, thread_count
+        );
+    }
+    Ok(())
+}
+
+fn stacks<K: Kernel>(kernel: K, out: &mut Output<'_>, _args: &mut Args<'_>) -> Result<()> {
+    let mut snapshots = [None; MAX_THREADS];
+    snapshot(kernel, out, &mut snapshots);
+    let mut usage = [None; MAX_THREADS];
+    stack_usage(kernel, &mut usage);
+
+    for snapshot in snapshots.iter().flatten() {
+        let _ = write!(
+            out,
+            "{:<24} {:#010x}-{:#010x} {} bytes",
+            snapshot.name,
+            snapshot.stack_start,
+            snapshot.stack_end,
+            snapshot.stack_end - snapshot.stack_start
+        );
+        // Threads may have been created between the two snapshots.
+        match usage.iter().flatten().find(|usage| usage.id == snapshot.id) {
+            Some(usage) => {
+                let _ = writeln!(out, ", {} used ({}%)", usage.used, usage.percent_used());
+            }
+            None => {
+                let _ = writeln!(out);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn mem<K: Kernel>(_kernel: K, out: &mut Output<'_>, args: &mut Args<'_>) -> Result<()> {
+    let addr = parse_usize(args.next())?;
+    let len = parse_usize(args.next())?;
+    if len > MAX_MEM_DUMP_LEN || addr.checked_add(len).is_none() {
+        return Err(Error::OutOfRange);
+    }
+
+    for line_addr in (addr..addr + len).step_by(16) {
+        let _ = write!(out, "{line_addr:#010x}:");
+        for byte_addr in line_addr..line_addr.saturating_add(16).min(addr + len) {
+            // SAFETY: Memory is dumped at the user's request.  Reading an
+            // unmapped address faults, which is accepted for a debug shell.
+            let byte = unsafe {
+                core::ptr::read_volatile(core::ptr::with_exposed_provenance::<u8>(byte_addr))
+            };
+            let _ = write!(out, " {byte:02x}");
+        }
+        let _ = writeln!(out);
+    }
+    Ok(())
+}
@@ -22,6 +22,9 @@
 //!
 //! - [Channel](#channel)
 //! - [Wait Group](#wait-group)
+//! - [Process](#process)
+//! - [Timer](#timer)
+//! - [UART](#uart)
 //!
 //! ### Handles
 //! All system calls reference objects through a u32 handle which indexes into
@@ -87,13 +90,19 @@
 //! - raising `Signals::USER` on the initiator by calling
 //!   [`object_raise_peer_user_signal()`]
 //!
+//! When the last thread of the process holding one end of a channel exits,
+//! that end is closed.  Any transaction in flight is dropped and
+//! `Signals::ERROR` is raised on the surviving end.  From then on, calls on
+//! the surviving end which require its peer fail with [`Error::Aborted`].
+//!
 //! #### Initiator Signals
 //! - `Signals::WRITABLE` indicates there is no pending transaction and one
 //!   can be started. Cleared on transaction initiation.
 //! - `Signals::READABLE` indicates the handler has responded to the
 //!   pending transaction.  Cleared on transaction initiation.
-//! - `Signals::ERROR` indicates pending transaction has an error.  Cleared
-//!   when the initiator is waited on.
+//! - `Signals::ERROR` indicates the handler has closed.  A blocking
+//!   [`channel_transact()`] in flight returns [`Error::Aborted`].  Cleared
+//!   when the handler's process is restarted.
 //! - `Signals::USER` indicates the handler calls [`object_raise_peer_user_signal()`].
 //!   Cleared when the initiator is waited on.
 //!
@@ -102,11 +111,11 @@
 //!   the handler calls [`channel_respond()`].
 //! - `Signals::WRITABLE` indicates there is a pending transaction.  Cleared when
 //!   the handler calls [`channel_respond()`].
-//! - `Signals::ERROR` indicates a pending transaction error.  No error states
-//!   are defined at the moment.  In the future an error may be raised when
-//!   the remote peer closes.
+//! - `Signals::ERROR` indicates the initiator has closed.  Any transaction
+//!   it had pending is dropped.  Cleared when the initiator's process is
+//!   restarted.
 //! - `Signals::USER` indicates the initiator calls [`object_raise_peer_user_signal()`].
-//!   Cleared when the initiator is waited on.
+//!   Cleared when the handler is waited on.
 //!
 //! ### Wait Group
 //! Wait groups provide a mechanism for waiting on multiple handles at once.
@@ -116,15 +125,20 @@
 //! is returned by [`object_wait()`], unmodified by the kernel, when the wait group is
 //! waited on.
 //!
-//! #### Open questions:
-//! - How is the wait group's member list allocated in there kernel.  To support
-//!   a fully statically allocated kernel one of two approaches are being
-//!   considered:
-//!   - an object (or possibly handle) may only be in a single wait group at
-//!     one time.  This allows a wait group to maintain an intrusive list of
-//!     objects with the list element storage being stored in the object.
-//!   - wait queues are statically sized at compile time and adding more objects
-//!     than there is space for will return an error.
+//! An object may only be a member of a single wait group at a time.  This
+//! allows a wait group to maintain an intrusive list of its members with the
+//! list element storage being stored in the member object, keeping the kernel
+//! fully statically allocated.  Adding an object which is already in a wait
+//! group returns [`Error::ResourceExhausted`].
+//!
+//! A wait group raises `Signals::READABLE` when any of its members has one of
+//! the signals it was added with pending.  Waiting on a wait group returns the
+//! `user_data` and pending signals of a single ready member.  Ready members are
+//! returned in a round robin fashion so that one busy member can not starve
+//! the others.
+//!
+//! `user_data` is limited to [`WaitReturn::MAX_USER_DATA`] so that it can be
+//! returned along side the pending signals in a single register pair.
 //!
 //! ### Interrupt
 //! Interrupt objects provide a mechanism for handling hardware interrupts.
@@ -155,7 +169,76 @@
 //!    at the hardware level.
 //!
 //! ### Futex
-//! In design
+//! Futexes provide a building block for user space synchronization primitives
+//! such as mutexes and condition variables between threads of the same
+//! process.  A futex is not a kernel object and has no handle.  Instead it is
+//! any aligned 32-bit word in the process' read/write memory.
+//!
+//! Uncontended operations are handled entirely in user space with atomic
+//! operations on the futex word.  The kernel is only entered to block or wake
+//! threads:
+//! - [`futex_wait()`] atomically checks that the futex word still holds an
+//!   expected value and blocks the calling thread on its address.
+//! - [`futex_wake()`] wakes up to a given number of threads blocked on an
+//!   address.
+//!
+//! The kernel keeps no state for a futex beyond the threads that are blocked
+//! on it.
+//!
+//! ### Process
+//! A process object refers to another process in the system and lets a
+//! supervising process control its lifecycle.  Process objects are only
+//! created by the system configuration and are intended to be given to a
+//! single, trusted supervisor app.
+//!
+//! - [`process_terminate()`] requests every thread of the process to
+//!   terminate.  Threads blocked in an interruptible wait are woken and exit
+//!   once they return to user space.  A process can not terminate itself.
+//! - Once the last thread of the process has exited, its objects are closed
+//!   and `Signals::READABLE` is raised on the process object.
+//! - A fault taken by any thread of the process, such as a memory access
+//!   violation, terminates the process in the same way instead of halting the
+//!   system.
+//! - [`process_get_exit_status()`] returns the status the process exited
+//!   with: `0` if its threads exited on their own, [`Error::Cancelled`] if it
+//!   was terminated or [`Error::Internal`] if one of its threads faulted.
+//! - [`process_get_fault()`] describes the fault which terminated the
+//!   process, if any.
+//! - [`process_restart()`] restarts an exited process from its entry point.
+//!   Its objects are reopened, clearing `Signals::ERROR` on their peers, and
+//!   `Signals::READABLE` is cleared on the process object.
+//!
+//! ### Timer
+//! A timer object raises `Signals::READABLE` when it expires, allowing apps to
+//! wait on timeouts alongside other objects in a [Wait Group](#wait-group).
+//!
+//! - [`timer_set()`] starts the timer with an absolute deadline and an
+//!   optional period.  A periodic timer expires every period after its
+//!   deadline without accumulating drift.  Expiries which are missed entirely
+//!   are skipped.
+//! - [`timer_cancel()`] stops the timer.
+//!
+//! `Signals::READABLE` is cleared when it is returned from a wait and when the
+//! timer is set.  The timer is cancelled when the process holding it exits.
+//!
+//! ### UART
+//! A UART object exposes a kernel owned UART, or any other character device,
+//! to an app.  The kernel driver buffers data in both directions so apps never
+//! touch the device's registers.
+//!
+//! - [`uart_read()`] copies received bytes out of the driver's read buffer
+//!   without blocking.
+//! - [`uart_write()`] queues as many bytes as fit in the driver's write buffer
+//!   without blocking.
+//!
+//! #### UART Signals
+//! - `Signals::READABLE` indicates received data may be available.  Cleared
+//!   when [`uart_read()`] empties the read buffer.
+//! - `Signals::WRITEABLE` indicates space may be available in the write
+//!   buffer.  Cleared when [`uart_write()`] fills the write buffer.
+//!
+//! Signals may be raised spuriously, in which case the next call transfers
+//! zero bytes.
 //!
 //! ## System Calls
 //! The C ABI system calls listed here are not intended to be called directly
@@ -178,6 +261,39 @@
 //! ### Wait Group Syscalls
 //! - [`wait_group_add()`]
 //! - [`wait_group_remove()`]
+//!
+//! ### Futex Syscalls
+//! - [`futex_wait()`]
+//! - [`futex_wake()`]
+//!
+//! ### Time Syscalls
+//! - [`get_time()`]
+//! - [`sleep_until()`]
+//!
+//! ### Scheduling Syscalls
+//! - [`yield_timeslice()`]
+//!
+//! ### Thread Syscalls
+//! - [`thread_create()`]
+//! - [`thread_join()`]
+//! - [`thread_exit()`]
+//!
+//! ### Process Syscalls
+//! - [`process_terminate()`]
+//! - [`process_restart()`]
+//! - [`process_get_exit_status()`]
+//! - [`process_get_fault()`]
+//!
+//! ### Watchdog Syscalls
+//! - [`watchdog_check_in()`]
+//!
+//! ### Timer Syscalls
+//! - [`timer_set()`]
+//! - [`timer_cancel()`]
+//!
+//! ### UART Syscalls
+//! - [`uart_read()`]
+//! - [`uart_write()`]
 
 #![no_std]
 
@@ -213,6 +329,48 @@ impl SysCallReturnValue {
             Ok(value.cast_unsigned() as u32)
         }
     }
+    pub fn to_result_wait_return(self) -> Result<WaitReturn> {
+        let value = self.0;
+        if value < 0 {
+            // TODO debug assert if error number is out of range
+            let value = (-value).cast_unsigned();
+            // TODO(421404517): Avoid the lossy cast
+            #[allow(clippy::cast_possible_truncation)]
+            Err(unsafe { core::mem::transmute::<u32, Error>(value as u32) })
+        } else {
+            let value = value.cast_unsigned();
+            // TODO(421404517): Avoid the lossy cast
+            #[allow(clippy::cast_possible_truncation)]
+            Ok(WaitReturn {
+                user_data: (value >> 32) as usize,
+                pending_signals: Signals(value as u32),
+            })
+        }
+    }
+    pub fn to_result_u64(self) -> Result<u64> {
+        let value = self.0;
+        if value < 0 {
+            // TODO debug assert if error number is out of range
+            let value = (-value).cast_unsigned();
+            // TODO(421404517): Avoid the lossy cast
+            #[allow(clippy::cast_possible_truncation)]
+            Err(unsafe { core::mem::transmute::<u32, Error>(value as u32) })
+        } else {
+            Ok(value.cast_unsigned())
+        }
+    }
+    /// Decodes the return value of [`process_get_exit_status()`].
+    ///
+    /// The outer result is the result of the system call while the inner
+    /// result is the exit status of the process.
+    pub fn to_result_exit_status(self) -> Result<Result<()>> {
+        // Negative values are errors of the system call itself while positive
+        // values are the error the process exited with.
+        let value = self.0;
+        SysCallReturnValue(value).to_result_unit()?;
+        Ok(SysCallReturnValue(-value).to_result_unit())
+    }
+
     pub fn to_result_signals(self) -> Result<Signals> {
         let value = self.0;
         if value < 0 {
@@ -249,6 +407,28 @@ pub enum SysCallId {
     ChannelRead = 0x0002,
     ChannelRespond = 0x0003,
     InterruptAck = 0x0004,
+    WaitGroupAdd = 0x0005,
+    WaitGroupRemove = 0x0006,
+    ChannelAsyncTransact = 0x0007,
+    ChannelAsyncCancel = 0x0008,
+    ObjectRaisePeerUserSignal = 0x0009,
+    FutexWait = 0x000a,
+    FutexWake = 0x000b,
+    GetTime = 0x000c,
+    SleepUntil = 0x000d,
+    YieldTimeslice = 0x000e,
+    ThreadCreate = 0x000f,
+    ThreadJoin = 0x0010,
+    ThreadExit = 0x0011,
+    ProcessTerminate = 0x0012,
+    ProcessRestart = 0x0013,
+    ProcessGetExitStatus = 0x0014,
+    WatchdogCheckIn = 0x0015,
+    TimerSet = 0x0016,
+    TimerCancel = 0x0017,
+    UartRead = 0x0018,
+    UartWrite = 0x0019,
+    ProcessGetFault = 0x001a,
 
     // System calls prefixed with 0xF000 are reserved development/debugging use.
     DebugPutc = 0xf000,
@@ -314,22 +494,97 @@ impl Signals {
     }
 }
 
+/// Description of the fault which terminated a process, as returned by
+/// [`process_get_fault()`].
+#[derive(Copy, Clone, Default)]
+#[repr(C)]
+pub struct ProcessFault {
+    /// Address of the faulting instruction.  Only valid if `valid` contains
+    /// [`ProcessFault::PC_VALID`].
+    pub pc: usize,
+
+    /// Architecture specific cause of the fault.
+    pub cause: usize,
+
+    /// Address whose access caused the fault.  Only valid if `valid` contains
+    /// [`ProcessFault::ADDRESS_VALID`].
+    pub address: usize,
+
+    /// Which of the optional fields are valid.
+    pub valid: usize,
+}
+
+impl ProcessFault {
+    /// `pc` holds the address of the faulting instruction.
+    pub const PC_VALID: usize = 1 << 0;
+
+    /// `address` holds the address whose access caused the fault.
+    pub const ADDRESS_VALID: usize = 1 << 1;
+
+    #[must_use]
+    pub fn new(pc: Option<usize>, cause: usize, address: Option<usize>) -> Self {
+        let mut valid = 0;
+        if pc.is_some() {
+            valid |= Self::PC_VALID;
+        }
+        if address.is_some() {
+            valid |= Self::ADDRESS_VALID;
+        }
+        Self {
+            pc: pc.unwrap_or(0),
+            cause,
+            address: address.unwrap_or(0),
+            valid,
+        }
+    }
+
+    /// Returns the address of the faulting instruction, if known.
+    #[must_use]
+    pub fn pc(&self) -> Option<usize> {
+        (self.valid & Self::PC_VALID != 0).then_some(self.pc)
+    }
+
+    /// Returns the address whose access caused the fault, if known.
+    #[must_use]
+    pub fn address(&self) -> Option<usize> {
+        (self.valid & Self::ADDRESS_VALID != 0).then_some(self.address)
+    }
+}
+
 /// Return value from the [`object_wait()`] syscall.
 ///
-/// TODO: This is a bit heavy.  Define exact syscall ABI for this data.
-#[repr(C)]
+/// On the syscall ABI, a `WaitReturn` is packed into the non-negative `i64`
+/// return value with `pending_signals` in bits 0-31 and `user_data` in
+/// bits 32-62.
+#[derive(Copy, Clone)]
 pub struct WaitReturn {
-    /// Status of the [`object_wait()`] syscall.
-    pub status: isize,
+    /// `user_data` of the wait group member.  When waiting on a channel
+    /// initiator with `Signals::READABLE` pending, the number of bytes the
+    /// handler responded with.  Otherwise `0`.
+    pub user_data: usize,
 
-    /// Signals pending on this object.
+    /// Signals pending on the object or, when waiting on a wait group, the
+    /// signals pending on the wait group member.
     pub pending_signals: Signals,
+}
+
+impl WaitReturn {
+    /// Largest `user_data` value which may be passed to [`wait_group_add()`].
+    pub const MAX_USER_DATA: usize = 0x7fff_ffff;
 
-    /// `user_data` of the wait group member.
-    pub wait_group_user_data: usize,
+    #[must_use]
+    pub const fn new(pending_signals: Signals) -> Self {
+        Self {
+            user_data: 0,
+            pending_signals,
+        }
+    }
 
-    /// Signals pending on the wait group member.
-    pub wait_group_pending_signals: Signals,
+    /// Packs the `WaitReturn` into its syscall ABI representation.
+    #[must_use]
+    pub fn to_raw(&self) -> u64 {
+        ((self.user_data as u64) << 32) | u64::from(self.pending_signals.bits())
+    }
 }
 
 unsafe extern "C" {
@@ -359,6 +614,7 @@ unsafe extern "C" {
     ///   valid memory regions in this processes' address space.
     /// - [`Error::DeadlineExceeded`]: The handler side did not respond before
     ///   `deadline` was exceeded.
+    /// - [`Error::Aborted`]: The handler side closed before responding.
     pub fn channel_transact(
         object_handle: u32,
         send_data: *mut u8,
@@ -376,9 +632,11 @@ unsafe extern "C" {
     /// canceled.)  `send_data` and `recv_data` may overlap or be the same
     /// buffer.
     ///
-    /// This call will cause `Signals::READABLE` to be cleared on the initiator
-    /// channel object.  It will be signaled by the handler side when it
-    /// responds.
+    /// This call will cause `Signals::READABLE` and `Signals::WRITABLE` to be
+    /// cleared on the initiator channel object.  Both will be signaled when the
+    /// handler side responds.  Waiting on the initiator with [`object_wait()`]
+    /// then returns the number of bytes received from the handler side in
+    /// [`WaitReturn::user_data`].
     ///
     /// # Returns
     /// - `0`: Transaction was successfully initiated.
@@ -387,6 +645,7 @@ unsafe extern "C" {
     /// - [`Error::ResourceExhausted`]: The channel already has a pending transaction.
     /// - [`Error::PermissionDenied`]: `send_data` or `recv_data` do not reference
     ///   valid memory regions in this processes' address space.
+    /// - [`Error::Aborted`]: The handler side has closed.
     pub fn channel_async_transact(
         object_handle: u32,
         send_data: *const u8,
@@ -397,6 +656,12 @@ unsafe extern "C" {
 
     /// Cancels a pending transaction on a channel
     ///
+    /// Removes the transaction from the handler, clearing its
+    /// `Signals::READABLE` and `Signals::WRITABLE`, and raises
+    /// `Signals::WRITABLE` on the initiator.  Subsequent [`channel_read()`] and
+    /// [`channel_respond()`] calls by the handler return
+    /// [`Error::FailedPrecondition`].
+    ///
     /// # Returns
     /// - `0`: Pending transaction was successfully canceled.
     /// - [`Error::InvalidArgument`]: `object_handle` is not a valid initiator
@@ -423,6 +688,7 @@ unsafe extern "C" {
     ///   cancels the transaction.
     /// - [`Error::PermissionDenied`]: `buffer` does not reference a valid memory
     ///   region in this processes' address space.
+    /// - [`Error::Aborted`]: The initiator side has closed.
     /// - TODO: What error should be returned if the initiator's `send_buffer`
     ///   is invalid.  Is that also permission denied?
     pub fn channel_read(
@@ -448,12 +714,21 @@ unsafe extern "C" {
     ///   cancels the transaction.
     /// - [`Error::PermissionDenied`]: `buffer` does not reference a valid memory
     ///   region in this processes' address space.
+    /// - [`Error::Aborted`]: The initiator side has closed.
     pub fn channel_respond(object_handle: u32, buffer: *mut u8, buffer_len: usize) -> isize;
 
     /// Raise `Signals::USER` on a paired object's peer
     ///
     /// Since channels are unidirectional, this serves as a way for the handler
-    /// to signal the initiator.
+    /// to signal the initiator.  `Signals::USER` is cleared on the peer once it
+    /// has been returned by [`object_wait()`].
+    ///
+    /// # Returns
+    /// - `0`: On success.
+    /// - [`Error::InvalidArgument`]: `object_handle` is not a valid channel
+    ///   object.
+    /// - [`Error::FailedPrecondition`]: The channel has no connected peer.
+    /// - [`Error::Aborted`]: The peer has closed.
     pub fn object_raise_peer_user_signal(object_handle: u32) -> isize;
 
     /// Acknowledges the signaled interrupts allowing them to be signaled again.
@@ -478,12 +753,15 @@ unsafe extern "C" {
     ///
     /// Waits for one of the signals in `signal_mask` to be pending on `object_handle`.
     ///
+    /// When `object_handle` is a wait group, waits for any of its members to
+    /// have one of the signals they were added with pending.
+    ///
     /// # Returns
-    /// - `WaitReturn.status >= 0`: Returns object specific metadata
+    /// - `>=0`: A [`WaitReturn`] packed as described in its documentation.
     /// - [`Error::InvalidArgument`]: `object_handle` is not a valid object.
     /// - [`Error::DeadlineExceeded`]: The handler side did not respond before
     ///   `deadline` was exceeded.
-    pub fn object_wait(object_handle: u32, signal_mask: Signals, deadline: u64) -> WaitReturn;
+    pub fn object_wait(object_handle: u32, signal_mask: Signals, deadline: u64) -> i64;
 
     /// Adds an object to a wait group
     ///
@@ -493,8 +771,9 @@ unsafe extern "C" {
     ///
     /// # Returns
     /// `0`: Success
-    /// - [`Error::InvalidArgument`]: `wait_group` is not a valid wait group or
-    ///   `object` is not a valid object.
+    /// - [`Error::InvalidArgument`]: `wait_group` is not a valid wait group,
+    ///   `object` is not a valid object, `object` is `wait_group` itself, or
+    ///   `user_data` is larger than [`WaitReturn::MAX_USER_DATA`].
     /// - [`Error::ResourceExhausted`]: `object` is already in a wait group.
     pub fn wait_group_add(
         wait_group: u32,
@@ -511,10 +790,216 @@ unsafe extern "C" {
     ///   `object` is not a valid object.
     /// - [`Error::NotFound`]: `object` is not in `wait_group`.
     pub fn wait_group_remove(wait_group: u32, object: u32) -> isize;
+
+    /// Blocks on a futex
+    ///
+    /// Blocks the calling thread on `futex` if it contains `expected`.  The
+    /// comparison and blocking are atomic with respect to [`futex_wake()`].
+    ///
+    /// # Returns
+    /// - `0`: The thread was woken by [`futex_wake()`].
+    /// - [`Error::InvalidArgument`]: `futex` is not 4 byte aligned.
+    /// - [`Error::PermissionDenied`]: `futex` does not reference a valid
+    ///   read/write memory region in this processes' address space.
+    /// - [`Error::Unavailable`]: `futex` did not contain `expected`.
+    /// - [`Error::DeadlineExceeded`]: The thread was not woken before
+    ///   `deadline` was exceeded.
+    pub fn futex_wait(futex: *const u32, expected: u32, deadline: u64) -> isize;
+
+    /// Wakes threads blocked on a futex
+    ///
+    /// Wakes up to `count` threads blocked on `futex` in [`futex_wait()`].
+    ///
+    /// # Returns
+    /// - `>=0`: The number of threads woken.
+    /// - [`Error::InvalidArgument`]: `futex` is not 4 byte aligned.
+    /// - [`Error::PermissionDenied`]: `futex` does not reference a valid
+    ///   read/write memory region in this processes' address space.
+    pub fn futex_wake(futex: *const u32, count: usize) -> isize;
+
+    /// Returns the current time
+    ///
+    /// Time is measured in ticks of the system's monotonic clock, the same
+    /// clock `deadline` arguments to other system calls are measured in.
+    ///
+    /// # Returns
+    /// - `>=0`: The current time in ticks.
+    pub fn get_time() -> i64;
+
+    /// Blocks the calling thread until `deadline`
+    ///
+    /// # Returns
+    /// - `0`: `deadline` has been reached.
+    /// - [`Error::Cancelled`]: The thread is being terminated.
+    pub fn sleep_until(deadline: u64) -> isize;
+
+    /// Yields the remainder of the calling thread's timeslice
+    ///
+    /// Gives other ready threads of the same priority a chance to run.
+    ///
+    /// # Returns
+    /// - `0`: On success.
+    pub fn yield_timeslice() -> isize;
+
+    /// Creates a thread in the calling process
+    ///
+    /// Starts a thread from the process' thread pool executing at `entry` with
+    /// `arg0` and `arg1` as its first two arguments.  The thread's stack is the
+    /// `stack_len` bytes at `stack` which must remain valid until the thread
+    /// has been joined.
+    ///
+    /// # Returns
+    /// - `>=0`: The handle of the new thread, for use with [`thread_join()`].
+    /// - [`Error::InvalidArgument`]: The stack region overflows the address
+    ///   space.
+    /// - [`Error::PermissionDenied`]: `stack` does not reference a valid
+    ///   read/write memory region or `entry` does not reference executable
+    ///   memory in this processes' address space.
+    /// - [`Error::ResourceExhausted`]: All threads in the process' thread pool
+    ///   are in use.
+    pub fn thread_create(
+        entry: usize,
+        stack: *mut u8,
+        stack_len: usize,
+        arg0: usize,
+        arg1: usize,
+    ) -> isize;
+
+    /// Waits for a thread created with [`thread_create()`] to exit
+    ///
+    /// On success the thread is returned to the process' thread pool and
+    /// `handle` is no longer valid.
+    ///
+    /// Unlike other system calls `deadline` is the first argument so that it
+    /// is passed in the same registers on all architectures.
+    ///
+    /// # Returns
+    /// - `0`: The thread has exited.
+    /// - [`Error::NotFound`]: `handle` does not refer to a running thread.
+    /// - [`Error::OutOfRange`]: `handle` is not a valid thread handle.
+    /// - [`Error::AlreadyExists`]: Another thread is already joining `handle`.
+    /// - [`Error::InvalidArgument`]: `handle` refers to the calling thread.
+    /// - [`Error::DeadlineExceeded`]: The thread did not exit before
+    ///   `deadline`.
+    pub fn thread_join(deadline: u64, handle: u32) -> isize;
+
+    /// Exits the calling thread
+    ///
+    /// Does not return.  Once the last thread of a process exits, all of the
+    /// process' object handles are closed.
+    pub fn thread_exit() -> isize;
+
+    /// Terminates the process referenced by a process object
+    ///
+    /// Asynchronously requests all threads of the process to exit.  Wait for
+    /// `Signals::READABLE` on `object_handle` to be notified once they have.
+    ///
+    /// # Returns
+    /// - `0`: Termination was successfully requested.
+    /// - [`Error::InvalidArgument`]: `object_handle` is not a valid process
+    ///   object or refers to the calling process.
+    /// - [`Error::FailedPrecondition`]: The process has already exited.
+    pub fn process_terminate(object_handle: u32) -> isize;
+
+    /// Restarts the process referenced by a process object
+    ///
+    /// Restarts the threads of an exited process from the process' entry
+    /// point.  Threads the process created with [`thread_create()`] are
+    /// returned to its thread pool.
+    ///
+    /// # Returns
+    /// - `0`: The process was restarted.
+    /// - [`Error::InvalidArgument`]: `object_handle` is not a valid process
+    ///   object.
+    /// - [`Error::FailedPrecondition`]: The process has not exited.
+    pub fn process_restart(object_handle: u32) -> isize;
+
+    /// Returns the exit status of the process referenced by a process object
+    ///
+    /// # Returns
+    /// - `0`: The process exited normally.
+    /// - `>0`: The [`Error`] the process exited with.
+    /// - [`Error::InvalidArgument`]: `object_handle` is not a valid process
+    ///   object.
+    /// - [`Error::FailedPrecondition`]: The process has not exited.
+    pub fn process_get_exit_status(object_handle: u32) -> isize;
+
+    /// Describes the fault which terminated the process referenced by a
+    /// process object
+    ///
+    /// The description is written to `fault`.  It is kept until the process
+    /// is restarted.
+    ///
+    /// # Returns
+    /// - `0`: On success.
+    /// - [`Error::InvalidArgument`]: `object_handle` is not a valid process
+    ///   object.
+    /// - [`Error::NotFound`]: The process was not terminated by a fault.
+    /// - [`Error::PermissionDenied`]: `fault` is not writable by the caller.
+    pub fn process_get_fault(object_handle: u32, fault: *mut ProcessFault) -> isize;
+
+    /// Checks in the calling thread with the kernel watchdog
+    ///
+    /// Threads configured with a `watchdog_interval_ms` must check in at least
+    /// once per interval or the system is reset.  Has no effect for other
+    /// threads.
+    ///
+    /// # Returns
+    /// - `0`: On success.
+    pub fn watchdog_check_in() -> isize;
+
+    /// Starts the timer referenced by a timer object
+    ///
+    /// The timer expires at `deadline` and, if `period` is non-zero, every
+    /// `period` ticks after that.  Setting an active timer replaces its
+    /// deadline and period.
+    ///
+    /// # Returns
+    /// - `0`: The timer was started.
+    /// - [`Error::InvalidArgument`]: `object_handle` is not a valid timer
+    ///   object or `period` is out of range.
+    pub fn timer_set(deadline: u64, period: u64, object_handle: u32) -> isize;
+
+    /// Stops the timer referenced by a timer object
+    ///
+    /// # Returns
+    /// - `0`: The timer was stopped.
+    /// - [`Error::InvalidArgument`]: `object_handle` is not a valid timer
+    ///   object.
+    pub fn timer_cancel(object_handle: u32) -> isize;
+
+    /// Perform a non-blocking read from a UART object
+    ///
+    /// Copies up to `buffer_len` received bytes into `buffer`.
+    ///
+    /// The maximum size of buffer that may be passed is `isize::MAX`.
+    ///
+    /// # Returns
+    /// - `>=0`: Number of bytes read.  `0` if no data was available.
+    /// - [`Error::InvalidArgument`]: `object_handle` is not a valid UART
+    ///   object.
+    /// - [`Error::PermissionDenied`]: `buffer` does not reference a valid memory
+    ///   region in this processes' address space.
+    pub fn uart_read(object_handle: u32, buffer: *mut u8, buffer_len: usize) -> isize;
+
+    /// Perform a non-blocking write to a UART object
+    ///
+    /// Queues as many bytes of `buffer` for transmission as fit in the UART's
+    /// write buffer.
+    ///
+    /// The maximum size of buffer that may be passed is `isize::MAX`.
+    ///
+    /// # Returns
+    /// - `>=0`: Number of bytes queued.  `0` if the write buffer was full.
+    /// - [`Error::InvalidArgument`]: `object_handle` is not a valid UART
+    ///   object.
+    /// - [`Error::PermissionDenied`]: `buffer` does not reference a valid memory
+    ///   region in this processes' address space.
+    pub fn uart_write(object_handle: u32, buffer: *const u8, buffer_len: usize) -> isize;
 }
 
 pub trait SysCallInterface {
-    fn object_wait(handle: u32, signal_mask: u32, deadline: u64) -> Result<Signals>;
+    fn object_wait(handle: u32, signal_mask: u32, deadline: u64) -> Result<WaitReturn>;
 
     #[expect(clippy::missing_safety_doc)]
     unsafe fn channel_transact(
@@ -541,8 +1026,74 @@ pub trait SysCallInterface {
         buffer_len: usize,
     ) -> Result<()>;
 
+    #[expect(clippy::missing_safety_doc)]
+    unsafe fn channel_async_transact(
+        handle: u32,
+        send_data: *const u8,
+        send_len: usize,
+        recv_data: *mut u8,
+        recv_len: usize,
+    ) -> Result<()>;
+
+    fn channel_async_cancel(handle: u32) -> Result<()>;
+
+    fn object_raise_peer_user_signal(handle: u32) -> Result<()>;
+
     fn interrupt_ack(object_handle: u32, signal_mask: Signals) -> Result<()>;
 
+    fn wait_group_add(
+        wait_group: u32,
+        object: u32,
+        signal_mask: Signals,
+        user_data: usize,
+    ) -> Result<()>;
+
+    fn wait_group_remove(wait_group: u32, object: u32) -> Result<()>;
+
+    fn futex_wait(futex: *const u32, expected: u32, deadline: u64) -> Result<()>;
+
+    fn futex_wake(futex: *const u32, count: usize) -> Result<u32>;
+
+    fn get_time() -> Result<u64>;
+
+    fn sleep_until(deadline: u64) -> Result<()>;
+
+    fn yield_timeslice() -> Result<()>;
+
+    #[expect(clippy::missing_safety_doc)]
+    unsafe fn thread_create(
+        entry: usize,
+        stack: *mut u8,
+        stack_len: usize,
+        arg0: usize,
+        arg1: usize,
+    ) -> Result<u32>;
+
+    fn thread_join(deadline: u64, handle: u32) -> Result<()>;
+
+    fn thread_exit() -> Result<()>;
+
+    fn process_terminate(object_handle: u32) -> Result<()>;
+
+    fn process_restart(object_handle: u32) -> Result<()>;
+
+    fn process_get_exit_status(object_handle: u32) -> Result<Result<()>>;
+
+    #[expect(clippy::missing_safety_doc)]
+    unsafe fn process_get_fault(object_handle: u32, fault: *mut ProcessFault) -> Result<()>;
+
+    fn watchdog_check_in() -> Result<()>;
+
+    fn timer_set(deadline: u64, period: u64, object_handle: u32) -> Result<()>;
+
+    fn timer_cancel(object_handle: u32) -> Result<()>;
+
+    #[expect(clippy::missing_safety_doc)]
+    unsafe fn uart_read(object_handle: u32, buffer: *mut u8, buffer_len: usize) -> Result<u32>;
+
+    #[expect(clippy::missing_safety_doc)]
+    unsafe fn uart_write(object_handle: u32, buffer: *const u8, buffer_len: usize) -> Result<u32>;
+
     fn debug_putc(a: u32) -> Result<u32>;
     // TODO: Consider adding an feature flagged PowerManager object and move
     // this shutdown call to it.
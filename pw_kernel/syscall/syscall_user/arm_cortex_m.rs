@@ -15,7 +15,9 @@
 use core::arch::naked_asm;
 
 use pw_status::Result;
-use syscall_defs::{Signals, SysCallId, SysCallInterface, SysCallReturnValue};
+use syscall_defs::{
+    ProcessFault, Signals, SysCallId, SysCallInterface, SysCallReturnValue, WaitReturn,
+};
 
 pub struct SysCall {}
 
@@ -100,6 +102,25 @@ macro_rules! syscall_asm {
         )
     };
 
+    ($id:ident, 5) => {
+        naked_asm!("
+            push  {{r4-r8, r11}}
+            mov   r11, {id}
+            mov   r4, r0
+            mov   r5, r1
+            mov   r6, r2
+            mov   r7, r3
+            ldr   r8, [sp, #(6 * 4)]
+            svc   0
+            mov   r0, r4
+            mov   r1, r5
+            pop  {{r4-r8, r11}}
+            bx lr
+            ",
+            id = const SysCallId::$id as u32
+        )
+    };
+
     ($id:ident, 2_u64) => {
         // The u64 arg here is naturally aligned so the 4 arg wrapper is used.
         syscall_asm!($id, 4)
@@ -138,6 +159,7 @@ macro_rules! syscall_veneer {
 }
 
 syscall_veneer!(ObjectWait, 2_u64, object_wait(handle: u32, signals: u32, deadline: u64));
+syscall_veneer!(ObjectRaisePeerUserSignal, 1, object_raise_peer_user_signal(handle: u32));
 syscall_veneer!(ChannelTransact, 5_u64, channel_transact(
     object_handle: u32,
     send_data: *const u8,
@@ -146,6 +168,14 @@ syscall_veneer!(ChannelTransact, 5_u64, channel_transact(
     recv_len: usize,
     deadline: u64
 ));
+syscall_veneer!(ChannelAsyncTransact, 5, channel_async_transact(
+    object_handle: u32,
+    send_data: *const u8,
+    send_len: usize,
+    recv_data: *mut u8,
+    recv_len: usize
+));
+syscall_veneer!(ChannelAsyncCancel, 1, channel_async_cancel(object_handle: u32));
 syscall_veneer!(ChannelRead, 4, channel_read(
     handle: u32,
     offset: usize,
@@ -158,6 +188,46 @@ syscall_veneer!(ChannelRespond, 3, channel_respond(
     buffer_len: usize
 ));
 syscall_veneer!(InterruptAck, 2, interrupt_ack(handle: u32, signal_mask: Signals));
+syscall_veneer!(WaitGroupAdd, 4, wait_group_add(
+    wait_group: u32,
+    object: u32,
+    signal_mask: Signals,
+    user_data: usize
+));
+syscall_veneer!(WaitGroupRemove, 2, wait_group_remove(wait_group: u32, object: u32));
+syscall_veneer!(FutexWait, 2_u64, futex_wait(futex: *const u32, expected: u32, deadline: u64));
+syscall_veneer!(FutexWake, 2, futex_wake(futex: *const u32, count: usize));
+syscall_veneer!(GetTime, 0, get_time());
+syscall_veneer!(SleepUntil, 2, sleep_until(deadline: u64));
+syscall_veneer!(YieldTimeslice, 0, yield_timeslice());
+syscall_veneer!(ThreadCreate, 5, thread_create(
+    entry: usize,
+    stack: *mut u8,
+    stack_len: usize,
+    arg0: usize,
+    arg1: usize
+));
+// The u64 deadline occupies r0-r1 leaving the handle in r2.
+syscall_veneer!(ThreadJoin, 3, thread_join(deadline: u64, handle: u32));
+syscall_veneer!(ThreadExit, 0, thread_exit());
+syscall_veneer!(ProcessTerminate, 1, process_terminate(object_handle: u32));
+syscall_veneer!(ProcessRestart, 1, process_restart(object_handle: u32));
+syscall_veneer!(ProcessGetExitStatus, 1, process_get_exit_status(object_handle: u32));
+syscall_veneer!(ProcessGetFault, 2, process_get_fault(object_handle: u32, fault: *mut ProcessFault));
+syscall_veneer!(WatchdogCheckIn, 0, watchdog_check_in());
+// The u64 arguments occupy r0-r3 leaving the handle on the stack.
+syscall_veneer!(TimerSet, 5, timer_set(deadline: u64, period: u64, object_handle: u32));
+syscall_veneer!(TimerCancel, 1, timer_cancel(object_handle: u32));
+syscall_veneer!(UartRead, 3, uart_read(
+    object_handle: u32,
+    buffer: *mut u8,
+    buffer_len: usize
+));
+syscall_veneer!(UartWrite, 3, uart_write(
+    object_handle: u32,
+    buffer: *const u8,
+    buffer_len: usize
+));
 syscall_veneer!(DebugPutc, 1, putc(a: u32));
 syscall_veneer!(DebugShutdown, 1, shutdown(a: u32));
 syscall_veneer!(DebugLog, 2, log(buffer: *const u8, buffer_len: usize));
@@ -166,8 +236,14 @@ syscall_veneer!(DebugTriggerInterrupt, 1, debug_trigger_interrupt(irq: u32));
 
 impl SysCallInterface for SysCall {
     #[inline(always)]
-    fn object_wait(handle: u32, signals: u32, deadline: u64) -> Result<Signals> {
-        SysCallReturnValue(unsafe { object_wait(handle, signals, deadline) }).to_result_signals()
+    fn object_wait(handle: u32, signals: u32, deadline: u64) -> Result<WaitReturn> {
+        SysCallReturnValue(unsafe { object_wait(handle, signals, deadline) })
+            .to_result_wait_return()
+    }
+
+    #[inline(always)]
+    fn object_raise_peer_user_signal(handle: u32) -> Result<()> {
+        SysCallReturnValue(unsafe { object_raise_peer_user_signal(handle) }).to_result_unit()
     }
 
     #[inline(always)]
@@ -185,6 +261,25 @@ impl SysCallInterface for SysCall {
         .to_result_u32()
     }
 
+    #[inline(always)]
+    unsafe fn channel_async_transact(
+        handle: u32,
+        send_data: *const u8,
+        send_len: usize,
+        recv_data: *mut u8,
+        recv_len: usize,
+    ) -> Result<()> {
+        SysCallReturnValue(unsafe {
+            channel_async_transact(handle, send_data, send_len, recv_data, recv_len)
+        })
+        .to_result_unit()
+    }
+
+    #[inline(always)]
+    fn channel_async_cancel(handle: u32) -> Result<()> {
+        SysCallReturnValue(unsafe { channel_async_cancel(handle) }).to_result_unit()
+    }
+
     #[inline(always)]
     unsafe fn channel_read(
         handle: u32,
@@ -206,6 +301,115 @@ impl SysCallInterface for SysCall {
         SysCallReturnValue(unsafe { interrupt_ack(handle, signal_mask) }).to_result_unit()
     }
 
+    #[inline(always)]
+    fn wait_group_add(
+        wait_group: u32,
+        object: u32,
+        signal_mask: Signals,
+        user_data: usize,
+    ) -> Result<()> {
+        SysCallReturnValue(unsafe { wait_group_add(wait_group, object, signal_mask, user_data) })
+            .to_result_unit()
+    }
+
+    #[inline(always)]
+    fn wait_group_remove(wait_group: u32, object: u32) -> Result<()> {
+        SysCallReturnValue(unsafe { wait_group_remove(wait_group, object) }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn futex_wait(futex: *const u32, expected: u32, deadline: u64) -> Result<()> {
+        SysCallReturnValue(unsafe { futex_wait(futex, expected, deadline) }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn futex_wake(futex: *const u32, count: usize) -> Result<u32> {
+        SysCallReturnValue(unsafe { futex_wake(futex, count) }).to_result_u32()
+    }
+
+    #[inline(always)]
+    fn get_time() -> Result<u64> {
+        SysCallReturnValue(unsafe { get_time() }).to_result_u64()
+    }
+
+    #[inline(always)]
+    fn sleep_until(deadline: u64) -> Result<()> {
+        SysCallReturnValue(unsafe { sleep_until(deadline) }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn yield_timeslice() -> Result<()> {
+        SysCallReturnValue(unsafe { yield_timeslice() }).to_result_unit()
+    }
+
+    #[inline(always)]
+    unsafe fn thread_create(
+        entry: usize,
+        stack: *mut u8,
+        stack_len: usize,
+        arg0: usize,
+        arg1: usize,
+    ) -> Result<u32> {
+        SysCallReturnValue(unsafe { thread_create(entry, stack, stack_len, arg0, arg1) })
+            .to_result_u32()
+    }
+
+    #[inline(always)]
+    fn thread_join(deadline: u64, handle: u32) -> Result<()> {
+        SysCallReturnValue(unsafe { thread_join(deadline, handle) }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn thread_exit() -> Result<()> {
+        SysCallReturnValue(unsafe { thread_exit() }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn process_terminate(object_handle: u32) -> Result<()> {
+        SysCallReturnValue(unsafe { process_terminate(object_handle) }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn process_restart(object_handle: u32) -> Result<()> {
+        SysCallReturnValue(unsafe { process_restart(object_handle) }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn process_get_exit_status(object_handle: u32) -> Result<Result<()>> {
+        SysCallReturnValue(unsafe { process_get_exit_status(object_handle) })
+            .to_result_exit_status()
+    }
+
+    #[inline(always)]
+    unsafe fn process_get_fault(object_handle: u32, fault: *mut ProcessFault) -> Result<()> {
+        SysCallReturnValue(unsafe { process_get_fault(object_handle, fault) }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn watchdog_check_in() -> Result<()> {
+        SysCallReturnValue(unsafe { watchdog_check_in() }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn timer_set(deadline: u64, period: u64, object_handle: u32) -> Result<()> {
+        SysCallReturnValue(unsafe { timer_set(deadline, period, object_handle) }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn timer_cancel(object_handle: u32) -> Result<()> {
+        SysCallReturnValue(unsafe { timer_cancel(object_handle) }).to_result_unit()
+    }
+
+    #[inline(always)]
+    unsafe fn uart_read(object_handle: u32, buffer: *mut u8, buffer_len: usize) -> Result<u32> {
+        SysCallReturnValue(unsafe { uart_read(object_handle, buffer, buffer_len) }).to_result_u32()
+    }
+
+    #[inline(always)]
+    unsafe fn uart_write(object_handle: u32, buffer: *const u8, buffer_len: usize) -> Result<u32> {
+        SysCallReturnValue(unsafe { uart_write(object_handle, buffer, buffer_len) }).to_result_u32()
+    }
+
     #[inline(always)]
     fn debug_putc(a: u32) -> Result<u32> {
         SysCallReturnValue(unsafe { putc(a) }).to_result_u32()
@@ -11,71 +11,335 @@
 // WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
 // License for the specific language governing permissions and limitations under
 // the License.
+
+//! System call transport for user space running on the host.
+//!
+//! On the host, apps are linked into the kernel's executable and their
+//! threads run in the kernel's address space.  A system call is therefore a
+//! plain function call: each veneer packs its arguments into a slice of `u64`
+//! and calls the kernel's `pw_kernel_host_syscall()` entry point.
+
 use pw_status::Result;
-use syscall_defs::{Signals, SysCallInterface};
+use syscall_defs::{
+    ProcessFault, Signals, SysCallId, SysCallInterface, SysCallReturnValue, WaitReturn,
+};
 
 pub struct SysCall {}
 
+unsafe extern "Rust" {
+    // Provided by `arch_host`.
+    fn pw_kernel_host_syscall(id: u16, args: &[u64]) -> i64;
+}
+
+// Conversion of a system call argument into a `u64` argument slot.
+trait HostSyscallArg {
+    fn into_arg(self) -> u64;
+}
+
+impl HostSyscallArg for u32 {
+    fn into_arg(self) -> u64 {
+        u64::from(self)
+    }
+}
+
+impl HostSyscallArg for u64 {
+    fn into_arg(self) -> u64 {
+        self
+    }
+}
+
+impl HostSyscallArg for usize {
+    fn into_arg(self) -> u64 {
+        self as u64
+    }
+}
+
+impl<T> HostSyscallArg for *const T {
+    fn into_arg(self) -> u64 {
+        self.expose_provenance() as u64
+    }
+}
+
+impl<T> HostSyscallArg for *mut T {
+    fn into_arg(self) -> u64 {
+        self.expose_provenance() as u64
+    }
+}
+
+impl HostSyscallArg for Signals {
+    fn into_arg(self) -> u64 {
+        u64::from(self.bits())
+    }
+}
+
+macro_rules! syscall_veneer {
+    ($id:ident, $name:ident($($arg_name:ident: $arg_type:ty),* $(,)?)) => {
+        unsafe fn $name($($arg_name: $arg_type),*) -> i64 {
+            unsafe { pw_kernel_host_syscall(SysCallId::$id as u16, &[$($arg_name.into_arg()),*]) }
+        }
+    };
+}
+
+syscall_veneer!(ObjectWait, object_wait(handle: u32, signals: u32, deadline: u64));
+syscall_veneer!(ObjectRaisePeerUserSignal, object_raise_peer_user_signal(handle: u32));
+syscall_veneer!(ChannelTransact, channel_transact(
+    object_handle: u32,
+    send_data: *const u8,
+    send_len: usize,
+    recv_data: *mut u8,
+    recv_len: usize,
+    deadline: u64,
+));
+syscall_veneer!(ChannelAsyncTransact, channel_async_transact(
+    object_handle: u32,
+    send_data: *const u8,
+    send_len: usize,
+    recv_data: *mut u8,
+    recv_len: usize,
+));
+syscall_veneer!(ChannelAsyncCancel, channel_async_cancel(object_handle: u32));
+syscall_veneer!(ChannelRead, channel_read(
+     object_handle: u32,
+     offset: usize,
+     buffer: *mut u8,
+     buffer_len: usize,
+));
+syscall_veneer!(ChannelRespond, channel_respond(object_handle: u32, buffer: *const u8, buffer_len: usize));
+syscall_veneer!(InterruptAck, interrupt_ack(object_handle: u32, signal_mask: Signals));
+syscall_veneer!(WaitGroupAdd, wait_group_add(
+    wait_group: u32,
+    object: u32,
+    signal_mask: Signals,
+    user_data: usize,
+));
+syscall_veneer!(WaitGroupRemove, wait_group_remove(wait_group: u32, object: u32));
+syscall_veneer!(FutexWait, futex_wait(futex: *const u32, expected: u32, deadline: u64));
+syscall_veneer!(FutexWake, futex_wake(futex: *const u32, count: usize));
+syscall_veneer!(GetTime, get_time());
+syscall_veneer!(SleepUntil, sleep_until(deadline: u64));
+syscall_veneer!(YieldTimeslice, yield_timeslice());
+syscall_veneer!(ThreadCreate, thread_create(
+    entry: usize,
+    stack: *mut u8,
+    stack_len: usize,
+    arg0: usize,
+    arg1: usize,
+));
+syscall_veneer!(ThreadJoin, thread_join(deadline: u64, handle: u32));
+syscall_veneer!(ThreadExit, thread_exit());
+syscall_veneer!(ProcessTerminate, process_terminate(object_handle: u32));
+syscall_veneer!(ProcessRestart, process_restart(object_handle: u32));
+syscall_veneer!(ProcessGetExitStatus, process_get_exit_status(object_handle: u32));
+syscall_veneer!(ProcessGetFault, process_get_fault(object_handle: u32, fault: *mut ProcessFault));
+syscall_veneer!(WatchdogCheckIn, watchdog_check_in());
+syscall_veneer!(TimerSet, timer_set(deadline: u64, period: u64, object_handle: u32));
+syscall_veneer!(TimerCancel, timer_cancel(object_handle: u32));
+syscall_veneer!(UartRead, uart_read(object_handle: u32, buffer: *mut u8, buffer_len: usize));
+syscall_veneer!(UartWrite, uart_write(object_handle: u32, buffer: *const u8, buffer_len: usize));
+syscall_veneer!(DebugPutc, putc(a: u32));
+syscall_veneer!(DebugShutdown, shutdown(a: u32));
+syscall_veneer!(DebugLog, log(buffer: *const u8, buffer_len: usize));
+syscall_veneer!(DebugNop, nop());
+syscall_veneer!(DebugTriggerInterrupt, debug_trigger_interrupt(irq: u32));
+
 impl SysCallInterface for SysCall {
     #[inline(always)]
-    fn object_wait(_: u32, _: u32, _: u64) -> Result<Signals> {
-        Err(pw_status::Error::Unimplemented)
+    fn object_wait(handle: u32, signals: u32, deadline: u64) -> Result<WaitReturn> {
+        SysCallReturnValue(unsafe { object_wait(handle, signals, deadline) })
+            .to_result_wait_return()
+    }
+
+    #[inline(always)]
+    fn object_raise_peer_user_signal(handle: u32) -> Result<()> {
+        SysCallReturnValue(unsafe { object_raise_peer_user_signal(handle) }).to_result_unit()
     }
 
     #[inline(always)]
     unsafe fn channel_transact(
-        _handle: u32,
-        _send_data: *const u8,
-        _send_len: usize,
-        _recv_data: *mut u8,
-        _recv_len: usize,
-        _deadline: u64,
+        handle: u32,
+        send_data: *const u8,
+        send_len: usize,
+        recv_data: *mut u8,
+        recv_len: usize,
+        deadline: u64,
     ) -> Result<u32> {
-        Err(pw_status::Error::Unimplemented)
+        SysCallReturnValue(unsafe {
+            channel_transact(handle, send_data, send_len, recv_data, recv_len, deadline)
+        })
+        .to_result_u32()
+    }
+
+    #[inline(always)]
+    unsafe fn channel_async_transact(
+        handle: u32,
+        send_data: *const u8,
+        send_len: usize,
+        recv_data: *mut u8,
+        recv_len: usize,
+    ) -> Result<()> {
+        SysCallReturnValue(unsafe {
+            channel_async_transact(handle, send_data, send_len, recv_data, recv_len)
+        })
+        .to_result_unit()
+    }
+
+    #[inline(always)]
+    fn channel_async_cancel(handle: u32) -> Result<()> {
+        SysCallReturnValue(unsafe { channel_async_cancel(handle) }).to_result_unit()
     }
 
     #[inline(always)]
     unsafe fn channel_read(
-        _handle: u32,
-        _offset: usize,
-        _buffer: *mut u8,
-        _buffer_len: usize,
+        handle: u32,
+        offset: usize,
+        buffer: *mut u8,
+        buffer_len: usize,
+    ) -> Result<u32> {
+        SysCallReturnValue(unsafe { channel_read(handle, offset, buffer, buffer_len) })
+            .to_result_u32()
+    }
+
+    #[inline(always)]
+    unsafe fn channel_respond(handle: u32, buffer: *const u8, buffer_len: usize) -> Result<()> {
+        SysCallReturnValue(unsafe { channel_respond(handle, buffer, buffer_len) }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn interrupt_ack(handle: u32, signal_mask: Signals) -> Result<()> {
+        SysCallReturnValue(unsafe { interrupt_ack(handle, signal_mask) }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn wait_group_add(
+        wait_group: u32,
+        object: u32,
+        signal_mask: Signals,
+        user_data: usize,
+    ) -> Result<()> {
+        SysCallReturnValue(unsafe { wait_group_add(wait_group, object, signal_mask, user_data) })
+            .to_result_unit()
+    }
+
+    #[inline(always)]
+    fn wait_group_remove(wait_group: u32, object: u32) -> Result<()> {
+        SysCallReturnValue(unsafe { wait_group_remove(wait_group, object) }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn futex_wait(futex: *const u32, expected: u32, deadline: u64) -> Result<()> {
+        SysCallReturnValue(unsafe { futex_wait(futex, expected, deadline) }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn futex_wake(futex: *const u32, count: usize) -> Result<u32> {
+        SysCallReturnValue(unsafe { futex_wake(futex, count) }).to_result_u32()
+    }
+
+    #[inline(always)]
+    fn get_time() -> Result<u64> {
+        SysCallReturnValue(unsafe { get_time() }).to_result_u64()
+    }
+
+    #[inline(always)]
+    fn sleep_until(deadline: u64) -> Result<()> {
+        SysCallReturnValue(unsafe { sleep_until(deadline) }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn yield_timeslice() -> Result<()> {
+        SysCallReturnValue(unsafe { yield_timeslice() }).to_result_unit()
+    }
+
+    #[inline(always)]
+    unsafe fn thread_create(
+        entry: usize,
+        stack: *mut u8,
+        stack_len: usize,
+        arg0: usize,
+        arg1: usize,
     ) -> Result<u32> {
-        Err(pw_status::Error::Unimplemented)
+        SysCallReturnValue(unsafe { thread_create(entry, stack, stack_len, arg0, arg1) })
+            .to_result_u32()
+    }
+
+    #[inline(always)]
+    fn thread_join(deadline: u64, handle: u32) -> Result<()> {
+        SysCallReturnValue(unsafe { thread_join(deadline, handle) }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn thread_exit() -> Result<()> {
+        SysCallReturnValue(unsafe { thread_exit() }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn process_terminate(object_handle: u32) -> Result<()> {
+        SysCallReturnValue(unsafe { process_terminate(object_handle) }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn process_restart(object_handle: u32) -> Result<()> {
+        SysCallReturnValue(unsafe { process_restart(object_handle) }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn process_get_exit_status(object_handle: u32) -> Result<Result<()>> {
+        SysCallReturnValue(unsafe { process_get_exit_status(object_handle) })
+            .to_result_exit_status()
+    }
+
+    #[inline(always)]
+    unsafe fn process_get_fault(object_handle: u32, fault: *mut ProcessFault) -> Result<()> {
+        SysCallReturnValue(unsafe { process_get_fault(object_handle, fault) }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn watchdog_check_in() -> Result<()> {
+        SysCallReturnValue(unsafe { watchdog_check_in() }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn timer_set(deadline: u64, period: u64, object_handle: u32) -> Result<()> {
+        SysCallReturnValue(unsafe { timer_set(deadline, period, object_handle) }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn timer_cancel(object_handle: u32) -> Result<()> {
+        SysCallReturnValue(unsafe { timer_cancel(object_handle) }).to_result_unit()
     }
 
     #[inline(always)]
-    unsafe fn channel_respond(_handle: u32, _buffer: *const u8, _buffer_len: usize) -> Result<()> {
-        Err(pw_status::Error::Unimplemented)
+    unsafe fn uart_read(object_handle: u32, buffer: *mut u8, buffer_len: usize) -> Result<u32> {
+        SysCallReturnValue(unsafe { uart_read(object_handle, buffer, buffer_len) }).to_result_u32()
     }
 
     #[inline(always)]
-    fn interrupt_ack(_handle: u32, _signal_mask: Signals) -> Result<()> {
-        Err(pw_status::Error::Unimplemented)
+    unsafe fn uart_write(object_handle: u32, buffer: *const u8, buffer_len: usize) -> Result<u32> {
+        SysCallReturnValue(unsafe { uart_write(object_handle, buffer, buffer_len) }).to_result_u32()
     }
 
     #[inline(always)]
-    fn debug_putc(_a: u32) -> Result<u32> {
-        Err(pw_status::Error::Unimplemented)
+    fn debug_putc(a: u32) -> Result<u32> {
+        SysCallReturnValue(unsafe { putc(a) }).to_result_u32()
     }
 
     #[inline(always)]
-    fn debug_shutdown(_a: u32) -> Result<()> {
-        Err(pw_status::Error::Unimplemented)
+    fn debug_shutdown(a: u32) -> Result<()> {
+        SysCallReturnValue(unsafe { shutdown(a) }).to_result_unit()
     }
 
     #[inline(always)]
-    unsafe fn debug_log(_buffer: *const u8, _buffer_len: usize) -> Result<()> {
-        Err(pw_status::Error::Unimplemented)
+    unsafe fn debug_log(buffer: *const u8, buffer_len: usize) -> Result<()> {
+        SysCallReturnValue(unsafe { log(buffer, buffer_len) }).to_result_unit()
     }
 
     #[inline(always)]
     fn debug_nop() -> Result<()> {
-        Err(pw_status::Error::Unimplemented)
+        SysCallReturnValue(unsafe { nop() }).to_result_unit()
     }
 
     #[inline(always)]
-    fn debug_trigger_interrupt(_irq: u32) -> Result<()> {
-        Err(pw_status::Error::Unimplemented)
+    fn debug_trigger_interrupt(irq: u32) -> Result<()> {
+        SysCallReturnValue(unsafe { debug_trigger_interrupt(irq) }).to_result_unit()
     }
 }
@@ -15,7 +15,9 @@
 use core::arch::naked_asm;
 
 use pw_status::Result;
-use syscall_defs::{Signals, SysCallId, SysCallInterface, SysCallReturnValue};
+use syscall_defs::{
+    ProcessFault, Signals, SysCallId, SysCallInterface, SysCallReturnValue, WaitReturn,
+};
 
 pub struct SysCall {}
 
@@ -35,6 +37,7 @@ macro_rules! syscall_veneer {
 }
 
 syscall_veneer!(ObjectWait, object_wait(handle: u32, signals: u32, deadline: u64));
+syscall_veneer!(ObjectRaisePeerUserSignal, object_raise_peer_user_signal(handle: u32));
 syscall_veneer!(ChannelTransact, channel_transact(
     object_handle: u32, // a0
     send_data: *const u8, // a1
@@ -43,6 +46,14 @@ syscall_veneer!(ChannelTransact, channel_transact(
     recv_len: usize,    // a4
     deadline: u64,      // a6-a7
 ));
+syscall_veneer!(ChannelAsyncTransact, channel_async_transact(
+    object_handle: u32,
+    send_data: *const u8,
+    send_len: usize,
+    recv_data: *mut u8,
+    recv_len: usize,
+));
+syscall_veneer!(ChannelAsyncCancel, channel_async_cancel(object_handle: u32));
 syscall_veneer!(ChannelRead, channel_read(
      object_handle: u32,
      offset: usize,
@@ -51,6 +62,36 @@ syscall_veneer!(ChannelRead, channel_read(
 ));
 syscall_veneer!(ChannelRespond, channel_respond(object_handle: u32, buffer: *const u8, buffer_len: usize));
 syscall_veneer!(InterruptAck, interrupt_ack(object_handle: u32, signal_mask: Signals));
+syscall_veneer!(WaitGroupAdd, wait_group_add(
+    wait_group: u32,
+    object: u32,
+    signal_mask: Signals,
+    user_data: usize,
+));
+syscall_veneer!(WaitGroupRemove, wait_group_remove(wait_group: u32, object: u32));
+syscall_veneer!(FutexWait, futex_wait(futex: *const u32, expected: u32, deadline: u64));
+syscall_veneer!(FutexWake, futex_wake(futex: *const u32, count: usize));
+syscall_veneer!(GetTime, get_time());
+syscall_veneer!(SleepUntil, sleep_until(deadline: u64));
+syscall_veneer!(YieldTimeslice, yield_timeslice());
+syscall_veneer!(ThreadCreate, thread_create(
+    entry: usize,
+    stack: *mut u8,
+    stack_len: usize,
+    arg0: usize,
+    arg1: usize,
+));
+syscall_veneer!(ThreadJoin, thread_join(deadline: u64, handle: u32));
+syscall_veneer!(ThreadExit, thread_exit());
+syscall_veneer!(ProcessTerminate, process_terminate(object_handle: u32));
+syscall_veneer!(ProcessRestart, process_restart(object_handle: u32));
+syscall_veneer!(ProcessGetExitStatus, process_get_exit_status(object_handle: u32));
+syscall_veneer!(ProcessGetFault, process_get_fault(object_handle: u32, fault: *mut ProcessFault));
+syscall_veneer!(WatchdogCheckIn, watchdog_check_in());
+syscall_veneer!(TimerSet, timer_set(deadline: u64, period: u64, object_handle: u32));
+syscall_veneer!(TimerCancel, timer_cancel(object_handle: u32));
+syscall_veneer!(UartRead, uart_read(object_handle: u32, buffer: *mut u8, buffer_len: usize));
+syscall_veneer!(UartWrite, uart_write(object_handle: u32, buffer: *const u8, buffer_len: usize));
 syscall_veneer!(DebugPutc, putc(a: u32));
 syscall_veneer!(DebugShutdown, shutdown(a: u32));
 syscall_veneer!(DebugLog, log(buffer: *const u8, buffer_len: usize));
@@ -59,8 +100,14 @@ syscall_veneer!(DebugTriggerInterrupt, debug_trigger_interrupt(irq: u32));
 
 impl SysCallInterface for SysCall {
     #[inline(always)]
-    fn object_wait(handle: u32, signals: u32, deadline: u64) -> Result<Signals> {
-        SysCallReturnValue(unsafe { object_wait(handle, signals, deadline) }).to_result_signals()
+    fn object_wait(handle: u32, signals: u32, deadline: u64) -> Result<WaitReturn> {
+        SysCallReturnValue(unsafe { object_wait(handle, signals, deadline) })
+            .to_result_wait_return()
+    }
+
+    #[inline(always)]
+    fn object_raise_peer_user_signal(handle: u32) -> Result<()> {
+        SysCallReturnValue(unsafe { object_raise_peer_user_signal(handle) }).to_result_unit()
     }
 
     #[inline(always)]
@@ -78,6 +125,25 @@ impl SysCallInterface for SysCall {
         .to_result_u32()
     }
 
+    #[inline(always)]
+    unsafe fn channel_async_transact(
+        handle: u32,
+        send_data: *const u8,
+        send_len: usize,
+        recv_data: *mut u8,
+        recv_len: usize,
+    ) -> Result<()> {
+        SysCallReturnValue(unsafe {
+            channel_async_transact(handle, send_data, send_len, recv_data, recv_len)
+        })
+        .to_result_unit()
+    }
+
+    #[inline(always)]
+    fn channel_async_cancel(handle: u32) -> Result<()> {
+        SysCallReturnValue(unsafe { channel_async_cancel(handle) }).to_result_unit()
+    }
+
     #[inline(always)]
     unsafe fn channel_read(
         handle: u32,
@@ -99,6 +165,115 @@ impl SysCallInterface for SysCall {
         SysCallReturnValue(unsafe { interrupt_ack(handle, signal_mask) }).to_result_unit()
     }
 
+    #[inline(always)]
+    fn wait_group_add(
+        wait_group: u32,
+        object: u32,
+        signal_mask: Signals,
+        user_data: usize,
+    ) -> Result<()> {
+        SysCallReturnValue(unsafe { wait_group_add(wait_group, object, signal_mask, user_data) })
+            .to_result_unit()
+    }
+
+    #[inline(always)]
+    fn wait_group_remove(wait_group: u32, object: u32) -> Result<()> {
+        SysCallReturnValue(unsafe { wait_group_remove(wait_group, object) }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn futex_wait(futex: *const u32, expected: u32, deadline: u64) -> Result<()> {
+        SysCallReturnValue(unsafe { futex_wait(futex, expected, deadline) }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn futex_wake(futex: *const u32, count: usize) -> Result<u32> {
+        SysCallReturnValue(unsafe { futex_wake(futex, count) }).to_result_u32()
+    }
+
+    #[inline(always)]
+    fn get_time() -> Result<u64> {
+        SysCallReturnValue(unsafe { get_time() }).to_result_u64()
+    }
+
+    #[inline(always)]
+    fn sleep_until(deadline: u64) -> Result<()> {
+        SysCallReturnValue(unsafe { sleep_until(deadline) }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn yield_timeslice() -> Result<()> {
+        SysCallReturnValue(unsafe { yield_timeslice() }).to_result_unit()
+    }
+
+    #[inline(always)]
+    unsafe fn thread_create(
+        entry: usize,
+        stack: *mut u8,
+        stack_len: usize,
+        arg0: usize,
+        arg1: usize,
+    ) -> Result<u32> {
+        SysCallReturnValue(unsafe { thread_create(entry, stack, stack_len, arg0, arg1) })
+            .to_result_u32()
+    }
+
+    #[inline(always)]
+    fn thread_join(deadline: u64, handle: u32) -> Result<()> {
+        SysCallReturnValue(unsafe { thread_join(deadline, handle) }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn thread_exit() -> Result<()> {
+        SysCallReturnValue(unsafe { thread_exit() }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn process_terminate(object_handle: u32) -> Result<()> {
+        SysCallReturnValue(unsafe { process_terminate(object_handle) }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn process_restart(object_handle: u32) -> Result<()> {
+        SysCallReturnValue(unsafe { process_restart(object_handle) }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn process_get_exit_status(object_handle: u32) -> Result<Result<()>> {
+        SysCallReturnValue(unsafe { process_get_exit_status(object_handle) })
+            .to_result_exit_status()
+    }
+
+    #[inline(always)]
+    unsafe fn process_get_fault(object_handle: u32, fault: *mut ProcessFault) -> Result<()> {
+        SysCallReturnValue(unsafe { process_get_fault(object_handle, fault) }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn watchdog_check_in() -> Result<()> {
+        SysCallReturnValue(unsafe { watchdog_check_in() }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn timer_set(deadline: u64, period: u64, object_handle: u32) -> Result<()> {
+        SysCallReturnValue(unsafe { timer_set(deadline, period, object_handle) }).to_result_unit()
+    }
+
+    #[inline(always)]
+    fn timer_cancel(object_handle: u32) -> Result<()> {
+        SysCallReturnValue(unsafe { timer_cancel(object_handle) }).to_result_unit()
+    }
+
+    #[inline(always)]
+    unsafe fn uart_read(object_handle: u32, buffer: *mut u8, buffer_len: usize) -> Result<u32> {
+        SysCallReturnValue(unsafe { uart_read(object_handle, buffer, buffer_len) }).to_result_u32()
+    }
+
+    #[inline(always)]
+    unsafe fn uart_write(object_handle: u32, buffer: *const u8, buffer_len: usize) -> Result<u32> {
+        SysCallReturnValue(unsafe { uart_write(object_handle, buffer, buffer_len) }).to_result_u32()
+    }
+
     #[inline(always)]
     fn debug_putc(a: u32) -> Result<u32> {
         SysCallReturnValue(unsafe { putc(a) }).to_result_u32()
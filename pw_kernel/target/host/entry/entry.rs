@@ -0,0 +1,43 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+#![no_std]
+
+//! Entry point shared by host targets which boot the kernel.
+
+extern crate std;
+
+use arch_host::HostArch;
+use kernel::Arch as _;
+
+#[unsafe(no_mangle)]
+#[allow(non_snake_case)]
+pub extern "C" fn pw_assert_HandleFailure() -> ! {
+    HostArch::panic()
+}
+
+/// Boots the kernel on the calling OS thread.
+pub fn main() -> ! {
+    kernel::static_init_state!(static mut INIT_STATE: InitKernelState<HostArch>);
+
+    // SAFETY: `main` is only executed once, so we never generate more than one
+    // `&mut` reference to `INIT_STATE`.
+    #[allow(static_mut_refs)]
+    kernel::main(HostArch, unsafe { &mut INIT_STATE });
+}
+
+/// Exits the host process with `code`.
+pub fn shutdown(code: u32) -> ! {
+    std::process::exit(i32::try_from(code).unwrap_or(i32::MAX))
+}
@@ -0,0 +1,47 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+#![no_main]
+
+use arch_host::HostArch;
+use target_common::{TargetInterface, declare_target};
+use time::Instant;
+use {console_backend as _, test_interrupt_listener as _, test_interrupts as _};
+
+pub struct Target {}
+
+impl TargetInterface for Target {
+    const NAME: &'static str = "Host User Interrupts";
+
+    fn main() -> ! {
+        arch_host::set_interrupt_table(codegen::PW_KERNEL_INTERRUPT_TABLE);
+        codegen::start();
+        // A spinning thread is never preempted on the host, so sleep rather
+        // than loop.
+        loop {
+            let _ = kernel::scheduler::sleep_until(HostArch, Instant::MAX);
+        }
+    }
+
+    fn shutdown(code: u32) -> ! {
+        entry::shutdown(code)
+    }
+}
+
+declare_target!(Target);
+
+#[unsafe(no_mangle)]
+pub extern "C" fn main() -> core::ffi::c_int {
+    entry::main()
+}
@@ -0,0 +1,48 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+#![no_main]
+
+use arch_host::HostArch;
+use target_common::{TargetInterface, declare_target};
+use {console_backend as _, kernel as _};
+
+pub struct Target {}
+
+impl TargetInterface for Target {
+    const NAME: &'static str = "Host Kernel Threads";
+
+    fn main() -> ! {
+        static mut APP_STATE: threads::AppState<HostArch> = threads::AppState::new(HostArch);
+        // SAFETY: `main` is only executed once, so we never generate more
+        // than one `&mut` reference to `APP_STATE`.
+        #[expect(static_mut_refs)]
+        let exit_status = match threads::main(HostArch, unsafe { &mut APP_STATE }) {
+            Ok(()) => 0,
+            Err(_e) => 1,
+        };
+        Self::shutdown(exit_status)
+    }
+
+    fn shutdown(code: u32) -> ! {
+        entry::shutdown(code)
+    }
+}
+
+declare_target!(Target);
+
+#[unsafe(no_mangle)]
+pub extern "C" fn main() -> core::ffi::c_int {
+    entry::main()
+}
@@ -0,0 +1,46 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+#![no_main]
+
+use target_common::{TargetInterface, declare_target};
+use unittest_core::TestsResult;
+use {console_backend as _, integration_tests as _, kernel as _};
+
+pub struct Target {}
+
+impl TargetInterface for Target {
+    const NAME: &'static str = "Host Unittest Runner";
+
+    fn main() -> ! {
+        // Tests are registered by ELF constructors, which the host runs
+        // before `main`.
+        let exit_status = match unittest_core::run_all_tests!() {
+            TestsResult::AllPassed => 0,
+            TestsResult::SomeFailed => 1,
+        };
+        Self::shutdown(exit_status)
+    }
+
+    fn shutdown(code: u32) -> ! {
+        entry::shutdown(code)
+    }
+}
+
+declare_target!(Target);
+
+#[unsafe(no_mangle)]
+pub extern "C" fn main() -> core::ffi::c_int {
+    entry::main()
+}
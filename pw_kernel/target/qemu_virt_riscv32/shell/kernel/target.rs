@@ -0,0 +1,59 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+#![no_std]
+#![no_main]
+
+use arch_riscv::Arch;
+use kernel::Duration;
+use kernel::scheduler::stack_check;
+use kernel_config::Uart0Config;
+use kernel_uart::UartConfigInterface;
+use riscv_semihosting::debug::{EXIT_SUCCESS, exit};
+use target_common::{TargetInterface, declare_target};
+use {console_backend as _, entry as _};
+
+kernel_uart::declare_uarts!(Arch, UARTS, uart_16550_kernel::Uart, [
+    UART0: Uart0Config,
+]);
+
+// QEMU's virt machine has no reset, so `reboot` ends the session instead.
+fn reset() -> ! {
+    exit(EXIT_SUCCESS);
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
+
+codegen::declare_shell!(&[], reset);
+
+pub struct Target {}
+
+impl TargetInterface for Target {
+    const NAME: &'static str = "QEMU-VIRT-RISCV Kernel Shell";
+
+    fn main() -> ! {
+        uart_16550_kernel::init(&UARTS);
+        kernel::set_panic_flush_handler(flush_uarts);
+
+        // SAFETY: `main` is only executed once.
+        unsafe { start_shell() };
+
+        // The main thread goes on to warn about threads running low on
+        // stack, sleeping in between checks so the shell gets to run.
+        stack_check::run(Arch, Duration::from_secs(1))
+    }
+}
+
+codegen::declare_kernel_interrupt_handlers!();
+declare_target!(Target);
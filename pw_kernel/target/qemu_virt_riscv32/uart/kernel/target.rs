@@ -0,0 +1,74 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+#![no_std]
+#![no_main]
+
+use arch_riscv::Arch;
+use kernel_config::Uart0Config;
+use kernel_uart::UartConfigInterface;
+use pw_status::Result;
+use riscv_semihosting::debug::{EXIT_FAILURE, EXIT_SUCCESS, exit};
+use target_common::{TargetInterface, declare_target};
+use {codegen as _, console_backend as _, entry as _};
+
+pub struct Target {}
+struct TargetUart {}
+
+kernel_uart::declare_uarts!(Arch, UARTS, uart_16550_kernel::Uart, [
+    UART0: Uart0Config,
+]);
+
+impl test_uart::TestUart for TargetUart {
+    fn enable_loopback() {
+        UART0.enable_loopback()
+    }
+
+    fn read() -> Result<Option<u8>> {
+        UART0.read(Arch)
+    }
+
+    fn write(byte: u8) -> Result<()> {
+        UART0.write(byte)
+    }
+
+    fn write_all(data: &[u8]) -> Result<()> {
+        UART0.write_all(data)
+    }
+}
+
+impl TargetInterface for Target {
+    const NAME: &'static str = "QEMU-VIRT-RISCV Kernel UART";
+
+    fn main() -> ! {
+        static mut TEST_STATE: test_uart::TestState<Arch> = test_uart::TestState::new();
+
+        uart_16550_kernel::init(&UARTS);
+        kernel::set_panic_flush_handler(flush_uarts);
+
+        // SAFETY: `main` is only executed once, so we never generate more
+        // than one `&mut` reference to `TEST_STATE`.
+        #[expect(static_mut_refs)]
+        let exit_status =
+            match test_uart::main::<Arch, TargetUart>(Arch, unsafe { &mut TEST_STATE }) {
+                Ok(()) => EXIT_SUCCESS,
+                Err(_e) => EXIT_FAILURE,
+            };
+        exit(exit_status);
+        #[expect(clippy::empty_loop)]
+        loop {}
+    }
+}
+
+codegen::declare_kernel_interrupt_handlers!();
+declare_target!(Target);
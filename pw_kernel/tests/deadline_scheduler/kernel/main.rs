@@ -0,0 +1,196 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+#![no_std]
+
+//! Tests the deadline based scheduler algorithms on a running system.
+//!
+//! The target's platform selects the algorithm.  The test threads are chosen
+//! so that both earliest deadline first and rate monotonic scheduling give the
+//! same results:
+//! - A thread with a shorter relative deadline preempts one with a longer
+//!   relative deadline, even when its base priority is lower.
+//! - A thread which overruns its budget no longer runs ahead of threads
+//!   without a deadline.
+
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use kernel::scheduler::thread::{self, StackStorage, StackStorageExt as _, Thread};
+use kernel::{Duration, Kernel, Priority};
+use pw_log::{error, info};
+use pw_status::{Error, Result};
+
+const TEST_THREAD_STACK_SIZE: usize = 2048;
+
+/// Set by the short deadline thread once it runs.
+static SHORT_RAN: AtomicBool = AtomicBool::new(false);
+
+/// Set by the long deadline thread if the short deadline thread ran before
+/// `start_thread` returned.
+static SHORT_PREEMPTED_LONG: AtomicBool = AtomicBool::new(false);
+
+/// Set by the main thread once it runs while the hog thread is spinning.
+static MAIN_RAN: AtomicBool = AtomicBool::new(false);
+
+/// Set by the hog thread if it stops spinning without the main thread having
+/// run.
+static HOG_TIMED_OUT: AtomicBool = AtomicBool::new(false);
+
+pub struct ThreadStorage<K: Kernel> {
+    thread: Thread<K>,
+    stack: StackStorage<TEST_THREAD_STACK_SIZE>,
+}
+
+impl<K: Kernel> ThreadStorage<K> {
+    const fn new(name: &'static str) -> Self {
+        Self {
+            thread: Thread::new(name, Priority::DEFAULT_PRIORITY),
+            stack: StackStorage::ZEROED,
+        }
+    }
+}
+
+pub struct TestState<K: Kernel> {
+    long: ThreadStorage<K>,
+    short: ThreadStorage<K>,
+    hog: ThreadStorage<K>,
+}
+
+impl<K: Kernel> TestState<K> {
+    #[allow(clippy::new_without_default)]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            long: ThreadStorage::new("long deadline thread"),
+            short: ThreadStorage::new("short deadline thread"),
+            hog: ThreadStorage::new("hog thread"),
+        }
+    }
+}
+
+/// Runs the deadline scheduler tests, returning an error if any fail.
+///
+/// Must be called from a thread without a relative deadline.
+pub fn test_main<K: Kernel>(kernel: K, state: &'static mut TestState<K>) -> Result<()> {
+    let TestState { long, short, hog } = state;
+    test_shorter_deadline_preempts(kernel, long, short)?;
+    test_overrun_is_demoted(kernel, hog)?;
+    Ok(())
+}
+
+fn test_shorter_deadline_preempts<K: Kernel>(
+    kernel: K,
+    long: &'static mut ThreadStorage<K>,
+    short: &'static mut ThreadStorage<K>,
+) -> Result<()> {
+    info!("🔄 [Shorter Deadline Preempts] RUNNING");
+
+    let mut long_thread = thread::init_thread_in(
+        kernel,
+        &mut long.thread,
+        &mut long.stack,
+        "long deadline thread",
+        Priority::Level20,
+        long_thread_entry,
+        short,
+    );
+    long_thread.set_relative_deadline(Duration::from_millis(100));
+    let long_thread = kernel::start_thread(kernel, long_thread).join(kernel)?;
+    let _ = long_thread.consume();
+
+    if !SHORT_PREEMPTED_LONG.load(Ordering::SeqCst) {
+        info!("❌ ├─ FAILED");
+        error!("❌ └─ Short deadline thread did not preempt long deadline thread");
+        return Err(Error::Internal);
+    }
+
+    info!("✅ └─ PASSED");
+    Ok(())
+}
+
+fn long_thread_entry<K: Kernel>(kernel: K, short: &'static mut ThreadStorage<K>) {
+    // The short deadline thread has a lower base priority, so it only
+    // preempts this thread if it is scheduled by deadline.
+    let mut short_thread = thread::init_thread_in(
+        kernel,
+        &mut short.thread,
+        &mut short.stack,
+        "short deadline thread",
+        Priority::Level10,
+        short_thread_entry,
+        0,
+    );
+    short_thread.set_relative_deadline(Duration::from_millis(10));
+    let short_thread_ref = kernel::start_thread(kernel, short_thread);
+    SHORT_PREEMPTED_LONG.store(SHORT_RAN.load(Ordering::SeqCst), Ordering::SeqCst);
+
+    match short_thread_ref.join(kernel) {
+        Ok(short_thread) => {
+            let _ = short_thread.consume();
+        }
+        Err(e) => error!("Failed to join short deadline thread: {}", e as u32),
+    }
+}
+
+fn short_thread_entry<K: Kernel>(_kernel: K, _arg: usize) {
+    SHORT_RAN.store(true, Ordering::SeqCst);
+}
+
+fn test_overrun_is_demoted<K: Kernel>(kernel: K, hog: &'static mut ThreadStorage<K>) -> Result<()> {
+    info!("🔄 [Overrun Is Demoted] RUNNING");
+
+    // The hog thread runs ahead of this thread until it uses up its budget.
+    // It is then scheduled by its base priority, below this thread's.
+    let mut hog_thread = thread::init_thread_in(
+        kernel,
+        &mut hog.thread,
+        &mut hog.stack,
+        "hog thread",
+        Priority::Level1,
+        hog_thread_entry,
+        0,
+    );
+    hog_thread.set_relative_deadline(Duration::from_millis(100));
+    hog_thread.set_budget(Duration::from_millis(5));
+    let hog_thread_ref = kernel::start_thread(kernel, hog_thread);
+    MAIN_RAN.store(true, Ordering::SeqCst);
+
+    let hog_thread = hog_thread_ref.join(kernel)?;
+    let overruns = hog_thread.algorithm_state.overruns();
+    let _ = hog_thread.consume();
+
+    if HOG_TIMED_OUT.load(Ordering::SeqCst) {
+        info!("❌ ├─ FAILED");
+        error!("❌ └─ Hog thread was not demoted after overrunning its budget");
+        return Err(Error::Internal);
+    }
+    if overruns == 0 {
+        info!("❌ ├─ FAILED");
+        error!("❌ └─ Hog thread overrun was not counted");
+        return Err(Error::Internal);
+    }
+
+    info!("✅ └─ PASSED");
+    Ok(())
+}
+
+fn hog_thread_entry<K: Kernel>(kernel: K, _arg: usize) {
+    let timeout = kernel.now() + Duration::from_millis(500);
+    while !MAIN_RAN.load(Ordering::SeqCst) {
+        if kernel.now() >= timeout {
+            HOG_TIMED_OUT.store(true, Ordering::SeqCst);
+            return;
+        }
+    }
+}
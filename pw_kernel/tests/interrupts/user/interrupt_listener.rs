@@ -67,7 +67,7 @@ fn entry() -> ! {
     loop {
         let res =
             match syscall::object_wait(handle::TEST_INTERRUPTS, signals::TEST_IRQ, Instant::MAX) {
-                Ok(interrupts) => handle_interrupt(interrupts),
+                Ok(wait_return) => handle_interrupt(wait_return.pending_signals),
                 Err(err) => {
                     pw_log::error!("Failed to wait on interrupt");
                     Err(err)
@@ -80,6 +80,9 @@ fn entry() -> ! {
     }
 }
 
+// On the host, the app is linked into the kernel's executable, which provides
+// the panic handler.
+#[cfg(target_os = "none")]
 #[panic_handler]
 fn panic(_info: &core::panic::PanicInfo) -> ! {
     loop {}
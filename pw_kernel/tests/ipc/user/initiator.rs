@@ -97,6 +97,9 @@ fn entry() -> ! {
     loop {}
 }
 
+// On the host, the app is linked into the kernel's executable, which provides
+// the panic handler.
+#[cfg(target_os = "none")]
 #[panic_handler]
 fn panic(_info: &core::panic::PanicInfo) -> ! {
     loop {}
@@ -0,0 +1,38 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+#![no_main]
+#![no_std]
+
+use userspace::entry;
+
+// Address outside of the app's memory.  Kept in sync with `FAULT_ADDRESS` in
+// `supervisor.rs`.
+const FAULT_ADDRESS: usize = 0x3800_0000;
+
+#[entry]
+fn entry() -> ! {
+    pw_log::info!("Faulter writing to {:#010x}", FAULT_ADDRESS as usize);
+
+    // SAFETY: This is intended to fault, terminating the process.
+    unsafe { core::ptr::with_exposed_provenance_mut::<u32>(FAULT_ADDRESS).write_volatile(0) };
+
+    pw_log::error!("Faulter survived its fault");
+    loop {}
+}
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    loop {}
+}
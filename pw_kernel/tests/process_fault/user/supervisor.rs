@@ -0,0 +1,78 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+#![no_main]
+#![no_std]
+
+use app_supervisor::handle;
+use pw_status::{Error, Result, StatusCode};
+use userspace::entry;
+use userspace::syscall::{self, Signals};
+use userspace::time::Instant;
+
+// Address the faulter app writes to.  Kept in sync with `FAULT_ADDRESS` in
+// `faulter.rs`.
+const FAULT_ADDRESS: usize = 0x3800_0000;
+
+fn test_process_fault() -> Result<()> {
+    pw_log::info!("Waiting for the faulter to exit");
+    syscall::object_wait(handle::FAULTER, Signals::READABLE, Instant::MAX)?;
+
+    // A fault terminates the process with `Error::Internal`.
+    if syscall::process_get_exit_status(handle::FAULTER)? != Err(Error::Internal) {
+        pw_log::error!("Faulter exited with an unexpected status");
+        return Err(Error::Unknown);
+    }
+
+    let fault = syscall::process_get_fault(handle::FAULTER)?;
+    pw_log::info!(
+        "Faulter faulted: pc {:#010x} cause {:#010x} address {:#010x}",
+        fault.pc as usize,
+        fault.cause as usize,
+        fault.address as usize
+    );
+    if fault.pc().is_none() {
+        pw_log::error!("Fault report is missing the program counter");
+        return Err(Error::Unknown);
+    }
+    if fault.address() != Some(FAULT_ADDRESS) {
+        pw_log::error!("Fault report has the wrong address");
+        return Err(Error::Unknown);
+    }
+
+    Ok(())
+}
+
+#[entry]
+fn entry() -> ! {
+    pw_log::info!("🔄 RUNNING");
+
+    let ret = test_process_fault();
+
+    // Log that an error occurred so that the app that caused the shutdown is logged.
+    if ret.is_err() {
+        pw_log::error!("❌ FAILED: {}", ret.status_code() as u32);
+    } else {
+        pw_log::info!("✅ PASSED");
+    }
+
+    // Since this is written as a test, shut down with the return status from `main()`.
+    let _ = syscall::debug_shutdown(ret);
+    loop {}
+}
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    loop {}
+}
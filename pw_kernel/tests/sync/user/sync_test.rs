@@ -0,0 +1,260 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+#![no_main]
+#![no_std]
+
+//! Tests the user space synchronization primitives with several threads of
+//! one process contending for them.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use pw_status::{Error, Result, StatusCode};
+use userspace::sync::{Condvar, Mutex, Once};
+use userspace::time::{self, Duration, Instant};
+use userspace::{entry, syscall, thread};
+
+const WORKERS: usize = 2;
+const WORKER_STACK_SIZE: usize = 1024;
+
+#[repr(align(16))]
+struct WorkerStack([u8; WORKER_STACK_SIZE]);
+
+static mut WORKER_STACKS: [WorkerStack; WORKERS] =
+    [const { WorkerStack([0; WORKER_STACK_SIZE]) }; WORKERS];
+
+type Stacks = [Option<&'static mut [u8]>; WORKERS];
+
+/// Incremented by a worker thread for each check that fails.
+static WORKER_FAILURES: AtomicU32 = AtomicU32::new(0);
+
+fn worker_check(condition: bool, message: &'static str) {
+    if !condition {
+        pw_log::error!("Worker check failed: {}", message as &str);
+        WORKER_FAILURES.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+/// Runs `entry` on `WORKERS` threads, calls `main_thread` while they run, and
+/// waits for them to exit.
+fn run_workers(
+    stacks: &mut Stacks,
+    entry: fn(usize),
+    main_thread: impl FnOnce() -> Result<()>,
+) -> Result<()> {
+    let mut handles = [const { None }; WORKERS];
+    for (index, (stack, handle)) in stacks.iter_mut().zip(&mut handles).enumerate() {
+        let stack = stack.take().ok_or(Error::Internal)?;
+        *handle = Some(thread::spawn(stack, entry, index)?);
+    }
+
+    let ret = main_thread();
+
+    for (stack, handle) in stacks.iter_mut().zip(&mut handles) {
+        if let Some(handle) = handle.take() {
+            *stack = Some(handle.join()?);
+        }
+    }
+    ret?;
+
+    if WORKER_FAILURES.load(Ordering::SeqCst) != 0 {
+        return Err(Error::Unknown);
+    }
+    Ok(())
+}
+
+// Gives threads which are about to block time to do so.
+fn let_workers_block() -> Result<()> {
+    time::sleep_for(Duration::from_millis(10))
+}
+
+const HANDOFF_VALUE: u32 = 100;
+
+static COUNTER: Mutex<u32> = Mutex::new(0);
+
+fn mutex_waiter(_index: usize) {
+    let mut counter = COUNTER.lock();
+    worker_check(
+        *counter >= HANDOFF_VALUE,
+        "mutex acquired before it was released",
+    );
+    *counter += 1;
+}
+
+fn test_mutex_contended_handoff(stacks: &mut Stacks) -> Result<()> {
+    pw_log::info!("Mutex contended handoff");
+
+    // Held before the workers start, so both block on it.
+    let mut counter = COUNTER.lock();
+    run_workers(stacks, mutex_waiter, move || {
+        let_workers_block()?;
+        *counter = HANDOFF_VALUE;
+        drop(counter);
+        Ok(())
+    })?;
+
+    let counter = *COUNTER.lock();
+    if counter != HANDOFF_VALUE + WORKERS as u32 {
+        pw_log::error!("Counter is {}", counter as u32);
+        return Err(Error::Unknown);
+    }
+    Ok(())
+}
+
+struct WaiterCounts {
+    waiting: u32,
+    notified: u32,
+    timed_out: u32,
+}
+
+static WAITERS: Mutex<WaiterCounts> = Mutex::new(WaiterCounts {
+    waiting: 0,
+    notified: 0,
+    timed_out: 0,
+});
+static CONDVAR: Condvar = Condvar::new();
+
+// Long enough for the main thread to notify the waiters, short enough for the
+// waiters which are not notified to time out quickly.
+const CONDVAR_TIMEOUT: Duration = Duration::from_millis(100);
+
+fn condvar_waiter(_index: usize) {
+    let mut waiters = WAITERS.lock();
+    waiters.waiting += 1;
+    let deadline = syscall::get_time()
+        .ok()
+        .and_then(|now| now.checked_add_duration(CONDVAR_TIMEOUT))
+        .unwrap_or(Instant::MAX);
+    let (mut waiters, ret) = CONDVAR.wait_until(waiters, deadline);
+    match ret {
+        Ok(()) => waiters.notified += 1,
+        Err(Error::DeadlineExceeded) => waiters.timed_out += 1,
+        Err(_) => worker_check(false, "condvar wait failed"),
+    }
+}
+
+fn test_condvar_notify(
+    stacks: &mut Stacks,
+    notify: fn(&Condvar),
+    expected_notified: u32,
+) -> Result<()> {
+    *WAITERS.lock() = WaiterCounts {
+        waiting: 0,
+        notified: 0,
+        timed_out: 0,
+    };
+
+    run_workers(stacks, condvar_waiter, || {
+        // Waiters only release the mutex from within `wait_until()`.
+        while WAITERS.lock().waiting < WORKERS as u32 {
+            let_workers_block()?;
+        }
+        let_workers_block()?;
+        notify(&CONDVAR);
+        Ok(())
+    })?;
+
+    let waiters = WAITERS.lock();
+    if waiters.notified != expected_notified
+        || waiters.timed_out != WORKERS as u32 - expected_notified
+    {
+        pw_log::error!(
+            "{} waiters notified and {} timed out",
+            waiters.notified as u32,
+            waiters.timed_out as u32
+        );
+        return Err(Error::Unknown);
+    }
+    Ok(())
+}
+
+fn test_condvar_notify_one(stacks: &mut Stacks) -> Result<()> {
+    pw_log::info!("Condvar notify_one");
+    test_condvar_notify(stacks, Condvar::notify_one, 1)
+}
+
+fn test_condvar_notify_all(stacks: &mut Stacks) -> Result<()> {
+    pw_log::info!("Condvar notify_all");
+    test_condvar_notify(stacks, Condvar::notify_all, WORKERS as u32)
+}
+
+static ONCE: Once = Once::new();
+static ONCE_CALLS: AtomicU32 = AtomicU32::new(0);
+
+// Returns `true` if the initialization has run exactly once by the time
+// `call_once()` returns.
+fn race_to_initialize() -> bool {
+    ONCE.call_once(|| {
+        ONCE_CALLS.fetch_add(1, Ordering::SeqCst);
+        // Keep the initialization running while the other threads arrive.
+        let _ = let_workers_block();
+    });
+    ONCE.is_completed() && ONCE_CALLS.load(Ordering::SeqCst) == 1
+}
+
+fn once_racer(_index: usize) {
+    worker_check(race_to_initialize(), "once initialization not run once");
+}
+
+fn test_once_runs_once(stacks: &mut Stacks) -> Result<()> {
+    pw_log::info!("Once under races");
+
+    run_workers(stacks, once_racer, || {
+        if race_to_initialize() {
+            Ok(())
+        } else {
+            Err(Error::Unknown)
+        }
+    })?;
+
+    if ONCE_CALLS.load(Ordering::SeqCst) != 1 {
+        return Err(Error::Unknown);
+    }
+    Ok(())
+}
+
+fn run_tests() -> Result<()> {
+    // SAFETY: `run_tests()` is only called once, so this is the only
+    // reference to `WORKER_STACKS`.
+    #[allow(static_mut_refs)]
+    let [first, second] = unsafe { &mut WORKER_STACKS };
+    let mut stacks: Stacks = [Some(&mut first.0[..]), Some(&mut second.0[..])];
+
+    test_mutex_contended_handoff(&mut stacks)?;
+    test_condvar_notify_one(&mut stacks)?;
+    test_condvar_notify_all(&mut stacks)?;
+    test_once_runs_once(&mut stacks)?;
+    Ok(())
+}
+
+#[entry]
+fn entry() -> ! {
+    pw_log::info!("🔄 RUNNING");
+
+    let ret = run_tests();
+
+    if ret.is_err() {
+        pw_log::error!("❌ FAILED: {}", ret.status_code() as u32);
+    } else {
+        pw_log::info!("✅ PASSED");
+    }
+
+    let _ = syscall::debug_shutdown(ret);
+    loop {}
+}
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    loop {}
+}
@@ -13,20 +13,61 @@
 // the License.
 #![no_std]
 
-use kernel::Kernel;
-use pw_status::Result;
+use core::cell::UnsafeCell;
+use core::ptr;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use kernel::scheduler::thread::{self, StackStorage, StackStorageExt as _, Thread};
+use kernel::sync::event::{Event, EventConfig, EventSignaler};
+use kernel::{Duration, Kernel, Priority};
+use pw_status::{Error, Result};
+use uart_16550_kernel::Uart;
+
+const TEST_THREAD_STACK_SIZE: usize = 2048;
+
+/// Bytes written in a single burst.  Small enough to fit in the receive FIFO
+/// of a UART in loopback mode.
+const BURST_LEN: usize = 16;
+
+/// Bytes written while the transmitter is stalled.  More than the kernel
+/// UART's write buffer holds.
+const BLOCKING_WRITE_LEN: usize = 256;
+
+/// Set by the transmitter thread just before it releases the stalled
+/// transmitter.
+static TX_RELEASED: AtomicBool = AtomicBool::new(false);
+
+/// Set by the main thread once `write_all()` returns.
+static WRITE_RETURNED: AtomicBool = AtomicBool::new(false);
 
 // The test assumes that any UART implementing this trait is backed by
 // a buffer.
 // It also assumes that read() will not block when there is no data.
-// If the UART buffer is full, write() will fail until it's read from.
+// write() and write_all() block while the UART's write buffer is full.
 pub trait TestUart {
     fn enable_loopback();
     fn read() -> Result<Option<u8>>;
     fn write(byte: u8) -> Result<()>;
+    fn write_all(data: &[u8]) -> Result<()>;
 }
 
-pub fn main<K: Kernel, U: TestUart>(_kernel: K) -> Result<()> {
+pub struct TestState<K: Kernel> {
+    thread: Thread<K>,
+    stack: StackStorage<TEST_THREAD_STACK_SIZE>,
+}
+
+impl<K: Kernel> TestState<K> {
+    #[allow(clippy::new_without_default)]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            thread: Thread::new("transmitter", Priority::DEFAULT_PRIORITY),
+            stack: StackStorage::ZEROED,
+        }
+    }
+}
+
+pub fn main<K: Kernel, U: TestUart>(kernel: K, state: &'static mut TestState<K>) -> Result<()> {
     pw_log::info!("🔄 RUNNING");
 
     // enable lo to support writing and then reading back the result.
@@ -64,6 +105,134 @@ pub fn main<K: Kernel, U: TestUart>(_kernel: K) -> Result<()> {
         "Buffer empty multiple reads"
     );
 
+    buffered_writes_arrive_in_order::<K, U>(kernel)?;
+    write_all_waits_for_write_space(kernel, state)?;
+
     pw_log::info!("✅ PASSED");
     Ok(())
 }
+
+fn buffered_writes_arrive_in_order<K: Kernel, U: TestUart>(kernel: K) -> Result<()> {
+    let data: [u8; BURST_LEN] = core::array::from_fn(|i| 0x40 + i as u8);
+    U::write_all(&data)?;
+
+    // The bytes are sent from the interrupt handler, so may not have been
+    // received yet.
+    for &expected in &data {
+        let deadline = kernel.now() + Duration::from_millis(100);
+        let value = loop {
+            if let Some(value) = U::read()? {
+                break value;
+            }
+            if kernel.now() >= deadline {
+                pw_log::error!("Timed out waiting for {}", expected as u8);
+                return Err(Error::DeadlineExceeded);
+            }
+            kernel::yield_timeslice(kernel);
+        };
+        pw_assert::eq!(value as u8, expected as u8);
+    }
+    pw_assert::assert!(
+        U::read().is_ok_and(|val| val.is_none()),
+        "Buffer empty after buffered writes"
+    );
+    Ok(())
+}
+
+// A RAM backed stand-in for the registers of a 16550 UART, which lets the
+// test stall the transmitter.  QEMU's UART accepts bytes as fast as they are
+// written, so it never fills the driver's write buffer.
+#[repr(C, align(4))]
+struct FakeRegisters(UnsafeCell<[u8; 8]>);
+
+impl FakeRegisters {
+    const THR: usize = 0;
+    const LSR: usize = 5;
+    const LSR_THRE: u8 = 1 << 5;
+
+    const fn new() -> Self {
+        Self(UnsafeCell::new([0; 8]))
+    }
+
+    fn base_address(&self) -> usize {
+        self.0.get() as usize
+    }
+
+    fn set_tx_ready(&self, ready: bool) {
+        let lsr = if ready { Self::LSR_THRE } else { 0 };
+        // SAFETY: `LSR` is within the register block.  The UART driver only
+        // uses volatile accesses as well.
+        unsafe { ptr::write_volatile(self.0.get().cast::<u8>().add(Self::LSR), lsr) };
+    }
+
+    fn thr(&self) -> u8 {
+        // SAFETY: `THR` is within the register block.
+        unsafe { ptr::read_volatile(self.0.get().cast::<u8>().add(Self::THR)) }
+    }
+}
+
+struct TransmitterArgs<'a, K: Kernel> {
+    registers: &'a FakeRegisters,
+    uart: &'a Uart<K>,
+    done_signaler: EventSignaler<K>,
+}
+
+fn write_all_waits_for_write_space<K: Kernel>(
+    kernel: K,
+    state: &'static mut TestState<K>,
+) -> Result<()> {
+    let registers = FakeRegisters::new();
+    registers.set_tx_ready(false);
+    let uart = Uart::new(kernel, registers.base_address(), 0);
+    let done = Event::new(kernel, EventConfig::ManualReset);
+
+    let args = TransmitterArgs {
+        registers: &registers,
+        uart: &uart,
+        done_signaler: done.get_signaler(),
+    };
+    let transmitter = thread::init_thread_in(
+        kernel,
+        &mut state.thread,
+        &mut state.stack,
+        "transmitter",
+        Priority::DEFAULT_PRIORITY,
+        transmitter_entry,
+        &args,
+    );
+    kernel::start_thread(kernel, transmitter);
+
+    // Never zero, so the transmit register shows whether anything was sent.
+    let data: [u8; BLOCKING_WRITE_LEN] = core::array::from_fn(|i| (i % 255) as u8 + 1);
+    let result = uart.write_all(&data);
+    WRITE_RETURNED.store(true, Ordering::SeqCst);
+
+    // `args` borrows from this stack frame, so wait for the transmitter
+    // thread to finish with it before checking the results.
+    done.wait_until(kernel.now() + Duration::from_secs(1))?;
+    result?;
+
+    pw_assert::assert!(
+        TX_RELEASED.load(Ordering::SeqCst),
+        "write_all() returned before the transmitter drained the buffer"
+    );
+    pw_assert::eq!(registers.thr() as u8, data[BLOCKING_WRITE_LEN - 1] as u8);
+    Ok(())
+}
+
+fn transmitter_entry<K: Kernel>(kernel: K, args: &TransmitterArgs<K>) {
+    // Give the main thread time to fill the write buffer.
+    let _ = kernel::sleep_until(kernel, kernel.now() + Duration::from_millis(10));
+    pw_assert::assert!(
+        !WRITE_RETURNED.load(Ordering::SeqCst),
+        "write_all() returned while the transmitter was stalled"
+    );
+    pw_assert::eq!(args.registers.thr() as u8, 0 as u8);
+
+    // Let the transmitter accept bytes and raise its interrupt.
+    TX_RELEASED.store(true, Ordering::SeqCst);
+    args.registers.set_tx_ready(true);
+    args.uart.interrupt_handler(kernel);
+
+    args.done_signaler.signal();
+}
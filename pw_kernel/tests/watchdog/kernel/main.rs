@@ -0,0 +1,135 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+#![no_std]
+
+//! Tests that a thread which stops checking in fires the watchdog.
+//!
+//! The target provides the watchdog and calls [`watchdog_fired()`] from its
+//! reset function.
+
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use kernel::scheduler::thread::{self, StackStorage, StackStorageExt as _, Thread};
+use kernel::{Duration, Instant, Kernel, Priority};
+use pw_log::{error, info};
+use pw_status::{Error, Result};
+use watchdog::Watchdog;
+
+const TEST_THREAD_STACK_SIZE: usize = 2048;
+
+/// Number of times the watched thread checks in before it stops.
+const CHECK_INS: usize = 5;
+
+/// Set once the watched thread has stopped checking in.
+static CHECK_INS_STOPPED: AtomicBool = AtomicBool::new(false);
+
+pub struct TestState<K: Kernel> {
+    watched_thread: Thread<K>,
+    watched_stack: StackStorage<TEST_THREAD_STACK_SIZE>,
+    watchdog_thread: Thread<K>,
+    watchdog_stack: StackStorage<TEST_THREAD_STACK_SIZE>,
+}
+
+impl<K: Kernel> TestState<K> {
+    #[allow(clippy::new_without_default)]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            watched_thread: Thread::new("watched thread", Priority::DEFAULT_PRIORITY),
+            watched_stack: StackStorage::ZEROED,
+            watchdog_thread: Thread::new("watchdog thread", Priority::DEFAULT_PRIORITY),
+            watchdog_stack: StackStorage::ZEROED,
+        }
+    }
+}
+
+/// Starts the watched thread and the watchdog service and waits for the
+/// watchdog to fire.
+///
+/// Only returns, with an error, if the watchdog does not fire in time.
+pub fn test_main<K: Kernel, W: Watchdog<K>>(
+    kernel: K,
+    state: &'static mut TestState<K>,
+    device: &'static W,
+) -> Result<()> {
+    info!("🔄 [Missed Check-In] RUNNING");
+
+    let mut watched_thread = thread::init_thread_in(
+        kernel,
+        &mut state.watched_thread,
+        &mut state.watched_stack,
+        "watched thread",
+        Priority::DEFAULT_PRIORITY,
+        watched_thread_entry,
+        0,
+    );
+    watched_thread.set_watchdog_interval(Duration::from_millis(50));
+    kernel::start_thread(kernel, watched_thread);
+
+    let watchdog_thread = thread::init_thread_in(
+        kernel,
+        &mut state.watchdog_thread,
+        &mut state.watchdog_stack,
+        "watchdog thread",
+        Priority::DEFAULT_PRIORITY,
+        watchdog_thread_entry::<K, W>,
+        device,
+    );
+    kernel::start_thread(kernel, watchdog_thread);
+
+    let _ = kernel::sleep_until(kernel, kernel.now() + Duration::from_secs(2));
+
+    info!("❌ ├─ FAILED");
+    error!("❌ └─ Watchdog did not fire");
+    Err(Error::DeadlineExceeded)
+}
+
+/// Checks that the watchdog fired for the right reason.
+///
+/// Called from the target's watchdog reset function.
+pub fn watchdog_fired() -> Result<()> {
+    if !CHECK_INS_STOPPED.load(Ordering::SeqCst) {
+        info!("❌ ├─ FAILED");
+        error!("❌ └─ Watchdog fired while the watched thread was checking in");
+        return Err(Error::Internal);
+    }
+
+    info!("✅ └─ PASSED");
+    Ok(())
+}
+
+fn watched_thread_entry<K: Kernel>(kernel: K, _arg: usize) {
+    for _ in 0..CHECK_INS {
+        kernel::scheduler::watchdog::check_in(kernel);
+        let _ = kernel::sleep_until(kernel, kernel.now() + Duration::from_millis(10));
+    }
+
+    info!("🔄 ├─ Watched thread no longer checking in");
+    CHECK_INS_STOPPED.store(true, Ordering::SeqCst);
+    let _ = kernel::sleep_until(kernel, Instant::MAX);
+}
+
+fn watchdog_thread_entry<K: Kernel, W: Watchdog<K>>(kernel: K, device: &'static W) {
+    let ret = watchdog::run(
+        kernel,
+        device,
+        Duration::from_millis(500),
+        Duration::from_millis(10),
+    );
+    error!(
+        "Watchdog service exited: {}",
+        ret.err().map_or(0, |e| e as u32) as u32
+    );
+}
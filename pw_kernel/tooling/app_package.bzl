@@ -90,6 +90,12 @@ def _app_linker_script(name, system_config, app_name, **kwargs):
         kwargs["target_compatible_with"] = select({
             "@pigweed//pw_kernel/target:system_config_not_set": ["@platforms//:incompatible"],
             "//conditions:default": [],
+        }) + select({
+            # Host apps are linked into the kernel's executable and do not have
+            # their own linker script.
+            "@platforms//cpu:armv8-m": [],
+            "@platforms//cpu:riscv32": [],
+            "//conditions:default": ["@platforms//:incompatible"],
         })
 
     if kwargs.get("template") == None:
@@ -180,7 +186,11 @@ def app_package(name, system_config, app_name, **kwargs):
     rust_library(
         name = name,
         srcs = [":{}.rustsrc".format(name)],
-        deps = [":{}.linker_script".format(name)] + [
+        deps = select({
+            "@platforms//cpu:armv8-m": [":{}.linker_script".format(name)],
+            "@platforms//cpu:riscv32": [":{}.linker_script".format(name)],
+            "//conditions:default": [],
+        }) + [
             "@pigweed//pw_kernel/syscall:syscall_defs",
         ],
         **kwargs
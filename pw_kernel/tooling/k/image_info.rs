@@ -24,9 +24,40 @@ pub struct StackInfo {
     pub stack_size: u64,
 }
 
+/// Layout of the kernel's process and thread lists.
+///
+/// Parsed from the `ThreadsAnnotation` the kernel emits into the
+/// `.pw_kernel.annotations.threads` section.  Offsets are in bytes.
+pub struct ThreadsInfo {
+    pub kernel_state_addr: u64,
+    pub process_list_head: u64,
+    pub link_next: u64,
+    pub process_link: u64,
+    pub process_name: u64,
+    pub process_thread_list_head: u64,
+    pub thread_link: u64,
+    pub thread_name: u64,
+    pub thread_state: u64,
+    pub thread_priority: u64,
+    pub thread_arch_state: u64,
+    /// `None` if the architecture does not save registers in memory.
+    pub saved_frame: Option<u64>,
+    pub saved_pc: u64,
+    /// Address of the state names, an array of `&str` indexed by the state's
+    /// discriminant.
+    pub state_names_addr: u64,
+    pub state_count: u64,
+    pub running_state: u64,
+    /// Names of the thread states, read from `state_names_addr`.
+    pub state_names: Vec<String>,
+}
+
 pub struct ImageInfo {
     pub stacks: Vec<StackInfo>,
+    /// `None` if the image has no thread annotation.
+    pub threads: Option<ThreadsInfo>,
     pub endian: object::Endianness,
+    pub is_64: bool,
 }
 
 impl ImageInfo {
@@ -69,7 +100,75 @@ impl ImageInfo {
             });
         }
 
-        Ok(ImageInfo { stacks, endian })
+        let threads = obj_file
+            .section_by_name(".pw_kernel.annotations.threads")
+            .map(|section| {
+                let data = section
+                    .data()
+                    .context("Failed to read thread annotation section data")?;
+                let mut threads = Self::parse_threads(data, endian, is_64)?;
+                threads.state_names = Self::read_state_names(&obj_file, &threads, endian, is_64)
+                    .context("Failed to read thread state names")?;
+                Ok(threads)
+            })
+            .transpose()?;
+
+        Ok(ImageInfo {
+            stacks,
+            threads,
+            endian,
+            is_64,
+        })
+    }
+
+    fn parse_threads(data: &[u8], endian: object::Endianness, is_64: bool) -> Result<ThreadsInfo> {
+        let field = |index| Self::extract_usize_field(endian, data, index, is_64);
+
+        // The kernel marks unavailable offsets with `usize::MAX`.
+        let usize_max = if is_64 { u64::MAX } else { u64::from(u32::MAX) };
+        let saved_frame = field(11)?;
+
+        Ok(ThreadsInfo {
+            kernel_state_addr: field(0)?,
+            process_list_head: field(1)?,
+            link_next: field(2)?,
+            process_link: field(3)?,
+            process_name: field(4)?,
+            process_thread_list_head: field(5)?,
+            thread_link: field(6)?,
+            thread_name: field(7)?,
+            thread_state: field(8)?,
+            thread_priority: field(9)?,
+            thread_arch_state: field(10)?,
+            saved_frame: (saved_frame != usize_max).then_some(saved_frame),
+            saved_pc: field(12)?,
+            state_names_addr: field(13)?,
+            state_count: field(14)?,
+            running_state: field(15)?,
+            state_names: Vec::new(),
+        })
+    }
+
+    fn read_state_names(
+        obj_file: &object::File,
+        threads: &ThreadsInfo,
+        endian: object::Endianness,
+        is_64: bool,
+    ) -> Result<Vec<String>> {
+        // Each name is a `&str`: a pointer followed by a length.
+        let entry_size = if is_64 { 16 } else { 8 };
+        let data = Self::read_data(
+            obj_file,
+            threads.state_names_addr,
+            threads.state_count * entry_size,
+        )?;
+        data.chunks(usize::try_from(entry_size)?)
+            .map(|entry| {
+                let name_addr = Self::extract_usize_field(endian, entry, 0, is_64)?;
+                let name_len = Self::extract_usize_field(endian, entry, 1, is_64)?;
+                Self::read_string(obj_file, name_addr, name_len)
+            })
+            .collect()
     }
 
     fn extract_usize_field(
@@ -113,3 +212,106 @@ impl ImageInfo {
         Ok(String::from_utf8_lossy(bytes).into_owned())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use object::Endianness;
+
+    use super::*;
+
+    // Fields of a thread annotation in the order the kernel emits them.
+    const FIELDS: [u64; 16] = [
+        0x8000_1000, // kernel_state_addr
+        8,           // process_list_head
+        4,           // link_next
+        12,          // process_link
+        16,          // process_name
+        24,          // process_thread_list_head
+        32,          // thread_link
+        40,          // thread_name
+        48,          // thread_state
+        49,          // thread_priority
+        56,          // thread_arch_state
+        0,           // saved_frame
+        60,          // saved_pc
+        0x8000_2000, // state_names_addr
+        7,           // state_count
+        3,           // running_state
+    ];
+
+    fn encode(fields: &[u64], endian: Endianness, is_64: bool) -> Vec<u8> {
+        let mut data = Vec::new();
+        for &field in fields {
+            match (is_64, endian) {
+                (true, Endianness::Little) => data.extend(field.to_le_bytes()),
+                (true, Endianness::Big) => data.extend(field.to_be_bytes()),
+                (false, Endianness::Little) => {
+                    data.extend(u32::try_from(field).unwrap().to_le_bytes());
+                }
+                (false, Endianness::Big) => {
+                    data.extend(u32::try_from(field).unwrap().to_be_bytes());
+                }
+            }
+        }
+        data
+    }
+
+    fn assert_fields(info: &ThreadsInfo, saved_frame: Option<u64>) {
+        assert_eq!(info.kernel_state_addr, FIELDS[0]);
+        assert_eq!(info.process_list_head, FIELDS[1]);
+        assert_eq!(info.link_next, FIELDS[2]);
+        assert_eq!(info.process_link, FIELDS[3]);
+        assert_eq!(info.process_name, FIELDS[4]);
+        assert_eq!(info.process_thread_list_head, FIELDS[5]);
+        assert_eq!(info.thread_link, FIELDS[6]);
+        assert_eq!(info.thread_name, FIELDS[7]);
+        assert_eq!(info.thread_state, FIELDS[8]);
+        assert_eq!(info.thread_priority, FIELDS[9]);
+        assert_eq!(info.thread_arch_state, FIELDS[10]);
+        assert_eq!(info.saved_frame, saved_frame);
+        assert_eq!(info.saved_pc, FIELDS[12]);
+        assert_eq!(info.state_names_addr, FIELDS[13]);
+        assert_eq!(info.state_count, FIELDS[14]);
+        assert_eq!(info.running_state, FIELDS[15]);
+        assert!(info.state_names.is_empty());
+    }
+
+    #[test]
+    fn parse_threads_32_bit() {
+        let data = encode(&FIELDS, Endianness::Little, false);
+        let info = ImageInfo::parse_threads(&data, Endianness::Little, false).unwrap();
+        assert_fields(&info, Some(0));
+    }
+
+    #[test]
+    fn parse_threads_64_bit_big_endian() {
+        let data = encode(&FIELDS, Endianness::Big, true);
+        let info = ImageInfo::parse_threads(&data, Endianness::Big, true).unwrap();
+        assert_fields(&info, Some(0));
+    }
+
+    #[test]
+    fn parse_threads_without_saved_frame() {
+        let mut fields = FIELDS;
+        fields[11] = u64::from(u32::MAX);
+        let data = encode(&fields, Endianness::Little, false);
+        let info = ImageInfo::parse_threads(&data, Endianness::Little, false).unwrap();
+        assert_fields(&info, None);
+
+        // `u32::MAX` is a valid offset in a 64-bit image.
+        let data = encode(&fields, Endianness::Little, true);
+        let info = ImageInfo::parse_threads(&data, Endianness::Little, true).unwrap();
+        assert_fields(&info, Some(u64::from(u32::MAX)));
+
+        fields[11] = u64::MAX;
+        let data = encode(&fields, Endianness::Little, true);
+        let info = ImageInfo::parse_threads(&data, Endianness::Little, true).unwrap();
+        assert_fields(&info, None);
+    }
+
+    #[test]
+    fn parse_threads_truncated_section() {
+        let data = encode(&FIELDS[..15], Endianness::Little, false);
+        assert!(ImageInfo::parse_threads(&data, Endianness::Little, false).is_err());
+    }
+}
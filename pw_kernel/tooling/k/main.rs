@@ -15,6 +15,7 @@
 use anyhow::Result;
 mod image_info;
 mod stacks;
+mod threads;
 
 use std::path::{Path, PathBuf};
 
@@ -44,6 +45,14 @@ enum Commands {
         #[arg(long, default_value = "localhost:1234")]
         gdb: String,
     },
+    /// Print the processes and threads of a running target
+    #[command(name = "threads")]
+    Threads {
+        #[arg(required = true)]
+        path: PathBuf,
+        #[arg(long, default_value = "localhost:1234")]
+        gdb: String,
+    },
 }
 
 fn print_image_info(path: &Path) -> Result<()> {
@@ -71,6 +80,9 @@ async fn main() -> Result<()> {
         Commands::Stacks { path, gdb } => {
             stacks::run(path, gdb).await?;
         }
+        Commands::Threads { path, gdb } => {
+            threads::run(path, gdb).await?;
+        }
     }
 
     Ok(())
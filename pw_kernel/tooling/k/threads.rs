@@ -0,0 +1,191 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+use std::path::Path;
+
+use anyhow::{Context, Result, anyhow};
+use object::Endian;
+use pw_gdb_protocol::Client;
+use tokio::net::TcpStream;
+use tokio_util::compat::{Compat, TokioAsyncReadCompatExt};
+
+use crate::image_info::{ImageInfo, ThreadsInfo};
+
+// Bounds on what is read from the target to guard against corrupted lists.
+const MAX_LIST_LEN: usize = 1024;
+const MAX_NAME_LEN: u64 = 256;
+
+struct Target {
+    client: Client<Compat<TcpStream>>,
+    endian: object::Endianness,
+    is_64: bool,
+}
+
+impl Target {
+    fn ptr_size(&self) -> u64 {
+        if self.is_64 { 8 } else { 4 }
+    }
+
+    async fn read_usize(&mut self, addr: u64) -> Result<u64> {
+        let bytes = self
+            .client
+            .read_memory(addr, self.ptr_size())
+            .await
+            .context(format!("Failed to read memory at 0x{addr:08x}"))?;
+        if self.is_64 {
+            Ok(self.endian.read_u64_bytes(bytes.as_slice().try_into()?))
+        } else {
+            Ok(u64::from(
+                self.endian.read_u32_bytes(bytes.as_slice().try_into()?),
+            ))
+        }
+    }
+
+    async fn read_u8(&mut self, addr: u64) -> Result<u8> {
+        let bytes = self
+            .client
+            .read_memory(addr, 1)
+            .await
+            .context(format!("Failed to read memory at 0x{addr:08x}"))?;
+        bytes
+            .first()
+            .copied()
+            .ok_or_else(|| anyhow!("Empty read at 0x{addr:08x}"))
+    }
+
+    // Reads a `&str` stored as a pointer followed by a length.
+    async fn read_str(&mut self, addr: u64) -> Result<String> {
+        let ptr = self.read_usize(addr).await?;
+        let len = self
+            .read_usize(addr + self.ptr_size())
+            .await?
+            .min(MAX_NAME_LEN);
+        if len == 0 {
+            return Ok(String::new());
+        }
+        let bytes = self
+            .client
+            .read_memory(ptr, len)
+            .await
+            .context(format!("Failed to read string at 0x{ptr:08x}"))?;
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+
+    // Returns the addresses of the elements of the list whose head link
+    // pointer is at `head_addr`.
+    async fn read_list(
+        &mut self,
+        layout: &ThreadsInfo,
+        head_addr: u64,
+        link_offset: u64,
+    ) -> Result<Vec<u64>> {
+        let mut elements = Vec::new();
+        let mut link = self.read_usize(head_addr).await?;
+        while link != 0 {
+            if elements.len() >= MAX_LIST_LEN {
+                return Err(anyhow!(
+                    "List at 0x{head_addr:08x} has more than {MAX_LIST_LEN} elements"
+                ));
+            }
+            elements.push(
+                link.checked_sub(link_offset)
+                    .context(format!("Invalid link 0x{link:08x}"))?,
+            );
+            link = self.read_usize(link + layout.link_next).await?;
+        }
+        Ok(elements)
+    }
+}
+
+pub async fn run(path: &Path, gdb_addr: &str) -> Result<()> {
+    let info = ImageInfo::new(path)?;
+    let layout = info
+        .threads
+        .context("Image has no .pw_kernel.annotations.threads section")?;
+
+    // Connect to GDB server
+    let stream = TcpStream::connect(gdb_addr)
+        .await
+        .context(format!("Failed to connect to GDB server at {}", gdb_addr))?;
+
+    let compat_stream = stream.compat();
+    let mut target = Target {
+        client: Client::new(compat_stream),
+        endian: info.endian,
+        is_64: info.is_64,
+    };
+
+    println!(
+        "{:<24} {:<16} {:<10} {:<8} {:<12} {:<12} {:<12}",
+        "Name", "Process", "State", "Priority", "Id", "PC", "SP"
+    );
+    println!(
+        "{:-<24} {:-<16} {:-<10} {:-<8} {:-<12} {:-<12} {:-<12}",
+        "", "", "", "", "", "", ""
+    );
+
+    let processes = target
+        .read_list(
+            &layout,
+            layout.kernel_state_addr + layout.process_list_head,
+            layout.process_link,
+        )
+        .await
+        .context("Failed to read process list")?;
+
+    for process in processes {
+        let process_name = target.read_str(process + layout.process_name).await?;
+        let threads = target
+            .read_list(
+                &layout,
+                process + layout.process_thread_list_head,
+                layout.thread_link,
+            )
+            .await
+            .context(format!("Failed to read threads of {process_name}"))?;
+
+        for thread in threads {
+            let name = target.read_str(thread + layout.thread_name).await?;
+            let state = target.read_u8(thread + layout.thread_state).await?;
+            let priority = target.read_u8(thread + layout.thread_priority).await?;
+            let id = thread + layout.thread_arch_state;
+
+            // The saved registers of the running thread are stale.
+            let mut pc = "-".to_string();
+            let mut sp = "-".to_string();
+            if let Some(saved_frame) = layout.saved_frame
+                && u64::from(state) != layout.running_state
+            {
+                let frame = target.read_usize(id + saved_frame).await?;
+                if frame != 0 {
+                    let saved_pc = target.read_usize(frame + layout.saved_pc).await?;
+                    pc = format!("0x{saved_pc:08x}");
+                    sp = format!("0x{frame:08x}");
+                }
+            }
+
+            let state = layout
+                .state_names
+                .get(usize::from(state))
+                .map_or_else(|| format!("?({state})"), Clone::clone);
+
+            println!(
+                "{:<24} {:<16} {:<10} {:<8} 0x{:08x}   {:<12} {:<12}",
+                name, process_name, state, priority, id, pc, sp
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -173,6 +173,33 @@ impl ArchConfigInterface for system_config::RiscVConfig {
     }
 }
 
+impl ArchConfigInterface for system_config::HostConfig {
+    fn get_arch_crate_name(&self) -> &'static str {
+        "arch_host"
+    }
+
+    fn get_start_fn_address(&self, _flash_start_address: u64) -> u64 {
+        // Apps are linked into the kernel's executable; see
+        // `calculate_and_validate_config`.
+        0
+    }
+
+    fn calculate_and_validate_config(
+        &mut self,
+        config: &mut system_config::BaseConfig,
+    ) -> Result<()> {
+        // Must match the symbol exported by `userspace_macro::host_entry`.
+        for app in &mut config.apps {
+            app.start_fn_symbol = Some(format!("pw_kernel_host_app_start_{}", app.name));
+        }
+        Ok(())
+    }
+
+    fn get_interrupt_table_link_section(&self) -> Option<String> {
+        None
+    }
+}
+
 pub struct SystemGenerator<'a, A: ArchConfigInterface> {
     cli: Cli,
     config: system_config::SystemConfig<A>,
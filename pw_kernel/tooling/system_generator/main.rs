@@ -23,9 +23,10 @@ use system_generator::{ArchConfigInterface, Cli, SystemGenerator, system_config}
 pub enum ArchConfig {
     Armv8M(system_config::Armv8MConfig),
     RiscV(system_config::RiscVConfig),
+    Host(system_config::HostConfig),
 }
 
-// SystemGenerator supports Armv8m and RiscV by default.
+// SystemGenerator supports Armv8m, RiscV and Host by default.
 // New architectures which are implemented out of tree can
 // implement ArchConfigInterface with all required architectures.
 impl ArchConfigInterface for ArchConfig {
@@ -33,6 +34,7 @@ impl ArchConfigInterface for ArchConfig {
         match self {
             ArchConfig::Armv8M(config) => config.get_arch_crate_name(),
             ArchConfig::RiscV(config) => config.get_arch_crate_name(),
+            ArchConfig::Host(config) => config.get_arch_crate_name(),
         }
     }
 
@@ -40,6 +42,7 @@ impl ArchConfigInterface for ArchConfig {
         match self {
             ArchConfig::Armv8M(config) => config.get_start_fn_address(flash_start_address),
             ArchConfig::RiscV(config) => config.get_start_fn_address(flash_start_address),
+            ArchConfig::Host(config) => config.get_start_fn_address(flash_start_address),
         }
     }
 
@@ -50,6 +53,7 @@ impl ArchConfigInterface for ArchConfig {
         match self {
             ArchConfig::Armv8M(arch_config) => arch_config.calculate_and_validate_config(config),
             ArchConfig::RiscV(arch_config) => arch_config.calculate_and_validate_config(config),
+            ArchConfig::Host(arch_config) => arch_config.calculate_and_validate_config(config),
         }
     }
 
@@ -57,6 +61,7 @@ impl ArchConfigInterface for ArchConfig {
         match self {
             ArchConfig::Armv8M(config) => config.get_interrupt_table_link_section(),
             ArchConfig::RiscV(config) => config.get_interrupt_table_link_section(),
+            ArchConfig::Host(config) => config.get_interrupt_table_link_section(),
         }
     }
 }
@@ -55,6 +55,10 @@ pub struct Armv8MNvicConfig {
 #[serde(deny_unknown_fields)]
 pub struct RiscVConfig;
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct HostConfig;
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct KernelConfig {
@@ -63,6 +67,19 @@ pub struct KernelConfig {
     pub ram_start_address: u64,
     pub ram_size_bytes: u64,
     pub interrupt_table: Option<InterruptTableConfig>,
+    pub shell: Option<ShellConfig>,
+}
+
+/// A kernel shell thread, started by the generated `start_shell()`.
+///
+/// `device` names the static driver instance the shell runs on, in the same
+/// way as [`UartConfig::device`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ShellConfig {
+    pub device: String,
+    pub stack_size_bytes: u64,
+    pub priority: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -115,6 +132,10 @@ pub struct AppConfig {
     pub ram_start_address: u64,
     #[serde(skip_deserializing)]
     pub start_fn_address: u64,
+    // Set on architectures which resolve the entry point by symbol rather than
+    // by address.
+    #[serde(skip_deserializing)]
+    pub start_fn_symbol: Option<String>,
     #[serde(skip_deserializing)]
     pub initial_sp: u64,
 }
@@ -130,6 +151,9 @@ pub struct ProcessConfig {
     #[serde(default)]
     pub objects: Vec<ObjectConfig>,
     pub threads: Vec<ThreadConfig>,
+
+    #[serde(default)]
+    pub thread_pool: Option<ThreadPoolConfig>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -157,6 +181,10 @@ pub enum ObjectConfig {
     ChannelInitiator(ChannelInitiatorConfig),
     ChannelHandler(ChannelHandlerConfig),
     Interrupt(InterruptConfig),
+    WaitGroup(WaitGroupConfig),
+    Process(ProcessObjectConfig),
+    Timer(TimerConfig),
+    Uart(UartConfig),
 }
 
 impl ObjectConfig {
@@ -166,6 +194,10 @@ impl ObjectConfig {
             ObjectConfig::ChannelInitiator(c) => &c.name,
             ObjectConfig::ChannelHandler(c) => &c.name,
             ObjectConfig::Interrupt(c) => &c.name,
+            ObjectConfig::WaitGroup(c) => &c.name,
+            ObjectConfig::Process(c) => &c.name,
+            ObjectConfig::Timer(c) => &c.name,
+            ObjectConfig::Uart(c) => &c.name,
         }
     }
 }
@@ -204,12 +236,68 @@ pub struct InterruptConfig {
     pub interrupt_signal_map: HashMap<String, String>,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct WaitGroupConfig {
+    pub name: String,
+}
+
+/// A timer which raises `Signals::READABLE` when it expires.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct TimerConfig {
+    pub name: String,
+}
+
+/// A kernel owned UART exposed through read and write syscalls.
+///
+/// `device` names the static driver instance, which must implement
+/// `kernel::char_device::CharDevice`, in the crate which invokes the
+/// generated `declare_uart_devices!()` macro.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct UartConfig {
+    pub name: String,
+    pub device: String,
+}
+
+/// A handle to another app's process, allowing the holder to supervise it.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProcessObjectConfig {
+    pub name: String,
+    pub app: String,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct ThreadConfig {
     pub name: String,
     pub stack_size_bytes: u64,
     pub priority: Option<String>,
+    /// Number of scheduler ticks the thread runs before being rotated behind
+    /// other threads of the same priority.  Defaults to one tick.
+    pub timeslice_ticks: Option<u32>,
+    /// Maximum time in milliseconds between the thread's watchdog check-ins.
+    /// Threads without an interval are not watched.
+    pub watchdog_interval_ms: Option<u32>,
+    /// Time in milliseconds, relative to each time the thread is started or
+    /// woken, by which it should have completed its work.  Only used by
+    /// deadline based scheduler algorithms.
+    pub relative_deadline_ms: Option<u32>,
+    /// Run time in milliseconds the thread may use each time it is started or
+    /// woken.  Requires `relative_deadline_ms`.
+    pub budget_ms: Option<u32>,
+}
+
+/// Threads which user space can create at run time.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ThreadPoolConfig {
+    pub name: String,
+    pub max_threads: usize,
+    pub stack_size_bytes: u64,
+    pub priority: Option<String>,
 }
 
 impl<A: ArchConfigInterface> SystemConfig<A> {
@@ -245,6 +333,12 @@ impl<A: ArchConfigInterface> SystemConfig<A> {
 
         Self::check_unique_names(self.base.apps.iter().map(|a| a.name.as_str()), "apps")?;
 
+        let mut connected_handlers = HashSet::new();
+        let mut supervised_apps = HashSet::new();
+        let mut listened_devices = HashSet::new();
+        if let Some(shell) = &self.base.kernel.shell {
+            listened_devices.insert(&shell.device);
+        }
         for app_config in &self.base.apps {
             Self::check_unique_names(
                 app_config
@@ -263,6 +357,47 @@ impl<A: ArchConfigInterface> SystemConfig<A> {
                 &format!("threads for app {}", app_config.name),
             )?;
 
+            if let Some(thread_pool) = &app_config.process.thread_pool
+                && thread_pool.max_threads == 0
+            {
+                return Err(anyhow!(
+                    "Thread pool \"{}\" for app {} must have at least one thread",
+                    thread_pool.name,
+                    app_config.name
+                ));
+            }
+
+            for thread in &app_config.process.threads {
+                if thread.timeslice_ticks == Some(0) {
+                    return Err(anyhow!(
+                        "Thread \"{}\" for app {} must have a timeslice of at least one tick",
+                        thread.name,
+                        app_config.name
+                    ));
+                }
+                if thread.watchdog_interval_ms == Some(0) {
+                    return Err(anyhow!(
+                        "Thread \"{}\" for app {} must have a non-zero watchdog interval",
+                        thread.name,
+                        app_config.name
+                    ));
+                }
+                if thread.relative_deadline_ms == Some(0) {
+                    return Err(anyhow!(
+                        "Thread \"{}\" for app {} must have a non-zero relative deadline",
+                        thread.name,
+                        app_config.name
+                    ));
+                }
+                if thread.budget_ms.is_some() && thread.relative_deadline_ms.is_none() {
+                    return Err(anyhow!(
+                        "Thread \"{}\" for app {} must have a relative deadline to have a budget",
+                        thread.name,
+                        app_config.name
+                    ));
+                }
+            }
+
             for object in &app_config.process.objects {
                 if let ObjectConfig::ChannelInitiator(initiator) = object {
                     let handler_app = &initiator.handler_app;
@@ -275,6 +410,46 @@ impl<A: ArchConfigInterface> SystemConfig<A> {
                             initiator_name = initiator.name,
                         ));
                     }
+                    // Channels connect a single initiator to a single handler.
+                    if !connected_handlers.insert((handler_app, handler_object_name)) {
+                        return Err(anyhow!(
+                            "Channel handler \"{handler_app}\":{handler_object_name} is referenced by more than one initiator",
+                        ));
+                    }
+                } else if let ObjectConfig::Process(process) = object {
+                    if !self.base.apps.iter().any(|a| a.name == process.app) {
+                        return Err(anyhow!(
+                            "Process object \"{app_name}:{object_name}\" references non-existent app \"{target_app}\"",
+                            app_name = app_config.name,
+                            object_name = process.name,
+                            target_app = process.app,
+                        ));
+                    }
+                    // A process can not terminate or restart itself.
+                    if process.app == app_config.name {
+                        return Err(anyhow!(
+                            "Process object \"{app_name}:{object_name}\" references its own app",
+                            app_name = app_config.name,
+                            object_name = process.name,
+                        ));
+                    }
+                    // A process notifies a single process object when it
+                    // exits.
+                    if !supervised_apps.insert(&process.app) {
+                        return Err(anyhow!(
+                            "App \"{target_app}\" is referenced by more than one process object",
+                            target_app = process.app,
+                        ));
+                    }
+                } else if let ObjectConfig::Uart(uart) = object {
+                    // A device notifies a single listener of changes in its
+                    // readiness.
+                    if !listened_devices.insert(&uart.device) {
+                        return Err(anyhow!(
+                            "UART device \"{device}\" is referenced by more than one uart object or the kernel shell",
+                            device = uart.device,
+                        ));
+                    }
                 } else if let ObjectConfig::Interrupt(interrupt_config) = object {
                     Self::check_unique_names(
                         interrupt_config.irqs.iter().map(|i| i.name.as_str()),
@@ -167,3 +167,50 @@ system_image_test = rule(
     },
     doc = "Defines a test for a system_image target.",
 )
+
+def _host_system_transition_impl(_, attr):
+    return {
+        str(Label("//pw_kernel/target:system_config_file")): str(attr.system_config),
+    }
+
+_host_system_transition = transition(
+    implementation = _host_system_transition_impl,
+    inputs = [],
+    outputs = [
+        str(Label("//pw_kernel/target:system_config_file")),
+    ],
+)
+
+def _host_system_test_impl(ctx):
+    executable_symlink = ctx.actions.declare_file(ctx.label.name)
+    ctx.actions.symlink(
+        output = executable_symlink,
+        target_file = ctx.executable.binary,
+        is_executable = True,
+    )
+
+    return [DefaultInfo(executable = executable_symlink)]
+
+host_system_test = rule(
+    implementation = _host_system_test_impl,
+    test = True,
+    attrs = {
+        "binary": attr.label(
+            doc = "Host kernel executable with the system's apps linked in.",
+            mandatory = True,
+            executable = True,
+            cfg = _host_system_transition,
+        ),
+        "system_config": attr.label(
+            doc = "System config file which defines the system.",
+            allow_single_file = True,
+            mandatory = True,
+        ),
+    },
+    doc = """Defines a test for a system running on the host.
+
+    On the host, apps are linked into the kernel's executable rather than
+    assembled into a system image.  The executable is built with
+    `system_config` selected so that the apps' generated packages match it.
+    """,
+)
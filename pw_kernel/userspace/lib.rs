@@ -16,12 +16,19 @@
 use pw_status::Error;
 #[cfg(feature = "arch_arm_cortex_m")]
 pub use userspace_macro::arm_cortex_m_entry as entry;
+#[cfg(feature = "arch_host")]
+pub use userspace_macro::host_entry as entry;
 #[cfg(feature = "arch_riscv")]
 pub use userspace_macro::riscv_entry as entry;
 
+#[cfg(target_has_atomic = "32")]
+pub mod sync;
 pub mod syscall;
+pub mod thread;
 pub mod time;
 
+// On the host, apps share the kernel's executable and its assert handler.
+#[cfg(not(feature = "arch_host"))]
 #[allow(non_snake_case)]
 #[unsafe(no_mangle)]
 pub extern "C" fn pw_assert_HandleFailure() -> ! {
@@ -0,0 +1,28 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+//! Synchronization primitives for threads within a process.
+//!
+//! These are built on atomic operations and only enter the kernel, through
+//! the futex system calls, when a thread needs to block or wake another
+//! thread.  As they require atomic compare and exchange, they are not
+//! available on targets without it.
+
+mod condvar;
+mod mutex;
+mod once;
+
+pub use condvar::Condvar;
+pub use mutex::{Mutex, MutexGuard};
+pub use once::Once;
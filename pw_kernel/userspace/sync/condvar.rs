@@ -0,0 +1,80 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use pw_status::{Error, Result};
+
+use super::MutexGuard;
+use crate::syscall;
+use crate::time::Instant;
+
+/// A condition variable for threads within a process.
+///
+/// As with most condition variables, waiters may be woken spuriously and
+/// should re-check their condition after waking.
+pub struct Condvar {
+    // Incremented on every notification.
+    futex: AtomicU32,
+}
+
+impl Condvar {
+    #[allow(clippy::new_without_default)]
+    pub const fn new() -> Self {
+        Self {
+            futex: AtomicU32::new(0),
+        }
+    }
+
+    /// Unlocks the mutex held by `guard` and blocks until notified, returning
+    /// once the mutex has been re-acquired.
+    pub fn wait<'lock, T>(&self, guard: MutexGuard<'lock, T>) -> MutexGuard<'lock, T> {
+        let (guard, _) = self.wait_until(guard, Instant::MAX);
+        guard
+    }
+
+    /// Unlocks the mutex held by `guard` and blocks until notified or
+    /// `deadline` is reached, returning once the mutex has been re-acquired.
+    pub fn wait_until<'lock, T>(
+        &self,
+        guard: MutexGuard<'lock, T>,
+        deadline: Instant,
+    ) -> (MutexGuard<'lock, T>, Result<()>) {
+        // Sampled with the mutex held so that any notification issued after
+        // the mutex is released causes the wait below to return.
+        let sequence = self.futex.load(Ordering::Relaxed);
+        let mutex = guard.lock;
+        drop(guard);
+
+        let ret = match syscall::futex_wait(&self.futex, sequence, deadline) {
+            // Notified between releasing the mutex and blocking.
+            Err(Error::Unavailable) => Ok(()),
+            ret => ret,
+        };
+
+        (mutex.lock(), ret)
+    }
+
+    /// Wakes one thread blocked on this condition variable.
+    pub fn notify_one(&self) {
+        self.futex.fetch_add(1, Ordering::Relaxed);
+        let _ = syscall::futex_wake(&self.futex, 1);
+    }
+
+    /// Wakes all threads blocked on this condition variable.
+    pub fn notify_all(&self) {
+        self.futex.fetch_add(1, Ordering::Relaxed);
+        let _ = syscall::futex_wake(&self.futex, usize::MAX);
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+use core::cell::UnsafeCell;
+use core::ops::{Deref, DerefMut};
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use pw_status::{Error, Result};
+
+use crate::syscall;
+use crate::time::Instant;
+
+const UNLOCKED: u32 = 0;
+const LOCKED: u32 = 1;
+// Locked and other threads may be blocked waiting for the mutex.
+const CONTENDED: u32 = 2;
+
+/// A mutual exclusion lock for threads within a process.
+///
+/// Locking and unlocking an uncontended mutex does not enter the kernel.
+pub struct Mutex<T> {
+    futex: AtomicU32,
+    inner: UnsafeCell<T>,
+}
+unsafe impl<T: Send> Sync for Mutex<T> {}
+unsafe impl<T: Send> Send for Mutex<T> {}
+
+pub struct MutexGuard<'lock, T> {
+    pub(super) lock: &'lock Mutex<T>,
+}
+
+impl<T> Deref for MutexGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.lock.inner.get() }
+    }
+}
+
+impl<T> DerefMut for MutexGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.lock.inner.get() }
+    }
+}
+
+impl<T> Drop for MutexGuard<'_, T> {
+    fn drop(&mut self) {
+        self.lock.unlock();
+    }
+}
+
+impl<T> Mutex<T> {
+    pub const fn new(initial_value: T) -> Self {
+        Self {
+            futex: AtomicU32::new(UNLOCKED),
+            inner: UnsafeCell::new(initial_value),
+        }
+    }
+
+    pub fn lock(&self) -> MutexGuard<'_, T> {
+        loop {
+            // An infinite wait can only fail if the thread is being
+            // terminated.
+            if let Ok(guard) = self.lock_until(Instant::MAX) {
+                return guard;
+            }
+        }
+    }
+
+    pub fn lock_until(&self, deadline: Instant) -> Result<MutexGuard<'_, T>> {
+        if let Some(guard) = self.try_lock() {
+            return Ok(guard);
+        }
+
+        // Mark the mutex as contended so that the holder wakes us on unlock.
+        // If it was released in the meantime, we now hold it.
+        while self.futex.swap(CONTENDED, Ordering::Acquire) != UNLOCKED {
+            match syscall::futex_wait(&self.futex, CONTENDED, deadline) {
+                // Woken, or the mutex changed state before we could block.
+                Ok(()) | Err(Error::Unavailable) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(MutexGuard { lock: self })
+    }
+
+    pub fn try_lock(&self) -> Option<MutexGuard<'_, T>> {
+        self.futex
+            .compare_exchange(UNLOCKED, LOCKED, Ordering::Acquire, Ordering::Relaxed)
+            .ok()
+            .map(|_| MutexGuard { lock: self })
+    }
+
+    fn unlock(&self) {
+        if self.futex.swap(UNLOCKED, Ordering::Release) == CONTENDED {
+            let _ = syscall::futex_wake(&self.futex, 1);
+        }
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use crate::syscall;
+use crate::time::Instant;
+
+const INCOMPLETE: u32 = 0;
+const RUNNING: u32 = 1;
+// Running and other threads may be blocked waiting for completion.
+const RUNNING_WITH_WAITERS: u32 = 2;
+const COMPLETE: u32 = 3;
+
+/// One time initialization for threads within a process.
+pub struct Once {
+    futex: AtomicU32,
+}
+
+impl Once {
+    #[allow(clippy::new_without_default)]
+    pub const fn new() -> Self {
+        Self {
+            futex: AtomicU32::new(INCOMPLETE),
+        }
+    }
+
+    /// Returns `true` if a call to [`Once::call_once()`] has completed.
+    pub fn is_completed(&self) -> bool {
+        self.futex.load(Ordering::Acquire) == COMPLETE
+    }
+
+    /// Calls `f` if this is the first call to `call_once()`.
+    ///
+    /// If another thread is running its initialization, blocks until it
+    /// completes.  Once `call_once()` returns, the effects of the
+    /// initialization are visible to the calling thread.
+    pub fn call_once<F: FnOnce()>(&self, f: F) {
+        let mut state = self.futex.load(Ordering::Acquire);
+        loop {
+            match state {
+                COMPLETE => return,
+                INCOMPLETE => {
+                    if let Err(current) = self.futex.compare_exchange(
+                        INCOMPLETE,
+                        RUNNING,
+                        Ordering::Acquire,
+                        Ordering::Acquire,
+                    ) {
+                        state = current;
+                        continue;
+                    }
+
+                    f();
+
+                    if self.futex.swap(COMPLETE, Ordering::Release) == RUNNING_WITH_WAITERS {
+                        let _ = syscall::futex_wake(&self.futex, usize::MAX);
+                    }
+                    return;
+                }
+                RUNNING => {
+                    // Let the running thread know it has to wake us.
+                    if let Err(current) = self.futex.compare_exchange(
+                        RUNNING,
+                        RUNNING_WITH_WAITERS,
+                        Ordering::Relaxed,
+                        Ordering::Acquire,
+                    ) {
+                        state = current;
+                        continue;
+                    }
+                    state = RUNNING_WITH_WAITERS;
+                }
+                _ => {
+                    let _ = syscall::futex_wait(&self.futex, RUNNING_WITH_WAITERS, Instant::MAX);
+                    state = self.futex.load(Ordering::Acquire);
+                }
+            }
+        }
+    }
+}
@@ -12,19 +12,30 @@
 // License for the specific language governing permissions and limitations under
 // the License.
 
+use core::sync::atomic::AtomicU32;
+
 use pw_cast::CastInto;
 use pw_status::{Result, StatusCode};
-pub use syscall_defs::Signals;
 use syscall_defs::SysCallInterface;
+pub use syscall_defs::{ProcessFault, Signals, WaitReturn};
 use syscall_user::SysCall;
 
-use crate::time::Instant;
+use crate::time::{Duration, Instant};
 
 #[inline(always)]
-pub fn object_wait(object_handle: u32, signal_mask: Signals, deadline: Instant) -> Result<Signals> {
+pub fn object_wait(
+    object_handle: u32,
+    signal_mask: Signals,
+    deadline: Instant,
+) -> Result<WaitReturn> {
     SysCall::object_wait(object_handle, signal_mask.bits(), deadline.ticks())
 }
 
+#[inline(always)]
+pub fn object_raise_peer_user_signal(object_handle: u32) -> Result<()> {
+    SysCall::object_raise_peer_user_signal(object_handle)
+}
+
 #[inline(always)]
 pub fn channel_transact(
     object_handle: u32,
@@ -45,6 +56,34 @@ pub fn channel_transact(
     }
 }
 
+/// Starts a transaction on a channel without waiting for the response.
+///
+/// # Safety
+/// `send_data` and `recv_data` must remain valid until the transaction is
+/// completed, which is signaled by `Signals::READABLE` on `object_handle`, or
+/// canceled with [`channel_async_cancel()`].
+#[inline(always)]
+pub unsafe fn channel_async_transact(
+    object_handle: u32,
+    send_data: &[u8],
+    recv_data: &mut [u8],
+) -> Result<()> {
+    unsafe {
+        SysCall::channel_async_transact(
+            object_handle,
+            send_data.as_ptr(),
+            send_data.len(),
+            recv_data.as_mut_ptr(),
+            recv_data.len(),
+        )
+    }
+}
+
+#[inline(always)]
+pub fn channel_async_cancel(object_handle: u32) -> Result<()> {
+    SysCall::channel_async_cancel(object_handle)
+}
+
 #[inline(always)]
 pub fn channel_read(object_handle: u32, offset: usize, buffer: &mut [u8]) -> Result<usize> {
     unsafe {
@@ -63,6 +102,138 @@ pub fn interrupt_ack(object_handle: u32, signal_mask: Signals) -> Result<()> {
     SysCall::interrupt_ack(object_handle, signal_mask)
 }
 
+#[inline(always)]
+pub fn wait_group_add(
+    wait_group: u32,
+    object: u32,
+    signal_mask: Signals,
+    user_data: usize,
+) -> Result<()> {
+    SysCall::wait_group_add(wait_group, object, signal_mask, user_data)
+}
+
+#[inline(always)]
+pub fn wait_group_remove(wait_group: u32, object: u32) -> Result<()> {
+    SysCall::wait_group_remove(wait_group, object)
+}
+
+#[inline(always)]
+pub fn futex_wait(futex: &AtomicU32, expected: u32, deadline: Instant) -> Result<()> {
+    SysCall::futex_wait(futex.as_ptr(), expected, deadline.ticks())
+}
+
+#[inline(always)]
+pub fn futex_wake(futex: &AtomicU32, count: usize) -> Result<usize> {
+    SysCall::futex_wake(futex.as_ptr(), count).map(|ret| ret.cast_into())
+}
+
+#[inline(always)]
+pub fn get_time() -> Result<Instant> {
+    SysCall::get_time().map(Instant::from_ticks)
+}
+
+#[inline(always)]
+pub fn sleep_until(deadline: Instant) -> Result<()> {
+    SysCall::sleep_until(deadline.ticks())
+}
+
+#[inline(always)]
+pub fn yield_timeslice() -> Result<()> {
+    SysCall::yield_timeslice()
+}
+
+/// Creates a thread in the calling process' thread pool.
+///
+/// # Safety
+/// `entry` must be the address of an `extern "C"` function which takes two
+/// `usize` arguments and never returns, and `stack` must not be used by
+/// anything else until the thread has been joined.
+#[inline(always)]
+pub unsafe fn thread_create(
+    entry: usize,
+    stack: *mut [u8],
+    arg0: usize,
+    arg1: usize,
+) -> Result<u32> {
+    unsafe { SysCall::thread_create(entry, stack.cast(), stack.len(), arg0, arg1) }
+}
+
+#[inline(always)]
+pub fn thread_join(handle: u32, deadline: Instant) -> Result<()> {
+    SysCall::thread_join(deadline.ticks(), handle)
+}
+
+#[inline(always)]
+pub fn thread_exit() -> Result<()> {
+    SysCall::thread_exit()
+}
+
+#[inline(always)]
+pub fn process_terminate(object_handle: u32) -> Result<()> {
+    SysCall::process_terminate(object_handle)
+}
+
+#[inline(always)]
+pub fn process_restart(object_handle: u32) -> Result<()> {
+    SysCall::process_restart(object_handle)
+}
+
+#[inline(always)]
+pub fn process_get_exit_status(object_handle: u32) -> Result<Result<()>> {
+    SysCall::process_get_exit_status(object_handle)
+}
+
+/// Returns the fault which terminated the process referenced by a process
+/// object.
+#[inline(always)]
+pub fn process_get_fault(object_handle: u32) -> Result<ProcessFault> {
+    let mut fault = ProcessFault::default();
+    unsafe { SysCall::process_get_fault(object_handle, &raw mut fault) }?;
+    Ok(fault)
+}
+
+/// Checks in the calling thread with the kernel watchdog.
+#[inline(always)]
+pub fn watchdog_check_in() -> Result<()> {
+    SysCall::watchdog_check_in()
+}
+
+/// Starts a timer object, expiring at `deadline` and then every `period` if
+/// one is given.
+#[inline(always)]
+pub fn timer_set(object_handle: u32, deadline: Instant, period: Option<Duration>) -> Result<()> {
+    let period_ticks = period.map_or(0, |period| period.ticks().cast_unsigned());
+    SysCall::timer_set(deadline.ticks(), period_ticks, object_handle)
+}
+
+/// Stops a timer object.
+#[inline(always)]
+pub fn timer_cancel(object_handle: u32) -> Result<()> {
+    SysCall::timer_cancel(object_handle)
+}
+
+/// Reads received bytes from a UART object without blocking.
+///
+/// Returns the number of bytes read, which is `0` if no data was available.
+#[inline(always)]
+pub fn uart_read(object_handle: u32, buffer: &mut [u8]) -> Result<usize> {
+    unsafe {
+        SysCall::uart_read(object_handle, buffer.as_mut_ptr(), buffer.len())
+            .map(|ret| ret.cast_into())
+    }
+}
+
+/// Queues bytes for transmission on a UART object without blocking.
+///
+/// Returns the number of bytes queued, which is `0` if the UART's write buffer
+/// was full.
+#[inline(always)]
+pub fn uart_write(object_handle: u32, buffer: &[u8]) -> Result<usize> {
+    unsafe {
+        SysCall::uart_write(object_handle, buffer.as_ptr(), buffer.len()).map(|ret| ret.cast_into())
+    }
+}
+
 #[inline(always)]
 pub fn debug_putc(c: char) -> Result<u32> {
     SysCall::debug_putc(c.into())
@@ -0,0 +1,93 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+//! Threads created at run time.
+//!
+//! Threads are taken from the process' thread pool, sized by the
+//! `thread_pool` entry of the process' system config, and run on a stack
+//! provided by the caller.
+
+use pw_status::{Error, Result};
+
+use crate::syscall;
+use crate::time::Instant;
+
+/// A handle to a thread created with [`spawn()`].
+///
+/// Dropping the handle without joining the thread leaves its pool slot in use
+/// until the process exits.
+pub struct JoinHandle {
+    handle: u32,
+    stack: *mut [u8],
+}
+
+impl JoinHandle {
+    /// Waits for the thread to exit.
+    ///
+    /// Returns the thread's stack, which may be used to spawn another thread.
+    pub fn join(self) -> Result<&'static mut [u8]> {
+        self.join_until(Instant::MAX).map_err(|(_, e)| e)
+    }
+
+    /// Waits for the thread to exit until `deadline`.
+    ///
+    /// On failure, the handle is returned along with the error so that the
+    /// join can be retried.
+    pub fn join_until(
+        self,
+        deadline: Instant,
+    ) -> core::result::Result<&'static mut [u8], (Self, Error)> {
+        match syscall::thread_join(self.handle, deadline) {
+            // Safety: The thread has exited and `stack` was borrowed for
+            // `'static` in `spawn()`.
+            Ok(()) => Ok(unsafe { &mut *self.stack }),
+            Err(e) => Err((self, e)),
+        }
+    }
+}
+
+extern "C" fn thread_start(entry: usize, arg: usize) -> ! {
+    let entry = core::ptr::with_exposed_provenance::<()>(entry);
+    // Safety: `entry` is a `fn(usize)` converted to a `usize` in `spawn()`.
+    let entry: fn(usize) = unsafe { core::mem::transmute(entry) };
+    entry(arg);
+    exit()
+}
+
+/// Spawns a thread which calls `entry(arg)` on `stack`.
+///
+/// The thread exits when `entry` returns.
+pub fn spawn(stack: &'static mut [u8], entry: fn(usize), arg: usize) -> Result<JoinHandle> {
+    let stack: *mut [u8] = stack;
+    let start: extern "C" fn(usize, usize) -> ! = thread_start;
+    // Safety: `thread_start` is an `extern "C" fn(usize, usize) -> !` and the
+    // `'static` borrow of `stack` is held by the returned `JoinHandle`.
+    let handle = unsafe {
+        syscall::thread_create(
+            start as usize,
+            stack,
+            (entry as *const ()).expose_provenance(),
+            arg,
+        )?
+    };
+    Ok(JoinHandle { handle, stack })
+}
+
+/// Exits the calling thread.
+pub fn exit() -> ! {
+    let _ = syscall::thread_exit();
+    // `thread_exit()` only returns on targets without user space support.
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
@@ -13,16 +13,28 @@
 // the License.
 
 use kernel_config::{KernelConfig, KernelConfigInterface};
+use pw_status::Result;
+use time::Clock as _;
+
+use crate::syscall;
 
 pub struct Clock;
 
 impl time::Clock for Clock {
     const TICKS_PER_SEC: u64 = KernelConfig::SYSTEM_CLOCK_HZ;
     fn now() -> time::Instant<Self> {
-        // Placeholder until a get_time() system call exists.
-        Instant::from_ticks(0)
+        // `get_time()` only fails on targets without user space support.
+        syscall::get_time().unwrap_or(Instant::MIN)
     }
 }
 
 pub type Instant = time::Instant<Clock>;
-pub type Duration = time::Instant<Clock>;
+pub type Duration = time::Duration<Clock>;
+
+/// Blocks the calling thread for at least `duration`.
+pub fn sleep_for(duration: Duration) -> Result<()> {
+    let deadline = Clock::now()
+        .checked_add_duration(duration)
+        .unwrap_or(Instant::MAX);
+    syscall::sleep_until(deadline)
+}
@@ -94,3 +94,21 @@ pub fn riscv_entry(args: TokenStream, input: TokenStream) -> TokenStream {
     )
     .into()
 }
+
+/// Entry point for apps linked into a host kernel executable.
+///
+/// All apps share one executable on the host, so rather than `main`, the entry
+/// point is exported as `pw_kernel_host_app_start_<crate name>`.  The app's
+/// crate name must match its name in the system config.
+#[proc_macro_attribute]
+pub fn host_entry(args: TokenStream, input: TokenStream) -> TokenStream {
+    let f = validate_and_set_entry_ident(args, input).unwrap();
+
+    let crate_name = std::env::var("CARGO_CRATE_NAME").unwrap();
+    let symbol = format!("pw_kernel_host_app_start_{crate_name}");
+    quote!(
+        #[unsafe(export_name = #symbol)]
+        extern "C" #f
+    )
+    .into()
+}